@@ -0,0 +1,126 @@
+//! Compares the streaming serializer against building an `xml_builder` DOM, which is how sitemaps used to be generated.
+//!
+//! `cargo bench --bench serialize`
+
+use chrono::{DateTime, FixedOffset, NaiveDate, SecondsFormat};
+use criterion::{Criterion, criterion_group, criterion_main};
+use sitemap_rs::image::Image;
+use sitemap_rs::url::{ChangeFrequency, Url};
+use sitemap_rs::url_set::UrlSet;
+use std::hint::black_box;
+use xml_builder::{XML, XMLBuilder, XMLElement, XMLVersion};
+
+fn url_set() -> UrlSet {
+    let last_modified: DateTime<FixedOffset> = DateTime::from_naive_utc_and_offset(
+        NaiveDate::from_ymd_opt(1998, 1, 15)
+            .unwrap()
+            .and_hms_opt(4, 20, 0)
+            .unwrap(),
+        FixedOffset::east_opt(0).unwrap(),
+    );
+
+    let urls: Vec<Url> = (0..50_000)
+        .map(|i| {
+            Url::builder(format!("https://www.toddgriffin.me/page/{i}"))
+                .last_modified(last_modified)
+                .change_frequency(ChangeFrequency::Monthly)
+                .priority(0.5)
+                .images(vec![Image::new(format!(
+                    "https://www.toddgriffin.me/image/{i}.webp"
+                ))])
+                .build()
+                .unwrap()
+        })
+        .collect();
+
+    UrlSet::new(urls).unwrap()
+}
+
+/// Builds the same document the way `xml_builder` required: one heap-allocated node per tag.
+fn xml_builder_dom(url_set: &UrlSet) -> XML {
+    let mut xml: XML = XMLBuilder::new()
+        .version(XMLVersion::XML1_0)
+        .encoding(url_set.xml_encoding.clone())
+        .build();
+
+    let mut urlset: XMLElement = XMLElement::new("urlset");
+    urlset.add_attribute("xmlns", &url_set.xmlns);
+    for namespace in &url_set.namespaces {
+        urlset.add_attribute(&namespace.attribute_name(), &namespace.uri);
+    }
+
+    for url in &url_set.urls {
+        let mut url_element: XMLElement = XMLElement::new("url");
+
+        let mut loc: XMLElement = XMLElement::new("loc");
+        loc.add_text(url.location.clone()).unwrap();
+        url_element.add_child(loc).unwrap();
+
+        if let Some(last_modified) = url.last_modified {
+            let mut lastmod: XMLElement = XMLElement::new("lastmod");
+            lastmod
+                .add_text(last_modified.to_rfc3339_opts(SecondsFormat::Secs, false))
+                .unwrap();
+            url_element.add_child(lastmod).unwrap();
+        }
+
+        if let Some(change_frequency) = url.change_frequency {
+            let mut changefreq: XMLElement = XMLElement::new("changefreq");
+            changefreq.add_text(change_frequency.to_string()).unwrap();
+            url_element.add_child(changefreq).unwrap();
+        }
+
+        if let Some(p) = url.priority {
+            let mut priority: XMLElement = XMLElement::new("priority");
+            priority.add_text(p.to_string()).unwrap();
+            url_element.add_child(priority).unwrap();
+        }
+
+        for image in url.images.iter().flatten() {
+            let mut image_element: XMLElement = XMLElement::new("image:image");
+            let mut loc: XMLElement = XMLElement::new("image:loc");
+            loc.add_text(image.location.clone()).unwrap();
+            image_element.add_child(loc).unwrap();
+            url_element.add_child(image_element).unwrap();
+        }
+
+        urlset.add_child(url_element).unwrap();
+    }
+
+    xml.set_root_element(urlset);
+    xml
+}
+
+fn bench_serialize(c: &mut Criterion) {
+    let url_set: UrlSet = url_set();
+
+    // both serializers must produce the exact same bytes
+    let mut streaming: Vec<u8> = Vec::new();
+    url_set.write(&mut streaming).unwrap();
+    let mut dom: Vec<u8> = Vec::new();
+    xml_builder_dom(&url_set).generate(&mut dom).unwrap();
+    assert_eq!(streaming, dom);
+
+    let mut group = c.benchmark_group("serialize 50,000 urls");
+    group.sample_size(20);
+    group.bench_function("streaming", |b| {
+        b.iter(|| {
+            let mut buf: Vec<u8> = Vec::with_capacity(streaming.len());
+            black_box(&url_set).write(&mut buf).unwrap();
+            buf
+        });
+    });
+    group.bench_function("xml_builder", |b| {
+        b.iter(|| {
+            let mut buf: Vec<u8> = Vec::with_capacity(dom.len());
+            xml_builder_dom(black_box(&url_set))
+                .generate(&mut buf)
+                .unwrap();
+            buf
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_serialize);
+criterion_main!(benches);
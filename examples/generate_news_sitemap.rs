@@ -1,4 +1,4 @@
-use chrono::{DateTime, FixedOffset, NaiveDate};
+use chrono::NaiveDate;
 use sitemap_rs::news::{News, Publication};
 use sitemap_rs::url::Url;
 use sitemap_rs::url_set::UrlSet;
@@ -10,13 +10,10 @@ fn main() {
         ))
         .news(News::new(
             Publication::new(String::from("The Example Times"), String::from("en")),
-            DateTime::from_naive_utc_and_offset(
-                NaiveDate::from_ymd_opt(1998, 1, 15)
-                    .unwrap()
-                    .and_hms_opt(4, 20, 0)
-                    .unwrap(),
-                FixedOffset::east_opt(0).unwrap(),
-            ),
+            NaiveDate::from_ymd_opt(1998, 1, 15)
+                .unwrap()
+                .and_hms_opt(4, 20, 0)
+                .unwrap(),
             String::from("Companies A, B in Merger Talks"),
         ))
         .build()
@@ -1,4 +1,4 @@
-use chrono::{DateTime, FixedOffset, NaiveDate};
+use chrono::NaiveDate;
 use sitemap_rs::url::{ChangeFrequency, Link, Url};
 use sitemap_rs::url_set::UrlSet;
 
@@ -9,13 +9,12 @@ fn main() {
                 "de".to_owned(),
                 "https://www.toddgriffin.me/de".to_owned(),
             )])
-            .last_modified(DateTime::from_naive_utc_and_offset(
+            .last_modified(
                 NaiveDate::from_ymd_opt(1998, 1, 15)
                     .unwrap()
                     .and_hms_opt(4, 20, 0)
                     .unwrap(),
-                FixedOffset::east_opt(0).unwrap(),
-            ))
+            )
             .change_frequency(ChangeFrequency::Monthly)
             .priority(0.69)
             .build()
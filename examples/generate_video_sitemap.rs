@@ -13,7 +13,7 @@ fn main() {
         String::from("https://www.toddgriffin.me/videoplayer.php?video=123"),
     )
     .duration(600)
-    .expiration_date(DateTime::from_naive_utc_and_offset(
+    .expiration_date(DateTime::<FixedOffset>::from_naive_utc_and_offset(
         NaiveDate::from_ymd_opt(2021, 11, 5)
             .unwrap()
             .and_hms_opt(11, 20, 30)
@@ -22,7 +22,7 @@ fn main() {
     ))
     .rating(4.2)
     .view_count(8633)
-    .publication_date(DateTime::from_naive_utc_and_offset(
+    .publication_date(DateTime::<FixedOffset>::from_naive_utc_and_offset(
         NaiveDate::from_ymd_opt(1998, 1, 15)
             .unwrap()
             .and_hms_opt(4, 20, 0)
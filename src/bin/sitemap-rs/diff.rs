@@ -0,0 +1,162 @@
+use crate::validate::read;
+use chrono::{DateTime, FixedOffset, SecondsFormat};
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
+use serde_json::{Value, json};
+use sitemap_rs::diff::{Change, Diff, diff};
+use sitemap_rs::parse::Document;
+use sitemap_rs::url::Url;
+use sitemap_rs::video::Video;
+use std::path::{Path, PathBuf};
+
+pub fn command() -> Command {
+    Command::new("diff")
+        .about("Lists the URLs added, removed, and changed between two sitemaps")
+        .arg(
+            Arg::new("old")
+                .required(true)
+                .value_parser(value_parser!(PathBuf))
+                .help("The sitemap to compare against; gzipped files are decompressed first"),
+        )
+        .arg(
+            Arg::new("new")
+                .required(true)
+                .value_parser(value_parser!(PathBuf))
+                .help("The sitemap to compare; gzipped files are decompressed first"),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_parser(["human", "json"])
+                .default_value("human")
+                .help("How to print the differences"),
+        )
+        .arg(
+            Arg::new("exit-code")
+                .long("exit-code")
+                .action(ArgAction::SetTrue)
+                .help("Exit with a failure status if the sitemaps differ"),
+        )
+}
+
+pub struct Args {
+    old: PathBuf,
+    new: PathBuf,
+    json: bool,
+    exit_code: bool,
+}
+
+impl Args {
+    pub fn from_matches(matches: &ArgMatches) -> Self {
+        Self {
+            old: matches
+                .get_one::<PathBuf>("old")
+                .cloned()
+                .unwrap_or_default(),
+            new: matches
+                .get_one::<PathBuf>("new")
+                .cloned()
+                .unwrap_or_default(),
+            json: matches
+                .get_one::<String>("format")
+                .is_some_and(|format| format == "json"),
+            exit_code: matches.get_flag("exit-code"),
+        }
+    }
+}
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let diff: Diff = diff(&urls(&args.old)?, &urls(&args.new)?);
+
+    if args.json {
+        let json: String = serde_json::to_string_pretty(&report(&diff))
+            .map_err(|error| format!("error: failed to write report: {error}"))?;
+        println!("{json}");
+    } else {
+        print!("{diff}");
+    }
+
+    if args.exit_code && !diff.is_empty() {
+        return Err(String::from("error: the sitemaps differ"));
+    }
+    Ok(())
+}
+
+/// Reads the URLs of the sitemap at `path`, whether or not it breaks any rules.
+fn urls(path: &Path) -> Result<Vec<Url>, String> {
+    match read(path) {
+        Ok(parsed) => match parsed.document {
+            Document::UrlSet(url_set) => Ok(url_set.urls),
+            Document::SitemapIndex(_) => Err(format!(
+                "{}: only <urlset> sitemaps can be compared, not <sitemapindex>",
+                path.display()
+            )),
+        },
+        Err(error) => Err(format!("{}: {error}", path.display())),
+    }
+}
+
+fn report(diff: &Diff) -> Value {
+    json!({
+        "added": diff.added.iter().map(|url| &url.location).collect::<Vec<_>>(),
+        "removed": diff.removed.iter().map(|url| &url.location).collect::<Vec<_>>(),
+        "changed": diff.changed.iter().map(|url| json!({
+            "location": url.location,
+            "changes": url.changes.iter().map(change).collect::<Vec<_>>(),
+        })).collect::<Vec<_>>(),
+    })
+}
+
+fn change(change: &Change) -> Value {
+    let date = |date: &Option<DateTime<FixedOffset>>| {
+        date.map(|date| date.to_rfc3339_opts(SecondsFormat::AutoSi, false))
+    };
+
+    match change {
+        Change::LastModified { old, new } => {
+            json!({ "field": "lastmod", "old": date(old), "new": date(new) })
+        }
+        Change::ChangeFrequency { old, new } => json!({
+            "field": "changefreq",
+            "old": old.map(|old| old.as_str().to_string()),
+            "new": new.map(|new| new.as_str().to_string()),
+        }),
+        Change::Priority { old, new } => json!({
+            "field": "priority",
+            "old": old.map(|old| old.to_string()),
+            "new": new.map(|new| new.to_string()),
+        }),
+        Change::Images { added, removed } => json!({
+            "field": "images",
+            "added": added.iter().map(|image| &image.location).collect::<Vec<_>>(),
+            "removed": removed.iter().map(|image| &image.location).collect::<Vec<_>>(),
+        }),
+        Change::Videos { added, removed } => json!({
+            "field": "videos",
+            "added": added.iter().map(video).collect::<Vec<_>>(),
+            "removed": removed.iter().map(video).collect::<Vec<_>>(),
+        }),
+        Change::News { old, new } => json!({
+            "field": "news",
+            "old": old.as_ref().map(|news| &news.title),
+            "new": new.as_ref().map(|news| &news.title),
+        }),
+        Change::Links { added, removed } => json!({
+            "field": "links",
+            "added": added.iter().map(|link| json!({ "hreflang": link.hreflang, "href": link.href })).collect::<Vec<_>>(),
+            "removed": removed.iter().map(|link| json!({ "hreflang": link.hreflang, "href": link.href })).collect::<Vec<_>>(),
+        }),
+        Change::Extensions { old, new } => json!({
+            "field": "extensions",
+            "old": old.len(),
+            "new": new.len(),
+        }),
+    }
+}
+
+fn video(video: &Video) -> Value {
+    json!({
+        "title": video.title,
+        "content_location": video.content_location,
+        "player_location": video.player_location,
+    })
+}
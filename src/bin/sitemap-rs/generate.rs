@@ -0,0 +1,288 @@
+use crate::output::{self, Output, join};
+use chrono::{DateTime, FixedOffset};
+use clap::{Arg, ArgMatches, Command, value_parser};
+use serde::Deserialize;
+use sitemap_rs::date::parse_w3c_datetime;
+use sitemap_rs::url::{ChangeFrequency, Priority, Url};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+pub fn command() -> Command {
+    output::args(Command::new("generate"))
+        .about("Generates a sitemap (or a sitemap index and its sitemaps) from a list of URLs")
+        .arg(
+            Arg::new("input")
+                .value_parser(value_parser!(PathBuf))
+                .help("The file to read URL entries from; standard input is read if this is left out"),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_parser(["csv", "jsonl", "list"])
+                .help("The format of the input; guessed from the file extension if left out, otherwise a plain URL list"),
+        )
+        .arg(
+            Arg::new("base-url")
+                .long("base-url")
+                .help("The URL that relative locations, and the locations of split sitemaps, are resolved against"),
+        )
+        .arg(
+            Arg::new("change-frequency")
+                .long("change-frequency")
+                .value_parser(parse_change_frequency)
+                .help("The change frequency of entries that don't have one"),
+        )
+        .arg(
+            Arg::new("priority")
+                .long("priority")
+                .value_parser(value_parser!(f32))
+                .help("The priority of entries that don't have one"),
+        )
+}
+
+pub struct Args {
+    input: Option<PathBuf>,
+    format: Option<Format>,
+    base_url: Option<String>,
+    output: Output,
+    change_frequency: Option<ChangeFrequency>,
+    priority: Option<f32>,
+}
+
+impl Args {
+    pub fn from_matches(matches: &ArgMatches) -> Self {
+        Self {
+            input: matches.get_one::<PathBuf>("input").cloned(),
+            format: matches
+                .get_one::<String>("format")
+                .map(|format| Format::from_name(format)),
+            base_url: matches.get_one::<String>("base-url").cloned(),
+            output: Output::from_matches(matches, PathBuf::from(".")),
+            change_frequency: matches
+                .get_one::<ChangeFrequency>("change-frequency")
+                .copied(),
+            priority: matches.get_one::<f32>("priority").copied(),
+        }
+    }
+}
+
+/// The format of URL entries.
+#[derive(Copy, Clone)]
+enum Format {
+    /// A header row naming the columns `loc`, `lastmod`, `changefreq`, and `priority`; only `loc` is required.
+    Csv,
+
+    /// One JSON object per line, with the same fields as the CSV columns.
+    Jsonl,
+
+    /// One URL per line; blank lines and lines starting with `#` are skipped.
+    List,
+}
+
+impl Format {
+    fn from_name(name: &str) -> Self {
+        match name {
+            "csv" => Self::Csv,
+            "jsonl" => Self::Jsonl,
+            _ => Self::List,
+        }
+    }
+
+    fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("csv") => Self::Csv,
+            Some("jsonl" | "ndjson") => Self::Jsonl,
+            _ => Self::List,
+        }
+    }
+}
+
+/// A single URL entry of the input.
+#[derive(Deserialize)]
+struct Entry {
+    loc: String,
+    lastmod: Option<String>,
+    changefreq: Option<String>,
+    priority: Option<f32>,
+}
+
+/// An entry of the input along with its line number, or why it couldn't be read.
+type NumberedEntry = (usize, Result<Entry, String>);
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let (source, input): (String, Box<dyn Read>) = match &args.input {
+        Some(path) => (
+            path.display().to_string(),
+            Box::new(File::open(path).map_err(|error| format!("{}: {error}", path.display()))?),
+        ),
+        None => (String::from("<stdin>"), Box::new(std::io::stdin())),
+    };
+    let format: Format = args.format.unwrap_or_else(|| {
+        args.input
+            .as_deref()
+            .map_or(Format::List, Format::from_path)
+    });
+
+    let entries: Vec<NumberedEntry> =
+        read_entries(input, format).map_err(|error| format!("{source}: {error}"))?;
+
+    // build every entry, so that all problems are reported at once
+    let mut urls: Vec<Url> = Vec::with_capacity(entries.len());
+    let mut errors: Vec<String> = vec![];
+    for (line, entry) in entries {
+        match entry
+            .map_err(|error| vec![error])
+            .and_then(|entry| build_url(args, entry))
+        {
+            Ok(url) => urls.push(url),
+            Err(entry_errors) => errors.extend(
+                entry_errors
+                    .into_iter()
+                    .map(|error| format!("  line {line}: {error}")),
+            ),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(format!(
+            "error: invalid URL entries in {source}\n{}",
+            errors.join("\n")
+        ));
+    }
+
+    args.output.write_sitemaps(urls, args.base_url.as_deref())
+}
+
+/// Reads the entries of `input` along with their line numbers.
+///
+/// An entry that can't be read is kept as its error, so that it is reported with the other problems; only failing to read the input itself is returned as an error.
+fn read_entries(input: Box<dyn Read>, format: Format) -> Result<Vec<NumberedEntry>, String> {
+    let mut entries: Vec<NumberedEntry> = vec![];
+
+    match format {
+        Format::Csv => {
+            let line = |position: &csv::Position| -> usize {
+                usize::try_from(position.line()).unwrap_or(usize::MAX)
+            };
+
+            let mut reader: csv::Reader<Box<dyn Read>> = csv::Reader::from_reader(input);
+            let headers: csv::StringRecord =
+                reader.headers().map_err(|error| error.to_string())?.clone();
+            for record in reader.records() {
+                match record {
+                    Ok(record) => entries.push((
+                        record.position().map_or(0, line),
+                        record
+                            .deserialize::<Entry>(Some(&headers))
+                            .map_err(|error| error.to_string()),
+                    )),
+                    // a row that isn't valid CSV has a position, an IO error doesn't
+                    Err(error) => match error.position() {
+                        Some(position) => entries.push((line(position), Err(error.to_string()))),
+                        None => return Err(error.to_string()),
+                    },
+                }
+            }
+        }
+        Format::Jsonl => {
+            for (index, line) in BufReader::new(input).lines().enumerate() {
+                let line: String = line.map_err(|error| error.to_string())?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let entry: Result<Entry, String> =
+                    serde_json::from_str(&line).map_err(|error| error.to_string());
+                entries.push((index + 1, entry));
+            }
+        }
+        Format::List => {
+            for (index, line) in BufReader::new(input).lines().enumerate() {
+                let line: String = line.map_err(|error| error.to_string())?;
+                let line: &str = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+                entries.push((
+                    index + 1,
+                    Ok(Entry {
+                        loc: line.to_string(),
+                        lastmod: None,
+                        changefreq: None,
+                        priority: None,
+                    }),
+                ));
+            }
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Builds the `Url` of `entry`, or returns every problem with it.
+fn build_url(args: &Args, entry: Entry) -> Result<Url, Vec<String>> {
+    let mut errors: Vec<String> = vec![];
+
+    let location: String = match &args.base_url {
+        Some(base_url) if !is_absolute(&entry.loc) => join(base_url, &entry.loc),
+        _ => entry.loc,
+    };
+
+    let last_modified: Option<DateTime<FixedOffset>> = entry.lastmod.and_then(|lastmod| {
+        let last_modified: Option<DateTime<FixedOffset>> = parse_w3c_datetime(&lastmod);
+        if last_modified.is_none() {
+            errors.push(format!("lastmod must be a W3C Datetime: {lastmod}"));
+        }
+        last_modified
+    });
+
+    let change_frequency: Option<ChangeFrequency> =
+        match entry.changefreq.map(|text| text.parse::<ChangeFrequency>()) {
+            Some(Ok(change_frequency)) => Some(change_frequency),
+            Some(Err(error)) => {
+                errors.push(error.to_string());
+                None
+            }
+            None => args.change_frequency,
+        };
+
+    let priority: Option<Priority> =
+        entry
+            .priority
+            .or(args.priority)
+            .and_then(|priority| match Priority::new(priority) {
+                Ok(priority) => Some(priority),
+                Err(error) => {
+                    errors.push(error.to_string());
+                    None
+                }
+            });
+
+    let url: Url = Url {
+        location,
+        links: vec![],
+        last_modified,
+        change_frequency,
+        priority,
+        images: None,
+        videos: None,
+        news: None,
+        extensions: vec![],
+    };
+
+    // the rules of the URL itself, such as the length of its location, come first
+    let mut url_errors: Vec<String> = url.validate().iter().map(ToString::to_string).collect();
+    if url_errors.is_empty() && errors.is_empty() {
+        return Ok(url);
+    }
+    url_errors.append(&mut errors);
+    Err(url_errors)
+}
+
+fn parse_change_frequency(text: &str) -> Result<ChangeFrequency, String> {
+    text.parse::<ChangeFrequency>()
+        .map_err(|error| error.to_string())
+}
+
+fn is_absolute(location: &str) -> bool {
+    location.starts_with("http://") || location.starts_with("https://")
+}
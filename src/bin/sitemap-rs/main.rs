@@ -0,0 +1,41 @@
+//! Command-line interface for generating, validating, and comparing sitemaps without writing Rust.
+//!
+//! Build with `cargo install sitemap-rs --features cli`.
+
+mod diff;
+mod generate;
+mod output;
+mod site;
+mod validate;
+
+use clap::{ArgMatches, Command};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let matches: ArgMatches = Command::new("sitemap-rs")
+        .version(env!("CARGO_PKG_VERSION"))
+        .about(env!("CARGO_PKG_DESCRIPTION"))
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .subcommand(generate::command())
+        .subcommand(diff::command())
+        .subcommand(site::command())
+        .subcommand(validate::command())
+        .get_matches();
+
+    let result: Result<(), String> = match matches.subcommand() {
+        Some(("generate", matches)) => generate::run(&generate::Args::from_matches(matches)),
+        Some(("diff", matches)) => diff::run(&diff::Args::from_matches(matches)),
+        Some(("site", matches)) => site::run(&site::Args::from_matches(matches)),
+        Some(("validate", matches)) => validate::run(&validate::Args::from_matches(matches)),
+        _ => unreachable!("clap requires a known subcommand"),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
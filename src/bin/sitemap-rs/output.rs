@@ -0,0 +1,212 @@
+use chrono::Utc;
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
+use sitemap_rs::incremental::{Regeneration, regenerate};
+use sitemap_rs::manifest::Manifest;
+use sitemap_rs::sitemap_index::SitemapIndex;
+use sitemap_rs::split::{sitemap_index, split_urls};
+use sitemap_rs::url::Url;
+use sitemap_rs::url_set::UrlSet;
+use sitemap_rs::write_options::WriteOptions;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Adds the arguments that control where and how sitemap files are written.
+pub fn args(command: Command) -> Command {
+    command
+        .arg(
+            Arg::new("output-dir")
+                .long("output-dir")
+                .short('o')
+                .value_parser(value_parser!(PathBuf))
+                .help("The directory the sitemap files are written to"),
+        )
+        .arg(
+            Arg::new("name")
+                .long("name")
+                .default_value("sitemap")
+                .help("The file name of the sitemap, without extension"),
+        )
+        .arg(
+            Arg::new("gzip")
+                .long("gzip")
+                .action(ArgAction::SetTrue)
+                .help("Gzip the sitemap files, and add .gz to their names"),
+        )
+        .arg(
+            Arg::new("manifest")
+                .long("manifest")
+                .value_parser(value_parser!(PathBuf))
+                .help("Keeps track of which sitemap each URL is in, so that only sitemaps whose contents changed are rewritten and the rest keep their <lastmod> in the sitemap index; created if it doesn't exist"),
+        )
+}
+
+/// Where and how sitemap files are written.
+pub struct Output {
+    dir: PathBuf,
+    name: String,
+    gzip: bool,
+    manifest: Option<PathBuf>,
+}
+
+impl Output {
+    /// Reads the arguments added by `args()`, writing to `default_dir` if no output directory is given.
+    pub fn from_matches(matches: &ArgMatches, default_dir: PathBuf) -> Self {
+        Self {
+            dir: matches
+                .get_one::<PathBuf>("output-dir")
+                .cloned()
+                .unwrap_or(default_dir),
+            name: matches
+                .get_one::<String>("name")
+                .cloned()
+                .unwrap_or_default(),
+            gzip: matches.get_flag("gzip"),
+            manifest: matches.get_one::<PathBuf>("manifest").cloned(),
+        }
+    }
+
+    /// Writes a single sitemap, or as many as `urls` need along with a sitemap index that references them.
+    ///
+    /// The sitemap index needs `base_url` to know where the sitemaps will be hosted.
+    pub fn write_sitemaps(&self, urls: Vec<Url>, base_url: Option<&str>) -> Result<(), String> {
+        if let Some(manifest) = &self.manifest {
+            return self.write_incremental(urls, base_url, manifest);
+        }
+
+        let url_sets: Vec<UrlSet> = split_urls(urls).map_err(|error| format!("error: {error}"))?;
+        let extension: &str = self.extension();
+
+        if let [url_set] = url_sets.as_slice() {
+            let path: PathBuf = self.dir.join(format!("{}.{extension}", self.name));
+            return write(&path, self.gzip, url_set);
+        }
+
+        // too many URLs for a single sitemap, so write an index that references each part
+        let Some(base_url) = base_url else {
+            return Err(format!(
+                "error: {} sitemaps are needed for this many URLs, which requires --base-url for the sitemap index",
+                url_sets.len()
+            ));
+        };
+        let file_name = |index: usize| self.file_name(index + 1);
+
+        for (index, url_set) in url_sets.iter().enumerate() {
+            let path: PathBuf = self.dir.join(file_name(index));
+            write(&path, self.gzip, url_set)?;
+        }
+
+        let index: SitemapIndex =
+            sitemap_index(&url_sets, |index| join(base_url, &file_name(index)))
+                .map_err(|error| format!("error: {error}"))?;
+        let path: PathBuf = self.dir.join(format!("{}.{extension}", self.name));
+        write(&path, self.gzip, &index)
+    }
+
+    /// Writes the sitemaps of `urls` that changed since the run that wrote `manifest_path`, and always a sitemap index.
+    fn write_incremental(
+        &self,
+        urls: Vec<Url>,
+        base_url: Option<&str>,
+        manifest_path: &Path,
+    ) -> Result<(), String> {
+        let Some(base_url) = base_url else {
+            return Err(String::from(
+                "error: --manifest always writes a sitemap index, which requires --base-url",
+            ));
+        };
+        let manifest_error =
+            |error: &dyn std::fmt::Display| format!("{}: {error}", manifest_path.display());
+
+        let previous: Manifest = match File::open(manifest_path) {
+            Ok(file) => Manifest::read(file).map_err(|error| manifest_error(&error))?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Manifest::default(),
+            Err(error) => return Err(manifest_error(&error)),
+        };
+        let regeneration: Regeneration =
+            regenerate(urls, &previous, &WriteOptions::default(), Utc::now())
+                .map_err(|error| format!("error: {error}"))?;
+
+        for sitemap in &regeneration.sitemaps {
+            let path: PathBuf = self.dir.join(self.file_name(sitemap.number));
+            if sitemap.changed || !path.exists() {
+                write(&path, self.gzip, &sitemap.url_set)?;
+            }
+        }
+        for number in &regeneration.removed {
+            let path: PathBuf = self.dir.join(self.file_name(*number));
+            match fs::remove_file(&path) {
+                Ok(()) => {}
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+                Err(error) => return Err(format!("{}: {error}", path.display())),
+            }
+        }
+
+        let index: SitemapIndex = regeneration
+            .sitemap_index(|number| join(base_url, &self.file_name(number)))
+            .map_err(|error| format!("error: {error}"))?;
+        let path: PathBuf = self.dir.join(format!("{}.{}", self.name, self.extension()));
+        write(&path, self.gzip, &index)?;
+
+        let file: File = File::create(manifest_path).map_err(|error| manifest_error(&error))?;
+        regeneration
+            .manifest
+            .write(file)
+            .map_err(|error| manifest_error(&error))
+    }
+
+    const fn extension(&self) -> &'static str {
+        if self.gzip { "xml.gz" } else { "xml" }
+    }
+
+    /// The file name of the sitemap numbered `number`, such as `sitemap-3.xml`.
+    fn file_name(&self, number: usize) -> String {
+        format!("{}-{number}.{}", self.name, self.extension())
+    }
+}
+
+pub fn join(base_url: &str, path: &str) -> String {
+    format!(
+        "{}/{}",
+        base_url.trim_end_matches('/'),
+        path.trim_start_matches('/')
+    )
+}
+
+/// A sitemap file, written either as is or gzipped.
+trait SitemapFile {
+    fn write_to(&self, writer: &mut BufWriter<File>, gzip: bool) -> Result<(), sitemap_rs::Error>;
+}
+
+impl SitemapFile for UrlSet {
+    fn write_to(&self, writer: &mut BufWriter<File>, gzip: bool) -> Result<(), sitemap_rs::Error> {
+        if gzip {
+            self.write_gzip(writer, &WriteOptions::default())
+        } else {
+            self.write_with_options(writer, &WriteOptions::default())
+        }
+    }
+}
+
+impl SitemapFile for SitemapIndex {
+    fn write_to(&self, writer: &mut BufWriter<File>, gzip: bool) -> Result<(), sitemap_rs::Error> {
+        if gzip {
+            self.write_gzip(writer, &WriteOptions::default())
+        } else {
+            self.write_with_options(writer, &WriteOptions::default())
+        }
+    }
+}
+
+fn write(path: &Path, gzip: bool, sitemap: &impl SitemapFile) -> Result<(), String> {
+    let file: File = File::create(path).map_err(|error| format!("{}: {error}", path.display()))?;
+    let mut writer: BufWriter<File> = BufWriter::new(file);
+
+    sitemap
+        .write_to(&mut writer, gzip)
+        .and_then(|()| writer.flush().map_err(Into::into))
+        .map_err(|error| format!("{}: {error}", path.display()))?;
+
+    println!("{}", path.display());
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use crate::output::{self, Output};
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
+use sitemap_rs::static_site::{StaticSite, StaticSiteWarning};
+use sitemap_rs::url::Url;
+use std::path::PathBuf;
+
+pub fn command() -> Command {
+    output::args(Command::new("site"))
+        .about("Generates a sitemap from the build directory of a static site, such as the output of Hugo, Zola, or mdBook")
+        .arg(
+            Arg::new("dir")
+                .required(true)
+                .value_parser(value_parser!(PathBuf))
+                .help("The build directory; sitemap files are written here unless --output-dir is given"),
+        )
+        .arg(
+            Arg::new("base-url")
+                .long("base-url")
+                .required(true)
+                .help("The URL the build directory is served at"),
+        )
+        .arg(
+            Arg::new("exclude")
+                .long("exclude")
+                .action(ArgAction::Append)
+                .value_parser(value_parser!(PathBuf))
+                .help("A file or directory to leave out, relative to the build directory; can be repeated"),
+        )
+        .arg(
+            Arg::new("no-last-modified")
+                .long("no-last-modified")
+                .action(ArgAction::SetTrue)
+                .help("Don't use file modification times as last modification dates"),
+        )
+        .arg(
+            Arg::new("include-noindex")
+                .long("include-noindex")
+                .action(ArgAction::SetTrue)
+                .help("Include pages marked with <meta name=\"robots\" content=\"noindex\">"),
+        )
+        .arg(
+            Arg::new("ignore-canonical")
+                .long("ignore-canonical")
+                .action(ArgAction::SetTrue)
+                .help("List pages under their own URL, even if they have a <link rel=\"canonical\">"),
+        )
+        .arg(
+            Arg::new("no-images")
+                .long("no-images")
+                .action(ArgAction::SetTrue)
+                .help("Don't list the <img> of each page as its images"),
+        )
+        .arg(
+            Arg::new("no-alternates")
+                .long("no-alternates")
+                .action(ArgAction::SetTrue)
+                .help("Don't list the <link rel=\"alternate\" hreflang> of each page as its links"),
+        )
+        .arg(
+            Arg::new("no-videos")
+                .long("no-videos")
+                .action(ArgAction::SetTrue)
+                .help("Don't list the <video> and JSON-LD VideoObject of each page as its videos"),
+        )
+}
+
+pub struct Args {
+    site: StaticSite,
+    base_url: String,
+    output: Output,
+}
+
+impl Args {
+    pub fn from_matches(matches: &ArgMatches) -> Self {
+        let dir: PathBuf = matches
+            .get_one::<PathBuf>("dir")
+            .cloned()
+            .unwrap_or_default();
+        let base_url: String = matches
+            .get_one::<String>("base-url")
+            .cloned()
+            .unwrap_or_default();
+
+        let mut site: StaticSite = StaticSite::new(dir.clone(), base_url.clone());
+        site.last_modified(!matches.get_flag("no-last-modified"))
+            .honor_noindex(!matches.get_flag("include-noindex"))
+            .honor_canonical(!matches.get_flag("ignore-canonical"))
+            .collect_images(!matches.get_flag("no-images"))
+            .collect_alternates(!matches.get_flag("no-alternates"))
+            .collect_videos(!matches.get_flag("no-videos"));
+        for path in matches.get_many::<PathBuf>("exclude").into_iter().flatten() {
+            site.exclude(path.clone());
+        }
+
+        Self {
+            site,
+            base_url,
+            output: Output::from_matches(matches, dir),
+        }
+    }
+}
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let (urls, warnings): (Vec<Url>, Vec<StaticSiteWarning>) = args
+        .site
+        .urls_with_warnings()
+        .map_err(|error| format!("error: {error}"))?;
+    for warning in warnings {
+        eprintln!("warning: {warning}");
+    }
+    args.output.write_sitemaps(urls, Some(&args.base_url))
+}
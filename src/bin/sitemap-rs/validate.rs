@@ -0,0 +1,236 @@
+use crate::output::join;
+use clap::{Arg, ArgAction, ArgMatches, Command, value_parser};
+use serde::Serialize;
+use sitemap_rs::parse::{Document, Parsed, Problem, parse};
+use sitemap_rs::parse_error::ParseError;
+use sitemap_rs::resolve::LocalResolver;
+use sitemap_rs::sitemap_index_error::SitemapIndexError;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+/// The first two bytes of every gzip file.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+pub fn command() -> Command {
+    Command::new("validate")
+        .about("Checks existing sitemap or sitemap index files against every rule of the sitemap protocol")
+        .arg(
+            Arg::new("files")
+                .required(true)
+                .action(ArgAction::Append)
+                .value_parser(value_parser!(PathBuf))
+                .help("The sitemap files to check; gzipped files are decompressed first"),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_parser(["human", "json"])
+                .default_value("human")
+                .help("How to print the report"),
+        )
+        .arg(
+            Arg::new("base-url")
+                .long("base-url")
+                .help("The URL the directory of each file is served at; sitemap indexes are then checked for entries that reference other sitemap indexes in that directory, or that are on other hosts"),
+        )
+}
+
+pub struct Args {
+    files: Vec<PathBuf>,
+    json: bool,
+    base_url: Option<String>,
+}
+
+impl Args {
+    pub fn from_matches(matches: &ArgMatches) -> Self {
+        Self {
+            files: matches
+                .get_many::<PathBuf>("files")
+                .map(|files| files.cloned().collect())
+                .unwrap_or_default(),
+            json: matches
+                .get_one::<String>("format")
+                .is_some_and(|format| format == "json"),
+            base_url: matches.get_one::<String>("base-url").cloned(),
+        }
+    }
+}
+
+/// The result of checking a single file.
+#[derive(Serialize)]
+struct Report {
+    file: String,
+
+    /// Either `urlset` or `sitemapindex`, if the file could be read.
+    kind: Option<&'static str>,
+
+    /// The number of \<url\> or \<sitemap\> entries.
+    entries: usize,
+
+    valid: bool,
+
+    /// Why the file couldn't be read at all.
+    error: Option<String>,
+
+    problems: Vec<ReportProblem>,
+}
+
+#[derive(Serialize)]
+struct ReportProblem {
+    /// The line the problem was found on, unless it is about the files a sitemap index references.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<usize>,
+    path: String,
+    message: String,
+}
+
+pub fn run(args: &Args) -> Result<(), String> {
+    let reports: Vec<Report> = args
+        .files
+        .iter()
+        .map(|path| check(path, args.base_url.as_deref()))
+        .collect();
+
+    if args.json {
+        let json: String = serde_json::to_string_pretty(&reports)
+            .map_err(|error| format!("error: failed to write report: {error}"))?;
+        println!("{json}");
+    } else {
+        for report in &reports {
+            print_human(report);
+        }
+    }
+
+    let invalid: usize = reports.iter().filter(|report| !report.valid).count();
+    match invalid {
+        0 => Ok(()),
+        1 if reports.len() == 1 => Err(String::from("error: the sitemap is invalid")),
+        _ => Err(format!(
+            "error: {invalid} of {} sitemaps are invalid",
+            reports.len()
+        )),
+    }
+}
+
+fn check(path: &Path, base_url: Option<&str>) -> Report {
+    let file: String = path.display().to_string();
+
+    match read(path) {
+        Ok(parsed) => {
+            let mut problems: Vec<ReportProblem> = parsed
+                .problems
+                .into_iter()
+                .map(|problem: Problem| ReportProblem {
+                    line: Some(problem.line),
+                    path: problem.path,
+                    message: problem.message,
+                })
+                .collect();
+            if let (Document::SitemapIndex(sitemap_index), Some(base_url)) =
+                (&parsed.document, base_url)
+            {
+                let dir: PathBuf = path.parent().map(Path::to_path_buf).unwrap_or_default();
+                let name: String = path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                let resolver: LocalResolver = LocalResolver::new(dir, base_url.to_string());
+                problems.extend(
+                    sitemap_index
+                        .check_references(&join(base_url, &name), &resolver)
+                        .into_iter()
+                        .map(|error: SitemapIndexError| {
+                            let location: &str = match &error {
+                                SitemapIndexError::NestedIndex(location)
+                                | SitemapIndexError::CrossHost { location, .. } => location,
+                                _ => "",
+                            };
+                            let path: String = sitemap_index
+                                .sitemaps
+                                .iter()
+                                .position(|sitemap| sitemap.location == location)
+                                .map_or_else(
+                                    || String::from("sitemaps"),
+                                    |index| format!("sitemaps[{index}].{}", error.field()),
+                                );
+                            ReportProblem {
+                                line: None,
+                                path,
+                                message: error.to_string(),
+                            }
+                        }),
+                );
+            }
+
+            let (kind, entries): (&'static str, usize) = match &parsed.document {
+                Document::UrlSet(url_set) => ("urlset", url_set.urls.len()),
+                Document::SitemapIndex(sitemap_index) => {
+                    ("sitemapindex", sitemap_index.sitemaps.len())
+                }
+            };
+            Report {
+                file,
+                kind: Some(kind),
+                entries,
+                valid: problems.is_empty(),
+                error: None,
+                problems,
+            }
+        }
+        Err(error) => Report {
+            file,
+            kind: None,
+            entries: 0,
+            valid: false,
+            error: Some(error.to_string()),
+            problems: vec![],
+        },
+    }
+}
+
+/// Parses the sitemap at `path`, decompressing it first if it is gzipped.
+pub fn read(path: &Path) -> Result<Parsed, ParseError> {
+    let mut reader: BufReader<File> = BufReader::new(File::open(path)?);
+
+    if reader.fill_buf()?.starts_with(&GZIP_MAGIC) {
+        parse(flate2::read::GzDecoder::new(reader))
+    } else {
+        parse(reader)
+    }
+}
+
+fn print_human(report: &Report) {
+    let entries: &str = match (report.kind, report.entries) {
+        (Some("sitemapindex"), 1) => "sitemap",
+        (Some("sitemapindex"), _) => "sitemaps",
+        (_, 1) => "URL",
+        _ => "URLs",
+    };
+
+    match (&report.error, report.kind) {
+        (Some(error), _) => println!("{}: {error}", report.file),
+        (None, Some(kind)) if report.valid => println!(
+            "{}: valid <{kind}> with {} {entries}",
+            report.file, report.entries
+        ),
+        (None, kind) => {
+            println!(
+                "{}: {} problem{} in <{}> with {} {entries}",
+                report.file,
+                report.problems.len(),
+                if report.problems.len() == 1 { "" } else { "s" },
+                kind.unwrap_or_default(),
+                report.entries
+            );
+            for problem in &report.problems {
+                match problem.line {
+                    Some(line) => {
+                        println!("  line {line}: {}: {}", problem.path, problem.message);
+                    }
+                    None => println!("  {}: {}", problem.path, problem.message),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,297 @@
+use crate::crawl_error::CrawlError;
+use crate::merge::normalize_location;
+use crate::parse::{Document, Parsed, Problem, parse};
+use crate::parse_error::ParseError;
+use crate::resolve::{SitemapKind, local_path};
+use crate::url::Url;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+/// By default, a sitemap index may reference sitemaps, but not other sitemap indexes, as the protocol requires.
+pub const DEFAULT_MAX_DEPTH: usize = 1;
+
+/// By default, a crawl stops after finding 1,000,000 URLs.
+pub const DEFAULT_MAX_URLS: usize = 1_000_000;
+
+/// By default, a crawl stops after reading 1GB of (uncompressed) sitemaps.
+pub const DEFAULT_MAX_BYTES: u64 = 1_073_741_824;
+
+/// The first two bytes of every gzip file.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Fetches the files a `Crawler` reads, such as over HTTP with a client like reqwest.
+pub trait Fetcher {
+    /// Opens the body of `location` as is; gzipped sitemaps are decompressed by the `Crawler`.
+    ///
+    /// The `Crawler` reads no more of the body than its byte limit allows.
+    ///
+    /// # Errors
+    ///
+    /// Will return an IO Error if `location` can't be fetched, such as when it doesn't exist.
+    fn fetch(&self, location: &str) -> io::Result<Box<dyn Read>>;
+}
+
+impl<F: Fn(&str) -> io::Result<Box<dyn Read>>> Fetcher for F {
+    fn fetch(&self, location: &str) -> io::Result<Box<dyn Read>> {
+        self(location)
+    }
+}
+
+/// Fetches locations from the files within a local directory that is served at a base URL, such as a copy of a site.
+#[derive(Debug, Clone)]
+pub struct LocalFetcher {
+    dir: PathBuf,
+    base_url: String,
+}
+
+impl LocalFetcher {
+    #[must_use]
+    pub const fn new(dir: PathBuf, base_url: String) -> Self {
+        Self { dir, base_url }
+    }
+}
+
+impl Fetcher for LocalFetcher {
+    fn fetch(&self, location: &str) -> io::Result<Box<dyn Read>> {
+        let path: PathBuf = local_path(&self.dir, &self.base_url, location).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("not within {}", self.base_url),
+            )
+        })?;
+        Ok(Box::new(File::open(path)?))
+    }
+}
+
+/// Collects every URL of a site by following its sitemaps, starting from a sitemap, a sitemap index, or a robots.txt.
+///
+/// Sitemap indexes are followed recursively, gzipped sitemaps are decompressed, and every sitemap is only read once.
+/// How deep sitemap indexes are followed, and how many URLs and bytes are read, is limited; see `DEFAULT_MAX_DEPTH`, `DEFAULT_MAX_URLS`, and `DEFAULT_MAX_BYTES`.
+#[derive(Debug, Clone)]
+pub struct Crawler<F> {
+    fetcher: F,
+    max_depth: usize,
+    max_urls: usize,
+    max_bytes: u64,
+}
+
+/// The result of `Crawler::crawl()`.
+#[derive(Debug, Default)]
+pub struct Crawl {
+    /// Every URL found, in the order of the sitemaps they were found in.
+    pub urls: Vec<Url>,
+
+    /// Every sitemap and sitemap index that was read, in the order they were read.
+    pub sitemaps: Vec<CrawledSitemap>,
+
+    /// The sitemaps that couldn't be fetched or read, and the limits that were hit.
+    ///
+    /// The crawl carries on past sitemaps that couldn't be read, but stops at the URL and byte limits.
+    pub errors: Vec<CrawlError>,
+}
+
+/// A single sitemap or sitemap index read during a `Crawl`.
+#[derive(Debug, Clone)]
+pub struct CrawledSitemap {
+    pub location: String,
+
+    /// How many sitemap indexes deep this is nested, where the starting sitemap (or each sitemap of a robots.txt) is `0`.
+    pub depth: usize,
+
+    /// Either `SitemapKind::UrlSet` or `SitemapKind::SitemapIndex`.
+    pub kind: SitemapKind,
+
+    /// The number of \<url\> or \<sitemap\> entries.
+    pub entries: usize,
+
+    /// Every rule the sitemap broke; see `parse()`.
+    pub problems: Vec<Problem>,
+}
+
+impl<F: Fetcher> Crawler<F> {
+    #[must_use]
+    pub const fn new(fetcher: F) -> Self {
+        Self {
+            fetcher,
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_urls: DEFAULT_MAX_URLS,
+            max_bytes: DEFAULT_MAX_BYTES,
+        }
+    }
+
+    /// How many sitemap indexes deep sitemaps are followed; deeper sitemaps are reported as `CrawlError::TooDeep`.
+    pub const fn max_depth(&mut self, max_depth: usize) -> &mut Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    pub const fn max_urls(&mut self, max_urls: usize) -> &mut Self {
+        self.max_urls = max_urls;
+        self
+    }
+
+    /// The most bytes of sitemaps to read, counted after decompressing them.
+    pub const fn max_bytes(&mut self, max_bytes: u64) -> &mut Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Follows every sitemap reachable from `location`, which is either a sitemap, a sitemap index, or a robots.txt whose `Sitemap:` lines are followed.
+    ///
+    /// # Errors
+    ///
+    /// Will return `CrawlError::Fetch` if `location` can't be fetched.
+    /// Will return `CrawlError::Parse` if `location` isn't a sitemap that can be read.
+    /// Will return `CrawlError::NoSitemaps` if `location` is a robots.txt that doesn't list any sitemaps.
+    /// Will return `CrawlError::TooManyBytes` if `location` alone is larger than the byte limit.
+    pub fn crawl(&self, location: &str) -> Result<Crawl, CrawlError> {
+        let mut crawl: Crawl = Crawl::default();
+        let mut bytes: u64 = 0;
+
+        let robots_txt: bool = location
+            .split(['?', '#'])
+            .next()
+            .is_some_and(|path| path.ends_with("/robots.txt"));
+        let roots: Vec<String> = if robots_txt {
+            let body: Vec<u8> = self.fetch(location, &mut bytes)?;
+            let sitemaps: Vec<String> = robots_txt_sitemaps(&String::from_utf8_lossy(&body));
+            if sitemaps.is_empty() {
+                return Err(CrawlError::NoSitemaps(location.to_string()));
+            }
+            sitemaps
+        } else {
+            vec![location.to_string()]
+        };
+
+        // depth-first, so URLs come out in the order their sitemaps are listed
+        let mut pending: Vec<(String, usize)> =
+            roots.into_iter().rev().map(|root| (root, 0)).collect();
+        let mut visited: HashSet<String> = HashSet::new();
+        while let Some((location, depth)) = pending.pop() {
+            // a sitemap too deep here may still be reachable at a shallower depth
+            if depth > self.max_depth {
+                crawl.errors.push(CrawlError::TooDeep {
+                    location,
+                    depth: self.max_depth,
+                });
+                continue;
+            }
+            if !visited.insert(normalize_location(&location)) {
+                continue;
+            }
+
+            let parsed: Parsed = match self.read(&location, &mut bytes) {
+                Ok(parsed) => parsed,
+                // a single sitemap to start from must be readable
+                Err(error) if !robots_txt && crawl.sitemaps.is_empty() => return Err(error),
+                Err(error @ CrawlError::TooManyBytes(_)) => {
+                    crawl.errors.push(error);
+                    break;
+                }
+                Err(error) => {
+                    crawl.errors.push(error);
+                    continue;
+                }
+            };
+
+            let (kind, entries): (SitemapKind, usize) = match &parsed.document {
+                Document::UrlSet(url_set) => (SitemapKind::UrlSet, url_set.urls.len()),
+                Document::SitemapIndex(sitemap_index) => {
+                    (SitemapKind::SitemapIndex, sitemap_index.sitemaps.len())
+                }
+            };
+            crawl.sitemaps.push(CrawledSitemap {
+                location,
+                depth,
+                kind,
+                entries,
+                problems: parsed.problems,
+            });
+
+            match parsed.document {
+                Document::UrlSet(url_set) => {
+                    let remaining: usize = self.max_urls.saturating_sub(crawl.urls.len());
+                    if url_set.urls.len() > remaining {
+                        crawl.urls.extend(url_set.urls.into_iter().take(remaining));
+                        crawl.errors.push(CrawlError::TooManyUrls(self.max_urls));
+                        break;
+                    }
+                    crawl.urls.extend(url_set.urls);
+                }
+                Document::SitemapIndex(sitemap_index) => pending.extend(
+                    sitemap_index
+                        .sitemaps
+                        .into_iter()
+                        .rev()
+                        .map(|sitemap| (sitemap.location, depth + 1)),
+                ),
+            }
+        }
+
+        Ok(crawl)
+    }
+
+    /// Fetches and parses the sitemap at `location`.
+    fn read(&self, location: &str, bytes: &mut u64) -> Result<Parsed, CrawlError> {
+        let body: Vec<u8> = self.fetch(location, bytes)?;
+        parse(body.as_slice()).map_err(|error| CrawlError::Parse {
+            location: location.to_string(),
+            error,
+        })
+    }
+
+    /// Fetches `location`, decompressing it if it is gzipped, and adds its size to `bytes`.
+    fn fetch(&self, location: &str, bytes: &mut u64) -> Result<Vec<u8>, CrawlError> {
+        let fetch_error = |error: io::Error| CrawlError::Fetch {
+            location: location.to_string(),
+            error,
+        };
+        let mut body: Box<dyn Read> = self.fetcher.fetch(location).map_err(fetch_error)?;
+        let remaining: u64 = self.max_bytes.saturating_sub(*bytes);
+
+        let mut magic: Vec<u8> = vec![];
+        (&mut body)
+            .take(GZIP_MAGIC.len() as u64)
+            .read_to_end(&mut magic)
+            .map_err(fetch_error)?;
+        let gzipped: bool = magic == GZIP_MAGIC;
+        let body: io::Chain<io::Cursor<Vec<u8>>, Box<dyn Read>> =
+            io::Cursor::new(magic).chain(body);
+
+        // never read, or decompress, more than the limit allows
+        let mut contents: Vec<u8> = vec![];
+        if gzipped {
+            flate2::read::GzDecoder::new(body)
+                .take(remaining.saturating_add(1))
+                .read_to_end(&mut contents)
+                .map_err(|error| CrawlError::Parse {
+                    location: location.to_string(),
+                    error: ParseError::Io(error),
+                })?;
+        } else {
+            body.take(remaining.saturating_add(1))
+                .read_to_end(&mut contents)
+                .map_err(fetch_error)?;
+        }
+
+        let length: u64 = u64::try_from(contents.len()).unwrap_or(u64::MAX);
+        if length > remaining {
+            return Err(CrawlError::TooManyBytes(self.max_bytes));
+        }
+        *bytes += length;
+        Ok(contents)
+    }
+}
+
+/// The locations of the `Sitemap:` lines of a robots.txt.
+fn robots_txt_sitemaps(robots_txt: &str) -> Vec<String> {
+    robots_txt
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .filter(|(field, _)| field.trim().eq_ignore_ascii_case("sitemap"))
+        .map(|(_, location)| location.trim().to_string())
+        .filter(|location| !location.is_empty())
+        .collect()
+}
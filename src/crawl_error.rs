@@ -0,0 +1,66 @@
+use crate::parse_error::ParseError;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+
+/// An error while crawling sitemaps with a `Crawler`.
+#[derive(Debug)]
+pub enum CrawlError {
+    /// Returned when the `Fetcher` can't fetch a location.
+    Fetch { location: String, error: io::Error },
+
+    /// Returned when a fetched sitemap can't be read at all, such as when it isn't well-formed XML.
+    Parse { location: String, error: ParseError },
+
+    /// Returned when a robots.txt doesn't list any `Sitemap:`.
+    NoSitemaps(String),
+
+    /// Returned when a sitemap is nested deeper within sitemap indexes than the limit; it isn't fetched.
+    TooDeep { location: String, depth: usize },
+
+    /// Returned when the crawl stops because it found more URLs than the limit.
+    TooManyUrls(usize),
+
+    /// Returned when the crawl stops because its (uncompressed) sitemaps add up to more bytes than the limit.
+    TooManyBytes(u64),
+}
+
+impl Error for CrawlError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Fetch { error, .. } => Some(error),
+            Self::Parse { error, .. } => Some(error),
+            Self::NoSitemaps(_)
+            | Self::TooDeep { .. }
+            | Self::TooManyUrls(_)
+            | Self::TooManyBytes(_) => None,
+        }
+    }
+}
+
+impl Display for CrawlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fetch { location, error } => write!(f, "{location}: {error}"),
+            Self::Parse { location, error } => write!(f, "{location}: {error}"),
+            Self::NoSitemaps(location) => {
+                write!(f, "{location}: robots.txt does not list any sitemaps")
+            }
+            Self::TooDeep { location, depth } => {
+                write!(
+                    f,
+                    "{location}: sitemap is nested more than {depth} sitemap indexes deep"
+                )
+            }
+            Self::TooManyUrls(limit) => {
+                write!(f, "stopped after finding more than {limit} URLs")
+            }
+            Self::TooManyBytes(limit) => {
+                write!(
+                    f,
+                    "stopped after reading more than {limit} bytes of sitemaps"
+                )
+            }
+        }
+    }
+}
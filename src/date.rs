@@ -0,0 +1,241 @@
+use crate::date_error::DateError;
+use crate::{RFC_3339_SECONDS_FORMAT, RFC_3339_USE_Z};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+
+/// A date or timestamp that can be used wherever a sitemap expects a W3C Datetime.
+///
+/// Dates and timestamps without a timezone are taken to be in UTC.
+/// Dates of the `time` and `jiff` crates can't always be converted, so they implement `TryIntoDateTime` instead.
+pub trait IntoDateTime {
+    fn into_date_time(self) -> DateTime<FixedOffset>;
+}
+
+impl<Tz: TimeZone> IntoDateTime for DateTime<Tz> {
+    fn into_date_time(self) -> DateTime<FixedOffset> {
+        self.fixed_offset()
+    }
+}
+
+impl IntoDateTime for NaiveDateTime {
+    fn into_date_time(self) -> DateTime<FixedOffset> {
+        self.and_utc().fixed_offset()
+    }
+}
+
+/// The date is taken to be at midnight UTC; write it with `DatePrecision::Date` to leave the time out.
+impl IntoDateTime for NaiveDate {
+    fn into_date_time(self) -> DateTime<FixedOffset> {
+        self.and_time(NaiveTime::MIN).into_date_time()
+    }
+}
+
+/// A date or timestamp that can be converted to a sitemap date, but not always.
+///
+/// This covers everything that implements `IntoDateTime`, and, with the `time` or `jiff` feature, the dates of those crates.
+/// They can hold UTC offsets of a day or more, and (for `jiff`) civil dates whose UTC timestamp is out of range, which `chrono` can't represent.
+pub trait TryIntoDateTime {
+    /// # Errors
+    ///
+    /// Will return `DateError::OffsetOutOfRange` if the UTC offset is a whole day or more.
+    /// Will return `DateError::OutOfRange` if the date is outside of the range `chrono` can represent.
+    fn try_into_date_time(self) -> Result<DateTime<FixedOffset>, DateError>;
+}
+
+impl<T: IntoDateTime> TryIntoDateTime for T {
+    fn try_into_date_time(self) -> Result<DateTime<FixedOffset>, DateError> {
+        Ok(self.into_date_time())
+    }
+}
+
+#[cfg(any(feature = "time", feature = "jiff"))]
+fn fixed_offset(seconds: i32) -> Result<FixedOffset, DateError> {
+    FixedOffset::east_opt(seconds).ok_or(DateError::OffsetOutOfRange(seconds))
+}
+
+#[cfg(feature = "time")]
+impl TryIntoDateTime for time::OffsetDateTime {
+    fn try_into_date_time(self) -> Result<DateTime<FixedOffset>, DateError> {
+        let offset: FixedOffset = fixed_offset(self.offset().whole_seconds())?;
+        DateTime::from_timestamp(self.unix_timestamp(), self.nanosecond())
+            .map(|date| date.with_timezone(&offset))
+            .ok_or_else(|| DateError::OutOfRange(self.to_string()))
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryIntoDateTime for time::PrimitiveDateTime {
+    fn try_into_date_time(self) -> Result<DateTime<FixedOffset>, DateError> {
+        self.assume_utc().try_into_date_time()
+    }
+}
+
+/// The date is taken to be at midnight UTC; write it with `DatePrecision::Date` to leave the time out.
+#[cfg(feature = "time")]
+impl TryIntoDateTime for time::Date {
+    fn try_into_date_time(self) -> Result<DateTime<FixedOffset>, DateError> {
+        self.midnight().try_into_date_time()
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryIntoDateTime for jiff::Timestamp {
+    fn try_into_date_time(self) -> Result<DateTime<FixedOffset>, DateError> {
+        // jiff gives negative nanoseconds for timestamps before the epoch, chrono wants them positive
+        let mut seconds: i64 = self.as_second();
+        let mut nanoseconds: i32 = self.subsec_nanosecond();
+        if nanoseconds < 0 {
+            seconds -= 1;
+            nanoseconds += 1_000_000_000;
+        }
+
+        DateTime::from_timestamp(seconds, nanoseconds.unsigned_abs())
+            .map(|date| date.fixed_offset())
+            .ok_or_else(|| DateError::OutOfRange(self.to_string()))
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryIntoDateTime for jiff::Zoned {
+    fn try_into_date_time(self) -> Result<DateTime<FixedOffset>, DateError> {
+        let offset: FixedOffset = fixed_offset(self.offset().seconds())?;
+        Ok(self
+            .timestamp()
+            .try_into_date_time()?
+            .with_timezone(&offset))
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryIntoDateTime for jiff::civil::DateTime {
+    fn try_into_date_time(self) -> Result<DateTime<FixedOffset>, DateError> {
+        jiff::tz::Offset::UTC
+            .to_timestamp(self)
+            .map_err(|_| DateError::OutOfRange(self.to_string()))?
+            .try_into_date_time()
+    }
+}
+
+/// The date is taken to be at midnight UTC; write it with `DatePrecision::Date` to leave the time out.
+#[cfg(feature = "jiff")]
+impl TryIntoDateTime for jiff::civil::Date {
+    fn try_into_date_time(self) -> Result<DateTime<FixedOffset>, DateError> {
+        self.to_datetime(jiff::civil::Time::midnight())
+            .try_into_date_time()
+    }
+}
+
+/// Parses a W3C Datetime, in any of the forms sitemaps allow.
+///
+/// This accepts a year (`1998`), a month (`1998-01`), a date (`1998-01-15`), or a date and time with a timezone down to the minute (`1998-01-15T04:20+08:00`), second, or fraction of a second.
+/// Years, months, and dates are taken to be at their start, at midnight UTC.
+#[must_use]
+pub fn parse_w3c_datetime(text: &str) -> Option<DateTime<FixedOffset>> {
+    match text.len() {
+        4 if text.bytes().all(|byte| byte.is_ascii_digit()) => {
+            NaiveDate::from_ymd_opt(text.parse().ok()?, 1, 1).map(IntoDateTime::into_date_time)
+        }
+        7 => NaiveDate::parse_from_str(&format!("{text}-01"), "%Y-%m-%d")
+            .ok()
+            .map(IntoDateTime::into_date_time),
+        10 => NaiveDate::parse_from_str(text, "%Y-%m-%d")
+            .ok()
+            .map(IntoDateTime::into_date_time),
+        _ => {
+            let text: String = match text.strip_suffix('Z') {
+                Some(text) => format!("{text}+00:00"),
+                None => text.to_string(),
+            };
+            DateTime::parse_from_rfc3339(&text)
+                .or_else(|_| DateTime::parse_from_str(&text, "%Y-%m-%dT%H:%M%:z"))
+                .ok()
+        }
+    }
+}
+
+/// Controls how dates (such as \<lastmod\>) are written.
+///
+/// The default writes seconds precision with a `+00:00` offset, as set by `RFC_3339_SECONDS_FORMAT` and `RFC_3339_USE_Z`.
+#[derive(Debug, Copy, Clone)]
+pub struct DateFormat {
+    /// How much of the date and time to write.
+    pub precision: DatePrecision,
+
+    /// Whether to write a UTC offset as `Z` instead of `+00:00`.
+    ///
+    /// This has no effect on `DatePrecision::Date`.
+    pub use_z: bool,
+
+    /// Whether to convert every date to UTC before writing it, instead of keeping its own offset.
+    pub normalize_to_utc: bool,
+}
+
+impl DateFormat {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            precision: DatePrecision::Seconds,
+            use_z: RFC_3339_USE_Z,
+            normalize_to_utc: false,
+        }
+    }
+
+    pub const fn precision(&mut self, precision: DatePrecision) -> &mut Self {
+        self.precision = precision;
+        self
+    }
+
+    pub const fn use_z(&mut self, use_z: bool) -> &mut Self {
+        self.use_z = use_z;
+        self
+    }
+
+    pub const fn normalize_to_utc(&mut self, normalize_to_utc: bool) -> &mut Self {
+        self.normalize_to_utc = normalize_to_utc;
+        self
+    }
+
+    /// Formats `date` as a W3C Datetime.
+    #[must_use]
+    pub fn format(&self, date: &DateTime<FixedOffset>) -> String {
+        let date: DateTime<FixedOffset> = if self.normalize_to_utc {
+            date.with_timezone(&Utc).fixed_offset()
+        } else {
+            *date
+        };
+
+        match self.precision {
+            DatePrecision::Date => date.format("%Y-%m-%d").to_string(),
+            DatePrecision::Minutes => {
+                let offset: String = if self.use_z && date.offset().local_minus_utc() == 0 {
+                    String::from("Z")
+                } else {
+                    date.format("%:z").to_string()
+                };
+                format!("{}{offset}", date.format("%Y-%m-%dT%H:%M"))
+            }
+            DatePrecision::Seconds => date.to_rfc3339_opts(RFC_3339_SECONDS_FORMAT, self.use_z),
+        }
+    }
+}
+
+impl Default for DateFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How much of a date and time to write.
+///
+/// All of these are allowed by the W3C Datetime format.
+#[derive(Debug, Copy, Clone, Default)]
+pub enum DatePrecision {
+    /// Only the date, such as `1998-01-15`.
+    Date,
+
+    /// The date and time down to the minute, such as `1998-01-15T04:20+00:00`.
+    Minutes,
+
+    /// The date and time down to the second, such as `1998-01-15T04:20:00+00:00`.
+    #[default]
+    Seconds,
+}
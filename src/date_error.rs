@@ -0,0 +1,25 @@
+use std::error;
+use std::fmt::{Display, Formatter};
+
+/// An error when converting a date from another crate into a sitemap date.
+#[derive(Debug, Clone)]
+pub enum DateError {
+    /// Returned when a UTC offset, in seconds, is a whole day or more; `chrono` can't represent it.
+    OffsetOutOfRange(i32),
+
+    /// Returned when a date or timestamp is outside of the range `chrono` can represent.
+    OutOfRange(String),
+}
+
+impl error::Error for DateError {}
+
+impl Display for DateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OffsetOutOfRange(seconds) => {
+                write!(f, "UTC offset must be less than a day: {seconds} seconds")
+            }
+            Self::OutOfRange(date) => write!(f, "date is out of range: {date}"),
+        }
+    }
+}
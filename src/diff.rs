@@ -0,0 +1,286 @@
+use crate::extension::Extension;
+use crate::image::Image;
+use crate::news::News;
+use crate::url::{ChangeFrequency, Link, Priority, Url};
+use crate::video::Video;
+use chrono::{DateTime, FixedOffset, SecondsFormat};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+use std::sync::Arc;
+
+/// The differences between two lists of URLs, matched up by their \<loc\>.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Diff {
+    /// URLs that only the new list has, in its order.
+    pub added: Vec<Url>,
+
+    /// URLs that only the old list has, in its order.
+    pub removed: Vec<Url>,
+
+    /// URLs that both lists have, but with different contents, in the order of the new list.
+    pub changed: Vec<UrlChanges>,
+}
+
+/// Everything that changed about a single URL.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UrlChanges {
+    pub location: String,
+    pub changes: Vec<Change>,
+}
+
+/// A single change to a URL.
+///
+/// Images, videos, and links are compared as a whole, so a video with a new title is both removed and added.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    LastModified {
+        old: Option<DateTime<FixedOffset>>,
+        new: Option<DateTime<FixedOffset>>,
+    },
+    ChangeFrequency {
+        old: Option<ChangeFrequency>,
+        new: Option<ChangeFrequency>,
+    },
+    Priority {
+        old: Option<Priority>,
+        new: Option<Priority>,
+    },
+    Images {
+        added: Vec<Image>,
+        removed: Vec<Image>,
+    },
+    Videos {
+        added: Vec<Video>,
+        removed: Vec<Video>,
+    },
+    News {
+        old: Option<News>,
+        new: Option<News>,
+    },
+    Links {
+        added: Vec<Link>,
+        removed: Vec<Link>,
+    },
+    Extensions {
+        old: Vec<Arc<dyn Extension + Send + Sync>>,
+        new: Vec<Arc<dyn Extension + Send + Sync>>,
+    },
+}
+
+impl Diff {
+    /// Whether the two lists of URLs are the same.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compares the URLs of two sitemaps, such as the `urls` of two `UrlSet`s.
+///
+/// If a location appears more than once in a list, only its first URL is compared.
+#[must_use]
+pub fn diff(old: &[Url], new: &[Url]) -> Diff {
+    let old_urls: HashMap<&str, &Url> = by_location(old);
+    let new_urls: HashMap<&str, &Url> = by_location(new);
+
+    let mut diff: Diff = Diff::default();
+    for url in new {
+        match old_urls.get(url.location.as_str()) {
+            None => diff.added.push(url.clone()),
+            Some(old_url)
+                if new_urls
+                    .get(url.location.as_str())
+                    .is_some_and(|first| std::ptr::eq(*first, url)) =>
+            {
+                let changes: Vec<Change> = changes(old_url, url);
+                if !changes.is_empty() {
+                    diff.changed.push(UrlChanges {
+                        location: url.location.clone(),
+                        changes,
+                    });
+                }
+            }
+            // a later duplicate of a location
+            Some(_) => {}
+        }
+    }
+    diff.removed = old
+        .iter()
+        .filter(|url| !new_urls.contains_key(url.location.as_str()))
+        .cloned()
+        .collect();
+
+    diff
+}
+
+/// The first URL of each location.
+fn by_location(urls: &[Url]) -> HashMap<&str, &Url> {
+    let mut by_location: HashMap<&str, &Url> = HashMap::with_capacity(urls.len());
+    for url in urls {
+        by_location.entry(url.location.as_str()).or_insert(url);
+    }
+    by_location
+}
+
+fn changes(old: &Url, new: &Url) -> Vec<Change> {
+    let mut changes: Vec<Change> = vec![];
+
+    if old.last_modified != new.last_modified {
+        changes.push(Change::LastModified {
+            old: old.last_modified,
+            new: new.last_modified,
+        });
+    }
+
+    if old.change_frequency != new.change_frequency {
+        changes.push(Change::ChangeFrequency {
+            old: old.change_frequency,
+            new: new.change_frequency,
+        });
+    }
+
+    if old.priority != new.priority {
+        changes.push(Change::Priority {
+            old: old.priority,
+            new: new.priority,
+        });
+    }
+
+    let (added, removed): (Vec<Image>, Vec<Image>) = added_and_removed(
+        old.images.as_deref().unwrap_or_default(),
+        new.images.as_deref().unwrap_or_default(),
+    );
+    if !added.is_empty() || !removed.is_empty() {
+        changes.push(Change::Images { added, removed });
+    }
+
+    let (added, removed): (Vec<Video>, Vec<Video>) = added_and_removed(
+        old.videos.as_deref().unwrap_or_default(),
+        new.videos.as_deref().unwrap_or_default(),
+    );
+    if !added.is_empty() || !removed.is_empty() {
+        changes.push(Change::Videos { added, removed });
+    }
+
+    if old.news != new.news {
+        changes.push(Change::News {
+            old: old.news.clone(),
+            new: new.news.clone(),
+        });
+    }
+
+    let (added, removed): (Vec<Link>, Vec<Link>) = added_and_removed(&old.links, &new.links);
+    if !added.is_empty() || !removed.is_empty() {
+        changes.push(Change::Links { added, removed });
+    }
+
+    if old.extensions != new.extensions {
+        changes.push(Change::Extensions {
+            old: old.extensions.clone(),
+            new: new.extensions.clone(),
+        });
+    }
+
+    changes
+}
+
+/// The items only `new` has, and the items only `old` has.
+fn added_and_removed<T: Clone + Eq + Hash>(old: &[T], new: &[T]) -> (Vec<T>, Vec<T>) {
+    let only_in = |these: &[T], those: &[T]| -> Vec<T> {
+        let those: HashSet<&T> = those.iter().collect();
+        these
+            .iter()
+            .filter(|item| !those.contains(item))
+            .cloned()
+            .collect()
+    };
+    (only_in(new, old), only_in(old, new))
+}
+
+/// Writes one line per added (`+`) and removed (`-`) URL, and one line per changed (`~`) URL followed by an indented line per change.
+impl Display for Diff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for url in &self.added {
+            writeln!(f, "+ {}", url.location)?;
+        }
+        for url in &self.removed {
+            writeln!(f, "- {}", url.location)?;
+        }
+        for url in &self.changed {
+            writeln!(f, "~ {}", url.location)?;
+            for change in &url.changes {
+                write!(f, "{change}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LastModified { old, new } => {
+                let date = |date: &Option<DateTime<FixedOffset>>| {
+                    date.map(|date| date.to_rfc3339_opts(SecondsFormat::AutoSi, false))
+                };
+                writeln!(f, "    lastmod: {}", old_new(date(old), date(new)))
+            }
+            Self::ChangeFrequency { old, new } => {
+                writeln!(f, "    changefreq: {}", old_new(*old, *new))
+            }
+            Self::Priority { old, new } => writeln!(f, "    priority: {}", old_new(*old, *new)),
+            Self::Images { added, removed } => {
+                for image in added {
+                    writeln!(f, "    + image {}", image.location)?;
+                }
+                for image in removed {
+                    writeln!(f, "    - image {}", image.location)?;
+                }
+                Ok(())
+            }
+            Self::Videos { added, removed } => {
+                for video in added {
+                    writeln!(f, "    + video {}", video_summary(video))?;
+                }
+                for video in removed {
+                    writeln!(f, "    - video {}", video_summary(video))?;
+                }
+                Ok(())
+            }
+            Self::News { old, new } => {
+                let title =
+                    |news: &Option<News>| news.as_ref().map(|news| format!("{:?}", news.title));
+                writeln!(f, "    news: {}", old_new(title(old), title(new)))
+            }
+            Self::Links { added, removed } => {
+                for link in added {
+                    writeln!(f, "    + link {} {}", link.hreflang, link.href)?;
+                }
+                for link in removed {
+                    writeln!(f, "    - link {} {}", link.hreflang, link.href)?;
+                }
+                Ok(())
+            }
+            Self::Extensions { old, new } => {
+                writeln!(f, "    extensions: {} -> {} elements", old.len(), new.len())
+            }
+        }
+    }
+}
+
+/// `old -> new`, with `-` for either that isn't set.
+fn old_new<T: Display>(old: Option<T>, new: Option<T>) -> String {
+    let text =
+        |value: Option<T>| value.map_or_else(|| String::from("-"), |value| value.to_string());
+    format!("{} -> {}", text(old), text(new))
+}
+
+fn video_summary(video: &Video) -> String {
+    let location: &str = if video.content_location.is_empty() {
+        &video.player_location
+    } else {
+        &video.content_location
+    };
+    format!("{location} {:?}", video.title)
+}
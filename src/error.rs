@@ -0,0 +1,148 @@
+use crate::date_error::DateError;
+use crate::manifest_error::ManifestError;
+#[cfg(feature = "parse")]
+use crate::parse_error::ParseError;
+use crate::sitemap_index_error::SitemapIndexError;
+use crate::static_site_error::StaticSiteError;
+use crate::url_error::UrlError;
+use crate::url_set_error::UrlSetError;
+use crate::validation_error::ValidationError;
+use crate::video_error::VideoError;
+use std::fmt::{Display, Formatter};
+use std::{error, io};
+
+/// Any error this library can return.
+///
+/// Every module-specific error converts into this type with `?`, and the original error is available through `source()`.
+/// Cargo features add variants of their own, so matches on it need a wildcard arm.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// An error when instantiating or generating sitemap URLs.
+    Url(UrlError),
+
+    /// An error when instantiating or generating sitemaps.
+    UrlSet(UrlSetError),
+
+    /// An error when instantiating or generating sitemap videos.
+    Video(VideoError),
+
+    /// An error when instantiating or generating sitemap index files.
+    SitemapIndex(SitemapIndexError),
+
+    /// A rule violation found while validating a whole `UrlSet`.
+    Validation(ValidationError),
+
+    /// An error when generating a sitemap from a static site's build directory.
+    StaticSite(StaticSiteError),
+
+    /// An error when reading the manifest of a previous regeneration.
+    Manifest(ManifestError),
+
+    /// An error when converting a date from another crate.
+    Date(DateError),
+
+    /// An IO error from the underlying writer.
+    Io(io::Error),
+
+    /// An error that stops a sitemap file from being read.
+    #[cfg(feature = "parse")]
+    Parse(ParseError),
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Url(error) => Some(error),
+            Self::UrlSet(error) => Some(error),
+            Self::Video(error) => Some(error),
+            Self::SitemapIndex(error) => Some(error),
+            Self::Validation(error) => Some(error),
+            Self::StaticSite(error) => Some(error),
+            Self::Manifest(error) => Some(error),
+            Self::Date(error) => Some(error),
+            Self::Io(error) => Some(error),
+            #[cfg(feature = "parse")]
+            Self::Parse(error) => Some(error),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Url(error) => write!(f, "invalid URL: {error}"),
+            Self::UrlSet(error) => write!(f, "invalid URL set: {error}"),
+            Self::Video(error) => write!(f, "invalid video: {error}"),
+            Self::SitemapIndex(error) => write!(f, "invalid sitemap index: {error}"),
+            Self::Validation(error) => write!(f, "validation failed: {error}"),
+            Self::StaticSite(error) => write!(f, "failed to walk static site: {error}"),
+            Self::Manifest(error) => write!(f, "invalid manifest: {error}"),
+            Self::Date(error) => write!(f, "invalid date: {error}"),
+            Self::Io(error) => write!(f, "failed to write sitemap: {error}"),
+            #[cfg(feature = "parse")]
+            Self::Parse(error) => write!(f, "failed to parse sitemap: {error}"),
+        }
+    }
+}
+
+impl From<UrlError> for Error {
+    fn from(error: UrlError) -> Self {
+        Self::Url(error)
+    }
+}
+
+impl From<UrlSetError> for Error {
+    fn from(error: UrlSetError) -> Self {
+        Self::UrlSet(error)
+    }
+}
+
+impl From<VideoError> for Error {
+    fn from(error: VideoError) -> Self {
+        Self::Video(error)
+    }
+}
+
+impl From<SitemapIndexError> for Error {
+    fn from(error: SitemapIndexError) -> Self {
+        Self::SitemapIndex(error)
+    }
+}
+
+impl From<ValidationError> for Error {
+    fn from(error: ValidationError) -> Self {
+        Self::Validation(error)
+    }
+}
+
+impl From<StaticSiteError> for Error {
+    fn from(error: StaticSiteError) -> Self {
+        Self::StaticSite(error)
+    }
+}
+
+impl From<ManifestError> for Error {
+    fn from(error: ManifestError) -> Self {
+        Self::Manifest(error)
+    }
+}
+
+impl From<DateError> for Error {
+    fn from(error: DateError) -> Self {
+        Self::Date(error)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+#[cfg(feature = "parse")]
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
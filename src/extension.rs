@@ -0,0 +1,209 @@
+use crate::url_set::UrlOrder;
+use crate::write_options::WriteOptions;
+use crate::xml_writer::XmlWriter;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::io;
+
+/// An XML namespace declared on the \<urlset\>, such as `xmlns:image="http://www.google.com/schemas/sitemap-image/1.1"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Namespace {
+    /// The prefix that elements in this namespace are written with, such as `image`.
+    pub prefix: Cow<'static, str>,
+
+    /// The URI that identifies this namespace.
+    pub uri: Cow<'static, str>,
+}
+
+impl Namespace {
+    #[must_use]
+    pub fn new(prefix: impl Into<Cow<'static, str>>, uri: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            prefix: prefix.into(),
+            uri: uri.into(),
+        }
+    }
+
+    /// The name of the attribute that declares this namespace, such as `xmlns:image`.
+    #[must_use]
+    pub fn attribute_name(&self) -> String {
+        format!("xmlns:{}", self.prefix)
+    }
+}
+
+/// Content within a \<url\> that lives in its own XML namespace.
+///
+/// The image, video, and news extensions implement this, as does `ExtensionElement` for any other extension (such as `PageMap`).
+/// Implement it for your own types to add them to `Url::extensions`.
+/// `UrlSet::new()` declares the namespace of every extension it finds on the \<urlset\>.
+pub trait Extension {
+    /// The namespace this extension's elements are written in.
+    fn namespace(&self) -> Namespace;
+
+    /// Writes this extension's elements within a \<url\>.
+    ///
+    /// # Errors
+    ///
+    /// Will return an IO Error if there is a problem writing to the underlying writer.
+    fn write_xml(&self, writer: &mut XmlWriter<'_>) -> io::Result<()>;
+}
+
+// Custom extensions are kept type-erased in `Url::extensions`, so they are compared, ordered, and hashed by what they write.
+// One that fails to write is compared by its error message instead.
+impl PartialEq for dyn Extension + Send + Sync {
+    fn eq(&self, other: &Self) -> bool {
+        self.namespace() == other.namespace() && key(self, false) == key(other, false)
+    }
+}
+
+impl Eq for dyn Extension + Send + Sync {}
+
+// extensions that write the same sorted elements come out the same, whichever of them comes first
+impl PartialOrd for dyn Extension + Send + Sync {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for dyn Extension + Send + Sync {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.namespace()
+            .cmp(&other.namespace())
+            .then_with(|| key(self, true).cmp(&key(other, true)))
+            .then_with(|| key(self, false).cmp(&key(other, false)))
+    }
+}
+
+impl Hash for dyn Extension + Send + Sync {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.namespace().hash(state);
+        key(self, false).hash(state);
+    }
+}
+
+impl Debug for dyn Extension + Send + Sync {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("Extension");
+        debug.field("namespace", &self.namespace());
+        match xml(self, false) {
+            Ok(xml) => debug.field("xml", &String::from_utf8_lossy(&xml)),
+            Err(error) => debug.field("error", &error),
+        };
+        debug.finish()
+    }
+}
+
+/// An arbitrary element of a custom sitemap extension.
+///
+/// The element and all of its children are written with the prefix of `namespace`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExtensionElement {
+    /// The namespace this element is written in.
+    pub namespace: Namespace,
+
+    /// The local name of this element, without the namespace prefix.
+    pub name: String,
+
+    /// The attributes of this element, in the order they are written unless `WriteOptions::order` is set.
+    pub attributes: Vec<(String, String)>,
+
+    /// The text content of this element.
+    ///
+    /// This is ignored if the element has `children`.
+    pub text: Option<String>,
+
+    /// The child elements of this element, which share its namespace.
+    pub children: Vec<ExtensionElement>,
+}
+
+impl ExtensionElement {
+    #[must_use]
+    pub const fn new(namespace: Namespace, name: String) -> Self {
+        Self {
+            namespace,
+            name,
+            attributes: vec![],
+            text: None,
+            children: vec![],
+        }
+    }
+
+    #[must_use]
+    pub fn attribute(mut self, name: String, value: String) -> Self {
+        self.attributes.push((name, value));
+        self
+    }
+
+    #[must_use]
+    pub fn text(mut self, text: String) -> Self {
+        self.text = Some(text);
+        self
+    }
+
+    /// Adds a child element; it is written with this element's namespace prefix.
+    #[must_use]
+    pub fn child(mut self, child: Self) -> Self {
+        self.children.push(child);
+        self
+    }
+}
+
+impl Extension for ExtensionElement {
+    fn namespace(&self) -> Namespace {
+        self.namespace.clone()
+    }
+
+    fn write_xml(&self, writer: &mut XmlWriter<'_>) -> io::Result<()> {
+        let name: String = format!("{}:{}", self.namespace.prefix, self.name);
+        let mut attributes: Vec<(&str, &str)> = self
+            .attributes
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect();
+        if writer.options().order.is_some() {
+            attributes.sort_unstable();
+        }
+
+        if !self.children.is_empty() {
+            let mut children: Vec<&Self> = self.children.iter().collect();
+            if writer.options().order.is_some() {
+                let mut keyed: Vec<(Vec<u8>, &Self)> = children
+                    .into_iter()
+                    .map(|child| Ok((xml(child, true)?, child)))
+                    .collect::<io::Result<_>>()?;
+                keyed.sort_by(|(a, _), (b, _)| a.cmp(b));
+                children = keyed.into_iter().map(|(_, child)| child).collect();
+            }
+            writer.start(&name, &attributes)?;
+            for child in children {
+                child.write_xml(writer)?;
+            }
+            return writer.end(&name);
+        }
+
+        match &self.text {
+            Some(text) => writer.text(&name, &attributes, text),
+            None => writer.empty(&name, &attributes),
+        }
+    }
+}
+
+/// The elements `extension` writes, without any whitespace, and in a fixed order if `sorted`.
+///
+/// Writing to a `Vec` can't fail, so an error comes from the extension itself.
+fn xml(extension: &(impl Extension + ?Sized), sorted: bool) -> io::Result<Vec<u8>> {
+    let mut xml: Vec<u8> = vec![];
+    let mut options: WriteOptions = WriteOptions::compact();
+    if sorted {
+        options.order(UrlOrder::Location);
+    }
+    extension.write_xml(&mut XmlWriter::new(&mut xml, &options))?;
+    Ok(xml)
+}
+
+/// What `extension` is compared, ordered, and hashed by: the elements it writes, or the message of the error it fails with.
+fn key(extension: &(impl Extension + ?Sized), sorted: bool) -> Result<Vec<u8>, String> {
+    xml(extension, sorted).map_err(|error| error.to_string())
+}
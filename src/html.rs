@@ -0,0 +1,163 @@
+/// A tag found within an HTML document, with its name and attribute names lowercased.
+///
+/// End tags keep their slash, such as `/video`.
+pub(crate) struct Tag {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+
+    /// Whether the tag is within the \<head\>.
+    pub head: bool,
+
+    /// The text of \<title\>, \<script\>, and \<style\> elements, which isn't HTML.
+    pub text: String,
+}
+
+impl Tag {
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Whether the space-separated values of the attribute contain `value`, ignoring case.
+    pub fn attribute_contains(&self, name: &str, value: &str) -> bool {
+        self.attribute(name).is_some_and(|values| {
+            values
+                .split([' ', ','])
+                .any(|token| token.trim().eq_ignore_ascii_case(value))
+        })
+    }
+}
+
+/// Returns every tag within `html`, skipping comments, doctypes, and processing instructions.
+///
+/// This is nowhere near a full HTML parser, but it is enough to find \<meta\>, \<link\>, \<img\>, and \<video\> tags in the output of static site generators.
+pub(crate) fn tags(html: &str) -> Vec<Tag> {
+    let mut tags: Vec<Tag> = vec![];
+    let mut rest: &str = html;
+    let mut head: bool = true;
+
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+
+        // skip comments, doctypes, and processing instructions
+        if let Some(comment) = rest.strip_prefix("!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        if rest.starts_with(['!', '?']) {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+            continue;
+        }
+
+        // end tags keep their slash
+        let slash: usize = usize::from(rest.starts_with('/'));
+        let name_end: usize = rest[slash..]
+            .find(|character: char| {
+                character.is_ascii_whitespace() || matches!(character, '>' | '/')
+            })
+            .map_or(rest.len(), |end| slash + end);
+        let name: String = rest[..name_end].to_ascii_lowercase();
+        if name.len() == slash {
+            continue;
+        }
+        if name == "/head" || name == "body" {
+            head = false;
+        }
+
+        let (attributes, remainder): (Vec<(String, String)>, &str) = attributes(&rest[name_end..]);
+        rest = remainder;
+
+        // the contents of titles, scripts, and styles aren't HTML
+        let mut text: String = String::new();
+        if matches!(name.as_str(), "title" | "script" | "style") {
+            let close: String = format!("</{name}");
+            let end: usize = rest.to_ascii_lowercase().find(&close).unwrap_or(rest.len());
+            text = if name == "title" {
+                decode_entities(rest[..end].trim())
+            } else {
+                rest[..end].to_string()
+            };
+            rest = &rest[end..];
+        }
+
+        tags.push(Tag {
+            name,
+            attributes,
+            head,
+            text,
+        });
+    }
+
+    tags
+}
+
+/// Reads attributes up to the end of a tag, and returns them along with what follows the tag.
+fn attributes(mut rest: &str) -> (Vec<(String, String)>, &str) {
+    let mut attributes: Vec<(String, String)> = vec![];
+
+    loop {
+        rest = rest.trim_start_matches(|character: char| {
+            character.is_ascii_whitespace() || character == '/'
+        });
+        if rest.is_empty() {
+            return (attributes, rest);
+        }
+        if let Some(remainder) = rest.strip_prefix('>') {
+            return (attributes, remainder);
+        }
+
+        let name_end: usize = rest
+            .find(|character: char| {
+                character.is_ascii_whitespace() || matches!(character, '=' | '>' | '/')
+            })
+            .unwrap_or(rest.len());
+        let name: String = rest[..name_end].to_ascii_lowercase();
+        rest = rest[name_end..].trim_start();
+
+        let value: &str = if let Some(value) = rest.strip_prefix('=') {
+            let value: &str = value.trim_start();
+            if let Some(quote @ ('"' | '\'')) = value.chars().next() {
+                let value: &str = &value[1..];
+                let end: usize = value.find(quote).unwrap_or(value.len());
+                rest = value.get(end + 1..).unwrap_or("");
+                &value[..end]
+            } else {
+                let end: usize = value
+                    .find(|character: char| character.is_ascii_whitespace() || character == '>')
+                    .unwrap_or(value.len());
+                rest = &value[end..];
+                &value[..end]
+            }
+        } else {
+            ""
+        };
+
+        if name.is_empty() {
+            // a stray character that can't start an attribute
+            let mut characters: std::str::Chars<'_> = rest.chars();
+            characters.next();
+            rest = characters.as_str();
+        } else {
+            attributes.push((name, decode_entities(value)));
+        }
+    }
+}
+
+/// Decodes the character references that commonly appear within attribute values and titles.
+fn decode_entities(value: &str) -> String {
+    if !value.contains('&') {
+        return value.to_string();
+    }
+
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&#x2F;", "/")
+        .replace("&#47;", "/")
+        .replace("&amp;", "&")
+}
@@ -1,7 +1,11 @@
-use xml_builder::{XMLElement, XMLError};
+use crate::IMAGE_NAMESPACE;
+use crate::extension::{Extension, Namespace};
+use crate::image_builder::ImageBuilder;
+use crate::xml_writer::XmlWriter;
+use std::io;
 
 /// A sitemap image.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Image {
     /// The URL of the image.
     ///
@@ -18,17 +22,23 @@ impl Image {
         Self { location }
     }
 
-    /// # Errors
-    ///
-    /// Will return `XMLError` if there is a problem creating XML elements.
-    pub fn to_xml(self) -> Result<XMLElement, XMLError> {
-        let mut image: XMLElement = XMLElement::new("image:image");
+    #[must_use]
+    pub const fn builder(location: String) -> ImageBuilder {
+        ImageBuilder::new(location)
+    }
+}
+
+impl Extension for Image {
+    fn namespace(&self) -> Namespace {
+        Namespace::new("image", IMAGE_NAMESPACE)
+    }
+
+    fn write_xml(&self, writer: &mut XmlWriter<'_>) -> io::Result<()> {
+        writer.start("image:image", &[])?;
 
         // add <image:loc>
-        let mut loc: XMLElement = XMLElement::new("image:loc");
-        loc.add_text(self.location)?;
-        image.add_child(loc)?;
+        writer.text("image:loc", &[], &self.location)?;
 
-        Ok(image)
+        writer.end("image:image")
     }
 }
@@ -0,0 +1,22 @@
+use crate::image::Image;
+
+/// A sitemap image.
+///
+/// This is a Builder for Image.
+#[derive(Debug, Clone)]
+pub struct ImageBuilder {
+    /// The URL of the image.
+    pub location: String,
+}
+
+impl ImageBuilder {
+    #[must_use]
+    pub const fn new(location: String) -> Self {
+        Self { location }
+    }
+
+    #[must_use]
+    pub fn build(&self) -> Image {
+        Image::new(self.location.clone())
+    }
+}
@@ -0,0 +1,246 @@
+use crate::date::IntoDateTime;
+use crate::error::Error;
+use crate::manifest::{Manifest, ManifestSitemap};
+use crate::sitemap::Sitemap;
+use crate::sitemap_index::SitemapIndex;
+use crate::sitemap_index_error::SitemapIndexError;
+use crate::split::{MAX_NEWS_URLS, MAX_URLS};
+use crate::url::Url;
+use crate::url_set::UrlSet;
+use crate::write_options::WriteOptions;
+use chrono::{DateTime, FixedOffset};
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+/// The result of `regenerate()`.
+#[derive(Debug)]
+pub struct Regeneration {
+    /// Every sitemap, sorted by number.
+    pub sitemaps: Vec<RegeneratedSitemap>,
+
+    /// The numbers of sitemaps from the previous manifest that no longer have any URLs, and whose files can be deleted.
+    pub removed: Vec<usize>,
+
+    /// The manifest to pass to the next `regenerate()`.
+    pub manifest: Manifest,
+}
+
+/// A single sitemap of a `Regeneration`.
+#[derive(Debug)]
+pub struct RegeneratedSitemap {
+    /// Identifies the sitemap across regenerations, such as `3` for `sitemap-3.xml`.
+    pub number: usize,
+
+    pub url_set: UrlSet,
+
+    /// Whether the contents of this sitemap differ from the previous manifest, and so its file needs to be written.
+    pub changed: bool,
+
+    /// The last modification date of this sitemap, as listed in the sitemap index.
+    pub last_modified: Option<DateTime<FixedOffset>>,
+}
+
+impl Regeneration {
+    /// Creates a `SitemapIndex` that references each sitemap.
+    ///
+    /// `location` is given the number of each sitemap and returns where it is hosted, such as `https://www.toddgriffin.me/sitemap-3.xml`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `SitemapIndexError::TooManySitemaps` if there are more than `50,000` sitemaps.
+    /// Will return `SitemapIndexError::LocationTooLong` if any `location` is 2,048 characters or more.
+    pub fn sitemap_index(
+        &self,
+        location: impl Fn(usize) -> String,
+    ) -> Result<SitemapIndex, SitemapIndexError> {
+        SitemapIndex::new(
+            self.sitemaps
+                .iter()
+                .map(|sitemap| Sitemap::new(location(sitemap.number), sitemap.last_modified))
+                .collect(),
+        )
+    }
+}
+
+/// Splits `urls` into sitemaps the way the `previous` regeneration did, so that only the sitemaps whose contents changed need to be written again.
+///
+/// URLs stay in the sitemap they were previously assigned to, and new URLs are appended to the last sitemap until it is full, and then to new sitemaps.
+/// Sitemaps are compared by hashing their contents as written with `options`.
+/// An unchanged sitemap keeps its previous last modification date, so that crawlers can skip it.
+/// A changed one is dated by the latest `last_modified` of its URLs, or `now` if that isn't any later than before.
+///
+/// Pass `Manifest::default()` as `previous` for the first regeneration.
+///
+/// # Errors
+///
+/// Will return `Error::UrlSet` if a sitemap breaks a rule, such as two extensions using the same namespace prefix for different namespace URIs.
+/// Will return `Error::Io` if a sitemap can't be written while hashing it.
+pub fn regenerate(
+    urls: Vec<Url>,
+    previous: &Manifest,
+    options: &WriteOptions,
+    now: impl IntoDateTime,
+) -> Result<Regeneration, Error> {
+    let now: DateTime<FixedOffset> = now.into_date_time();
+
+    let (assigned, removed): (Vec<(usize, Vec<Url>)>, Vec<usize>) = assign(urls, previous);
+
+    let previous_sitemaps: HashMap<usize, &ManifestSitemap> = previous
+        .sitemaps
+        .iter()
+        .map(|sitemap| (sitemap.number, sitemap))
+        .collect();
+
+    let mut sitemaps: Vec<RegeneratedSitemap> = Vec::with_capacity(assigned.len());
+    let mut manifest: Manifest = Manifest::default();
+    for (number, urls) in assigned {
+        let locations: Vec<String> = urls.iter().map(|url| url.location.clone()).collect();
+        let url_set: UrlSet = UrlSet::new(urls)?;
+
+        let mut hasher: Fnv = Fnv::new();
+        url_set.write_with_options(&mut hasher, options)?;
+        let hash: u64 = hasher.0;
+
+        let previous: Option<&&ManifestSitemap> = previous_sitemaps.get(&number);
+        let changed: bool = previous.is_none_or(|previous| previous.hash != hash);
+        let last_modified: Option<DateTime<FixedOffset>> = match previous {
+            Some(previous) if !changed => previous.last_modified,
+            previous => {
+                let latest: Option<DateTime<FixedOffset>> = url_set
+                    .urls
+                    .iter()
+                    .filter_map(|url| url.last_modified)
+                    .max();
+                let before: Option<DateTime<FixedOffset>> =
+                    previous.and_then(|previous| previous.last_modified);
+                match latest {
+                    Some(latest) if before.is_none_or(|before| latest > before) => Some(latest),
+                    _ => Some(now),
+                }
+            }
+        };
+
+        manifest.sitemaps.push(ManifestSitemap {
+            number,
+            hash,
+            last_modified,
+            locations,
+        });
+        sitemaps.push(RegeneratedSitemap {
+            number,
+            url_set,
+            changed,
+            last_modified,
+        });
+    }
+
+    Ok(Regeneration {
+        sitemaps,
+        removed,
+        manifest,
+    })
+}
+
+/// Assigns each of `urls` to the number of a sitemap, and returns them along with the numbers of sitemaps that are no longer needed.
+fn assign(urls: Vec<Url>, previous: &Manifest) -> (Vec<(usize, Vec<Url>)>, Vec<usize>) {
+    // every URL goes back into the sitemap it was in, if it still exists
+    let positions: HashMap<&str, usize> = previous
+        .sitemaps
+        .iter()
+        .enumerate()
+        .flat_map(|(position, sitemap)| {
+            sitemap
+                .locations
+                .iter()
+                .map(move |location| (location.as_str(), position))
+        })
+        .collect();
+    let mut assigned: Vec<(usize, Vec<Url>)> = previous
+        .sitemaps
+        .iter()
+        .map(|sitemap| (sitemap.number, vec![]))
+        .collect();
+    let mut pending: Vec<Url> = vec![];
+    for url in urls {
+        match positions.get(url.location.as_str()) {
+            Some(&position) => assigned[position].1.push(url),
+            None => pending.push(url),
+        }
+    }
+
+    // sitemaps that outgrew their limit (by gaining news) pass their last URLs on
+    let mut overflow: Vec<Url> = vec![];
+    for (_, urls) in &mut assigned {
+        let limit: usize = limit(has_news(urls));
+        if urls.len() > limit {
+            overflow.extend(urls.split_off(limit));
+        }
+    }
+    overflow.append(&mut pending);
+
+    let removed: Vec<usize> = assigned
+        .iter()
+        .filter(|(_, urls)| urls.is_empty())
+        .map(|(number, _)| *number)
+        .collect();
+    let mut next_number: usize = previous
+        .sitemaps
+        .last()
+        .map_or(1, |sitemap| sitemap.number + 1);
+    assigned.retain(|(_, urls)| !urls.is_empty());
+
+    // new URLs fill up the last sitemap, and then new ones
+    let mut last_has_news: bool = assigned.last().is_some_and(|(_, urls)| has_news(urls));
+    for url in overflow {
+        let url_has_news: bool = url.news.is_some();
+        let fits: bool = assigned
+            .last()
+            .is_some_and(|(_, urls)| urls.len() < limit(last_has_news || url_has_news));
+        if !fits {
+            assigned.push((next_number, vec![]));
+            next_number += 1;
+            last_has_news = false;
+        }
+        last_has_news |= url_has_news;
+        if let Some((_, urls)) = assigned.last_mut() {
+            urls.push(url);
+        }
+    }
+    if assigned.is_empty() {
+        assigned.push((next_number, vec![]));
+    }
+
+    (assigned, removed)
+}
+
+fn has_news(urls: &[Url]) -> bool {
+    urls.iter().any(|url| url.news.is_some())
+}
+
+/// The most URLs a sitemap can contain, depending on whether any of them contain news.
+const fn limit(has_news: bool) -> usize {
+    if has_news { MAX_NEWS_URLS } else { MAX_URLS }
+}
+
+/// A 64-bit FNV-1a hash, which (unlike the standard library's hashers) is stable across Rust releases, and so can be persisted.
+struct Fnv(u64);
+
+impl Fnv {
+    const fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Write for Fnv {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
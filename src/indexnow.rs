@@ -0,0 +1,241 @@
+use crate::diff::Diff;
+use crate::indexnow_error::IndexNowError;
+use crate::location::host;
+use crate::url_set::UrlSet;
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::hash::{BuildHasher, RandomState};
+use std::io;
+
+/// The shared `IndexNow` endpoint, which passes submissions on to every participating search engine (such as Bing and Yandex).
+pub const ENDPOINT: &str = "https://api.indexnow.org/indexnow";
+
+/// A single `IndexNow` submission can contain no more than 10,000 URLs.
+pub const MAX_URLS_PER_SUBMISSION: usize = 10_000;
+
+/// Sends submissions to an `IndexNow` endpoint over HTTPS, such as with a client like reqwest.
+pub trait Transport {
+    /// Sends `body`, a JSON submission, as an HTTP POST to `endpoint`, and returns the status code of the response.
+    ///
+    /// # Errors
+    ///
+    /// Will return an IO Error if the submission can't be sent, or no response is received.
+    fn post(&self, endpoint: &str, body: &str) -> io::Result<u16>;
+}
+
+impl<F: Fn(&str, &str) -> io::Result<u16>> Transport for F {
+    fn post(&self, endpoint: &str, body: &str) -> io::Result<u16> {
+        self(endpoint, body)
+    }
+}
+
+/// Notifies search engines of added, changed, and removed URLs through `IndexNow`.
+///
+/// `IndexNow` proves ownership of a host with a key: a text file containing just the key, hosted at `/{key}.txt` on every host submitted for (see `IndexNow::key_file_name()`), or at a `key_location` on that host.
+/// URLs are grouped by host, and sent in batches of `MAX_URLS_PER_SUBMISSION`.
+#[derive(Debug, Clone)]
+pub struct IndexNow {
+    key: String,
+
+    /// The hosts whose key file isn't at `/{key}.txt`, each with where it is instead.
+    key_locations: Vec<(String, String)>,
+
+    endpoint: String,
+}
+
+/// A single `IndexNow` request: up to `MAX_URLS_PER_SUBMISSION` URLs of a single host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    /// The host all `urls` belong to, such as `www.toddgriffin.me`.
+    pub host: String,
+
+    pub key: String,
+
+    /// Where the key file is hosted, if not at `/{key}.txt`.
+    pub key_location: Option<String>,
+
+    pub urls: Vec<String>,
+}
+
+impl IndexNow {
+    /// Submits to the shared `ENDPOINT`, proving ownership with `key`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `IndexNowError::InvalidKey` if `key` isn't 8 to 128 characters of `a-z`, `A-Z`, `0-9`, and `-`.
+    pub fn new(key: String) -> Result<Self, IndexNowError> {
+        let valid: bool = (8..=128).contains(&key.len())
+            && key
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-');
+        if !valid {
+            return Err(IndexNowError::InvalidKey(key));
+        }
+
+        Ok(Self {
+            key,
+            key_locations: vec![],
+            endpoint: ENDPOINT.to_string(),
+        })
+    }
+
+    /// Generates a new random key of 32 hexadecimal characters.
+    ///
+    /// The key ends up publicly hosted anyway, so it is random but not cryptographically secure.
+    #[must_use]
+    pub fn generate_key() -> String {
+        let mut key: String = String::with_capacity(32);
+        for _ in 0..2 {
+            let random: u64 = RandomState::new().hash_one(key.len());
+            let _ = write!(key, "{random:016x}");
+        }
+        key
+    }
+
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// The name of the key file to host at the root of each host, unless `key_location` is set: `{key}.txt`.
+    ///
+    /// The file contains just the key.
+    #[must_use]
+    pub fn key_file_name(&self) -> String {
+        format!("{}.txt", self.key)
+    }
+
+    /// Where the key file of the host of `key_location` is hosted, instead of at `/{key}.txt`.
+    ///
+    /// `IndexNow` only accepts a key location on the host it proves ownership of, so it is only sent with the URLs of that host; set one for each host that needs it.
+    /// Setting another key location on the same host replaces the previous one.
+    pub fn key_location(&mut self, key_location: String) -> &mut Self {
+        let Some(key_host) = host(&key_location) else {
+            return self;
+        };
+        match self
+            .key_locations
+            .iter_mut()
+            .find(|(existing, _)| *existing == key_host)
+        {
+            Some((_, existing)) => *existing = key_location,
+            None => self.key_locations.push((key_host, key_location)),
+        }
+        self
+    }
+
+    /// The endpoint to submit to, such as `https://www.bing.com/indexnow` to submit to a single search engine.
+    pub fn endpoint(&mut self, endpoint: String) -> &mut Self {
+        self.endpoint = endpoint;
+        self
+    }
+
+    /// Groups `locations` by host, and then into batches of `MAX_URLS_PER_SUBMISSION`.
+    ///
+    /// Duplicate locations are left out; otherwise, locations keep their order.
+    ///
+    /// # Errors
+    ///
+    /// Will return `IndexNowError::InvalidUrl` if any of `locations` isn't an absolute URL with a host.
+    pub fn submissions(
+        &self,
+        locations: impl IntoIterator<Item = String>,
+    ) -> Result<Vec<Submission>, IndexNowError> {
+        // hosts in order of first appearance, each with its locations
+        let mut hosts: Vec<(String, Vec<String>)> = vec![];
+        let mut seen: HashSet<String> = HashSet::new();
+        for location in locations {
+            let host: String = host(&location)
+                .filter(|host| !host.is_empty())
+                .ok_or_else(|| IndexNowError::InvalidUrl(location.clone()))?;
+            if !seen.insert(location.clone()) {
+                continue;
+            }
+            match hosts.iter_mut().find(|(existing, _)| *existing == host) {
+                Some((_, urls)) => urls.push(location),
+                None => hosts.push((host, vec![location])),
+            }
+        }
+
+        Ok(hosts
+            .into_iter()
+            .flat_map(|(host, urls)| {
+                let key_location: Option<String> = self
+                    .key_locations
+                    .iter()
+                    .find(|(key_host, _)| *key_host == host)
+                    .map(|(_, key_location)| key_location.clone());
+                urls.chunks(MAX_URLS_PER_SUBMISSION)
+                    .map(|urls| Submission {
+                        host: host.clone(),
+                        key: self.key.clone(),
+                        key_location: key_location.clone(),
+                        urls: urls.to_vec(),
+                    })
+                    .collect::<Vec<Submission>>()
+            })
+            .collect())
+    }
+
+    /// Sends every submission of `locations` through `transport`, one after another, and returns how many were sent.
+    ///
+    /// # Errors
+    ///
+    /// Will return `IndexNowError::InvalidUrl` if any of `locations` isn't an absolute URL with a host.
+    /// Will return `IndexNowError::Transport` if `transport` can't send a submission.
+    /// Will return `IndexNowError::Rejected` if the endpoint answers a submission with a status other than `2xx`; later submissions aren't sent.
+    pub fn submit(
+        &self,
+        transport: &impl Transport,
+        locations: impl IntoIterator<Item = String>,
+    ) -> Result<usize, IndexNowError> {
+        let submissions: Vec<Submission> = self.submissions(locations)?;
+        for submission in &submissions {
+            let status: u16 = transport.post(&self.endpoint, &submission.to_json())?;
+            if !(200..300).contains(&status) {
+                return Err(IndexNowError::Rejected {
+                    host: submission.host.clone(),
+                    status,
+                });
+            }
+        }
+        Ok(submissions.len())
+    }
+}
+
+impl Submission {
+    /// The body of the request, as `IndexNow` expects it.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut json: serde_json::Value = serde_json::json!({
+            "host": self.host,
+            "key": self.key,
+            "urlList": self.urls,
+        });
+        if let Some(key_location) = &self.key_location {
+            json["keyLocation"] = serde_json::Value::from(key_location.as_str());
+        }
+        json.to_string()
+    }
+}
+
+/// The locations of every URL of `url_set`, such as a sitemap that was just regenerated.
+#[must_use]
+pub fn url_set_locations(url_set: &UrlSet) -> Vec<String> {
+    url_set
+        .urls
+        .iter()
+        .map(|url| url.location.clone())
+        .collect()
+}
+
+/// The locations of every URL that was added, removed, or changed, all of which `IndexNow` wants to hear about.
+#[must_use]
+pub fn diff_locations(diff: &Diff) -> Vec<String> {
+    diff.added
+        .iter()
+        .chain(&diff.removed)
+        .map(|url| url.location.clone())
+        .chain(diff.changed.iter().map(|changes| changes.location.clone()))
+        .collect()
+}
@@ -0,0 +1,59 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+
+/// An error when building or sending `IndexNow` submissions.
+#[derive(Debug)]
+pub enum IndexNowError {
+    /// Returned when a key isn't 8 to 128 characters of `a-z`, `A-Z`, `0-9`, and `-`.
+    InvalidKey(String),
+
+    /// Returned when a URL isn't an absolute URL with a host.
+    InvalidUrl(String),
+
+    /// Returned when the `Transport` can't send a submission.
+    Transport(io::Error),
+
+    /// Returned when the search engine answers a submission with an error status.
+    ///
+    /// Submissions before this one were accepted.
+    Rejected { host: String, status: u16 },
+}
+
+impl Error for IndexNowError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Transport(error) => Some(error),
+            Self::InvalidKey(_) | Self::InvalidUrl(_) | Self::Rejected { .. } => None,
+        }
+    }
+}
+
+impl Display for IndexNowError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidKey(key) => write!(
+                f,
+                "key must be 8 to 128 characters of a-z, A-Z, 0-9, and -: {key}"
+            ),
+            Self::InvalidUrl(url) => write!(f, "URL must be absolute and have a host: {url}"),
+            Self::Transport(error) => write!(f, "{error}"),
+            Self::Rejected { host, status } => {
+                let reason: &str = match status {
+                    400 => "the submission is malformed",
+                    403 => "the key is not valid, or its key file can't be found",
+                    422 => "the URLs don't belong to the host, or the key doesn't match",
+                    429 => "too many requests",
+                    _ => "the submission was rejected",
+                };
+                write!(f, "{host}: {reason} (HTTP {status})")
+            }
+        }
+    }
+}
+
+impl From<io::Error> for IndexNowError {
+    fn from(error: io::Error) -> Self {
+        Self::Transport(error)
+    }
+}
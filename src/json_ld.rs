@@ -0,0 +1,137 @@
+use crate::date::parse_w3c_datetime;
+use crate::video::VideoDuration;
+use crate::video_builder::VideoBuilder;
+use serde_json::Value;
+
+/// Returns every schema.org `VideoObject` within the JSON-LD `json`, including those nested within other objects or an `@graph`.
+///
+/// Videos without a thumbnail, name, description, and either a content or embed URL are left out.
+/// Their URLs are returned as written, which may be relative.
+pub(crate) fn videos(json: &str) -> Vec<VideoBuilder> {
+    let Ok(value) = serde_json::from_str::<Value>(json) else {
+        return vec![];
+    };
+
+    let mut videos: Vec<VideoBuilder> = vec![];
+    collect(&value, &mut videos);
+    videos
+}
+
+fn collect(value: &Value, videos: &mut Vec<VideoBuilder>) {
+    match value {
+        Value::Array(values) => {
+            for value in values {
+                collect(value, videos);
+            }
+        }
+        Value::Object(object) => {
+            if is_video_object(value) {
+                if let Some(video) = video(value) {
+                    videos.push(video);
+                }
+                return;
+            }
+            for value in object.values() {
+                collect(value, videos);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Whether `@type` is `VideoObject`, or an array containing it.
+fn is_video_object(value: &Value) -> bool {
+    match &value["@type"] {
+        Value::String(kind) => kind == "VideoObject",
+        Value::Array(kinds) => kinds.iter().any(|kind| kind == "VideoObject"),
+        _ => false,
+    }
+}
+
+fn video(value: &Value) -> Option<VideoBuilder> {
+    let thumbnail_location: String = url(&value["thumbnailUrl"])?;
+    let title: String = string(&value["name"])?;
+    let description: String = string(&value["description"])?;
+    let content_location: Option<String> = url(&value["contentUrl"]);
+    let player_location: Option<String> = url(&value["embedUrl"]);
+    if content_location.is_none() && player_location.is_none() {
+        return None;
+    }
+
+    let mut builder: VideoBuilder = VideoBuilder::new(
+        thumbnail_location,
+        title,
+        description,
+        content_location.unwrap_or_default(),
+        player_location.unwrap_or_default(),
+    );
+    builder.truncate_long_text(true);
+
+    // optional properties are left out if they can't be represented, rather than leaving out the whole video
+    if let Some(duration) = value["duration"]
+        .as_str()
+        .and_then(parse_duration)
+        .filter(|duration| VideoDuration::new(*duration).is_ok())
+    {
+        builder.duration(duration);
+    }
+    if let Some(publication_date) = value["uploadDate"].as_str().and_then(parse_w3c_datetime) {
+        builder.publication_date(publication_date);
+    }
+    if let Some(expiration_date) = value["expires"].as_str().and_then(parse_w3c_datetime) {
+        builder.expiration_date(expiration_date);
+    }
+    if let Some(family_friendly) = value["isFamilyFriendly"].as_bool() {
+        builder.family_friendly(family_friendly);
+    }
+
+    Some(builder)
+}
+
+/// A non-empty string, or the first of an array of them.
+fn string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.trim().to_string()).filter(|text| !text.is_empty()),
+        Value::Array(values) => values.iter().find_map(string),
+        _ => None,
+    }
+}
+
+/// A URL, which may also be written as an `ImageObject` or similar with a `url` property.
+fn url(value: &Value) -> Option<String> {
+    match value {
+        Value::Object(_) => string(&value["url"]),
+        Value::Array(values) => values.iter().find_map(url),
+        value => string(value),
+    }
+}
+
+/// Parses an ISO 8601 duration of days, hours, minutes, and seconds, such as `PT1M33S`, into seconds.
+fn parse_duration(text: &str) -> Option<u16> {
+    let text: &str = text.strip_prefix('P')?;
+    let (date, time): (&str, &str) = text.split_once('T').unwrap_or((text, ""));
+
+    let mut seconds: f64 = 0.0;
+    for (part, units) in [
+        (date, &[('D', 86_400.0)][..]),
+        (time, &[('H', 3_600.0), ('M', 60.0), ('S', 1.0)][..]),
+    ] {
+        let mut rest: &str = part;
+        for &(designator, scale) in units {
+            if let Some((number, remainder)) = rest.split_once(designator) {
+                seconds += number.parse::<f64>().ok()? * scale;
+                rest = remainder;
+            }
+        }
+        if !rest.is_empty() {
+            return None;
+        }
+    }
+
+    let seconds: f64 = seconds.round();
+    if seconds < 0.0 || seconds > f64::from(u16::MAX) {
+        return None;
+    }
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Some(seconds as u16)
+}
@@ -5,7 +5,7 @@
 //! `cargo run --example generate_url_sitemap`
 //!
 //! ```rust
-//! use chrono::{DateTime, FixedOffset, NaiveDate};
+//! use chrono::NaiveDate;
 //! use sitemap_rs::url::{ChangeFrequency, Link, Url};
 //! use sitemap_rs::url_set::UrlSet;
 //!
@@ -15,13 +15,12 @@
 //!             "de".to_owned(),
 //!             "https://www.toddgriffin.me/de".to_owned(),
 //!         )])
-//!         .last_modified(DateTime::from_naive_utc_and_offset(
+//!         .last_modified(
 //!             NaiveDate::from_ymd_opt(1998, 1, 15)
 //!                 .unwrap()
 //!                 .and_hms_opt(4, 20, 0)
 //!                 .unwrap(),
-//!             FixedOffset::east_opt(0).unwrap(),
-//!         ))
+//!         )
 //!         .change_frequency(ChangeFrequency::Monthly)
 //!         .priority(0.69)
 //!         .build()
@@ -52,19 +51,62 @@
 
 use chrono::SecondsFormat;
 
+#[cfg(feature = "crawl")]
+pub mod crawl;
+#[cfg(feature = "crawl")]
+pub mod crawl_error;
+pub mod date;
+pub mod date_error;
+pub mod diff;
+pub mod error;
+pub mod extension;
+mod html;
 pub mod image;
+pub mod image_builder;
+pub mod incremental;
+#[cfg(feature = "indexnow")]
+pub mod indexnow;
+#[cfg(feature = "indexnow")]
+pub mod indexnow_error;
+#[cfg(feature = "json-ld")]
+mod json_ld;
+mod location;
+pub mod manifest;
+pub mod manifest_error;
+pub mod merge;
 pub mod news;
+pub mod news_builder;
+#[cfg(feature = "parse")]
+pub mod parse;
+#[cfg(feature = "parse")]
+pub mod parse_error;
+pub mod rejected;
+pub mod resolve;
 pub mod sitemap;
+pub mod sitemap_builder;
 pub mod sitemap_index;
+pub mod sitemap_index_builder;
 pub mod sitemap_index_error;
+pub mod split;
+pub mod static_site;
+pub mod static_site_error;
+pub mod stylesheet;
+pub mod truncate;
 pub mod url;
 pub mod url_builder;
 pub mod url_error;
 pub mod url_set;
+pub mod url_set_builder;
 pub mod url_set_error;
+pub mod validation_error;
 pub mod video;
 pub mod video_builder;
 pub mod video_error;
+pub mod write_options;
+pub mod xml_version;
+pub mod xml_writer;
+
+pub use error::Error;
 
 pub const NAMESPACE: &str = "http://www.sitemaps.org/schemas/sitemap/0.9";
 pub const XHTML_NAMESPACE: &str = "http://www.w3.org/1999/xhtml";
@@ -0,0 +1,10 @@
+use crate::merge::normalize_location;
+
+/// The host (and port, if it isn't the default) of `location`, lowercased.
+pub(crate) fn host(location: &str) -> Option<String> {
+    let location: String = normalize_location(location);
+    let (_, rest): (&str, &str) = location.split_once("://")?;
+    let authority: &str = rest.split(['/', '?']).next().unwrap_or_default();
+    let host: &str = authority.rsplit('@').next().unwrap_or(authority);
+    Some(host.to_string())
+}
@@ -0,0 +1,125 @@
+use crate::date::parse_w3c_datetime;
+use crate::manifest_error::ManifestError;
+use chrono::{DateTime, FixedOffset, SecondsFormat};
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+/// The first line of every manifest, which versions its format.
+const HEADER: &str = "sitemap-rs manifest 1";
+
+/// What the previous regeneration wrote: which sitemap each URL went into, and what each sitemap contained.
+///
+/// See `incremental::regenerate()`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Manifest {
+    /// Sorted by `number`.
+    pub sitemaps: Vec<ManifestSitemap>,
+}
+
+/// A single sitemap within a `Manifest`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestSitemap {
+    /// Identifies the sitemap across regenerations, such as `3` for `sitemap-3.xml`.
+    pub number: usize,
+
+    /// A hash of the written sitemap, which changes whenever its contents do.
+    pub hash: u64,
+
+    /// The last modification date listed for this sitemap in the sitemap index.
+    pub last_modified: Option<DateTime<FixedOffset>>,
+
+    /// The \<loc\> of each of its URLs, in order.
+    pub locations: Vec<String>,
+}
+
+impl Manifest {
+    /// Reads a manifest written by `Manifest::write()`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `ManifestError::Io` if `reader` fails.
+    /// Will return `ManifestError::Invalid` if any line isn't in the format `Manifest::write()` writes.
+    pub fn read(reader: impl Read) -> Result<Self, ManifestError> {
+        let mut sitemaps: Vec<ManifestSitemap> = vec![];
+
+        for (index, line) in BufReader::new(reader).lines().enumerate() {
+            let line: String = line?;
+            let invalid = |message: &str| ManifestError::Invalid {
+                line: index + 1,
+                message: message.to_string(),
+            };
+
+            if index == 0 {
+                if line != HEADER {
+                    return Err(invalid("not a sitemap-rs manifest"));
+                }
+                continue;
+            }
+
+            // URLs are indented by a tab beneath the sitemap they belong to
+            if let Some(location) = line.strip_prefix('\t') {
+                sitemaps
+                    .last_mut()
+                    .ok_or_else(|| invalid("URL before any sitemap"))?
+                    .locations
+                    .push(location.to_string());
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split(' ').collect();
+            let ["sitemap", number, hash, last_modified] = fields.as_slice() else {
+                return Err(invalid("expected `sitemap <number> <hash> <lastmod>`"));
+            };
+            let number: usize = number
+                .parse()
+                .map_err(|_| invalid("sitemap number must be a whole number"))?;
+            if sitemaps.last().is_some_and(|last| last.number >= number) {
+                return Err(invalid("sitemaps must be in ascending order"));
+            }
+            let hash: u64 =
+                u64::from_str_radix(hash, 16).map_err(|_| invalid("hash must be hexadecimal"))?;
+            let last_modified: Option<DateTime<FixedOffset>> = match *last_modified {
+                "-" => None,
+                text => Some(
+                    parse_w3c_datetime(text)
+                        .ok_or_else(|| invalid("lastmod must be a W3C Datetime"))?,
+                ),
+            };
+
+            sitemaps.push(ManifestSitemap {
+                number,
+                hash,
+                last_modified,
+                locations: vec![],
+            });
+        }
+
+        Ok(Self { sitemaps })
+    }
+
+    /// Writes the manifest as text, one line per sitemap followed by one line per URL.
+    ///
+    /// # Errors
+    ///
+    /// Will return `io::Error` if `writer` fails.
+    pub fn write(&self, writer: impl Write) -> io::Result<()> {
+        let mut writer: io::BufWriter<_> = io::BufWriter::new(writer);
+        writeln!(writer, "{HEADER}")?;
+
+        for sitemap in &self.sitemaps {
+            let last_modified: String = sitemap.last_modified.map_or_else(
+                || String::from("-"),
+                |last_modified| last_modified.to_rfc3339_opts(SecondsFormat::AutoSi, false),
+            );
+            writeln!(
+                writer,
+                "sitemap {} {:016x} {last_modified}",
+                sitemap.number, sitemap.hash
+            )?;
+            for location in &sitemap.locations {
+                writeln!(writer, "\t{location}")?;
+            }
+        }
+
+        writer.flush()
+    }
+}
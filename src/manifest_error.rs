@@ -0,0 +1,37 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+
+/// An error when reading a `Manifest`.
+#[derive(Debug)]
+pub enum ManifestError {
+    /// Returned when the manifest can't be read.
+    Io(io::Error),
+
+    /// Returned when a line of the manifest isn't in the format `Manifest::write()` writes.
+    Invalid { line: usize, message: String },
+}
+
+impl Error for ManifestError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Invalid { .. } => None,
+        }
+    }
+}
+
+impl Display for ManifestError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "{error}"),
+            Self::Invalid { line, message } => write!(f, "line {line}: {message}"),
+        }
+    }
+}
+
+impl From<io::Error> for ManifestError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
@@ -0,0 +1,317 @@
+use crate::url::Url;
+use crate::url_set::UrlSet;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+
+/// How to resolve a field that duplicate URLs disagree on.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Resolution {
+    /// Keep the value of the URL that came first.
+    First,
+
+    /// Keep the value of the URL that came last.
+    Last,
+
+    /// Combine the values: the newest `last_modified`, the most frequent `change_frequency`, the highest `priority`,
+    /// the first `news`, and the union of `images`, `videos`, `links`, and `extensions`.
+    #[default]
+    Combine,
+}
+
+/// Merges URLs from several sources, such as the partial `UrlSet`s of several services, into one list without duplicate locations.
+///
+/// URLs are matched up by their normalized location (see `normalize_location()`), and listed in the order their location first appeared.
+/// Each field has its own `Resolution`, which all default to `Resolution::Combine`.
+#[derive(Debug, Clone)]
+pub struct Merger {
+    last_modified: Resolution,
+    change_frequency: Resolution,
+    priority: Resolution,
+    images: Resolution,
+    videos: Resolution,
+    news: Resolution,
+    links: Resolution,
+    extensions: Resolution,
+}
+
+/// The result of `Merger::merge()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Merged {
+    /// One URL per normalized location.
+    pub urls: Vec<Url>,
+
+    /// Every location whose duplicates disagreed on any field, in the order of `urls`.
+    pub conflicts: Vec<Conflict>,
+}
+
+/// Duplicates of a location that disagreed on some of their fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict {
+    /// The normalized location.
+    pub location: String,
+
+    /// How many URLs had this location.
+    pub duplicates: usize,
+
+    /// The fields they disagreed on, named as in the sitemap, such as `lastmod`.
+    pub fields: Vec<&'static str>,
+}
+
+impl Merger {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            last_modified: Resolution::Combine,
+            change_frequency: Resolution::Combine,
+            priority: Resolution::Combine,
+            images: Resolution::Combine,
+            videos: Resolution::Combine,
+            news: Resolution::Combine,
+            links: Resolution::Combine,
+            extensions: Resolution::Combine,
+        }
+    }
+
+    pub const fn last_modified(&mut self, resolution: Resolution) -> &mut Self {
+        self.last_modified = resolution;
+        self
+    }
+
+    pub const fn change_frequency(&mut self, resolution: Resolution) -> &mut Self {
+        self.change_frequency = resolution;
+        self
+    }
+
+    pub const fn priority(&mut self, resolution: Resolution) -> &mut Self {
+        self.priority = resolution;
+        self
+    }
+
+    pub const fn images(&mut self, resolution: Resolution) -> &mut Self {
+        self.images = resolution;
+        self
+    }
+
+    pub const fn videos(&mut self, resolution: Resolution) -> &mut Self {
+        self.videos = resolution;
+        self
+    }
+
+    pub const fn news(&mut self, resolution: Resolution) -> &mut Self {
+        self.news = resolution;
+        self
+    }
+
+    pub const fn links(&mut self, resolution: Resolution) -> &mut Self {
+        self.links = resolution;
+        self
+    }
+
+    pub const fn extensions(&mut self, resolution: Resolution) -> &mut Self {
+        self.extensions = resolution;
+        self
+    }
+
+    /// Merges `urls`, giving each the normalized location it was matched up by.
+    ///
+    /// The merged URLs aren't validated again, so combining `images` can exceed the limit of `1,000`; check with `UrlSet::validate()`.
+    #[must_use]
+    pub fn merge(&self, urls: impl IntoIterator<Item = Url>) -> Merged {
+        let mut groups: Vec<Vec<Url>> = vec![];
+        let mut indexes: HashMap<String, usize> = HashMap::new();
+
+        for mut url in urls {
+            url.location = normalize_location(&url.location);
+            if let Some(&index) = indexes.get(&url.location) {
+                groups[index].push(url);
+            } else {
+                indexes.insert(url.location.clone(), groups.len());
+                groups.push(vec![url]);
+            }
+        }
+
+        let mut merged: Merged = Merged {
+            urls: Vec::with_capacity(groups.len()),
+            conflicts: vec![],
+        };
+        for group in groups {
+            let fields: Vec<&'static str> = disagreements(&group);
+            if !fields.is_empty() {
+                merged.conflicts.push(Conflict {
+                    location: group[0].location.clone(),
+                    duplicates: group.len(),
+                    fields,
+                });
+            }
+            merged.urls.push(self.resolve(group));
+        }
+
+        merged
+    }
+
+    /// Merges the URLs of every one of `url_sets`.
+    #[must_use]
+    pub fn merge_url_sets(&self, url_sets: impl IntoIterator<Item = UrlSet>) -> Merged {
+        self.merge(url_sets.into_iter().flat_map(|url_set| url_set.urls))
+    }
+
+    /// Resolves a group of URLs with the same location into one.
+    fn resolve(&self, group: Vec<Url>) -> Url {
+        let mut group: std::vec::IntoIter<Url> = group.into_iter();
+        let mut merged: Url = group.next().expect("every group has at least one URL");
+
+        for url in group {
+            merged.last_modified = pick(
+                self.last_modified,
+                merged.last_modified,
+                url.last_modified,
+                Ord::max,
+            );
+            // change frequencies are ordered from most to least frequent
+            merged.change_frequency = pick(
+                self.change_frequency,
+                merged.change_frequency,
+                url.change_frequency,
+                Ord::min,
+            );
+            merged.priority = pick(self.priority, merged.priority, url.priority, Ord::max);
+            merged.news = pick(self.news, merged.news, url.news, |first, _| first);
+
+            merged.images = pick(
+                self.images,
+                merged.images,
+                url.images,
+                |mut first, second| {
+                    for image in second {
+                        if !first
+                            .iter()
+                            .any(|existing| existing.location == image.location)
+                        {
+                            first.push(image);
+                        }
+                    }
+                    first
+                },
+            );
+            merged.videos = pick(self.videos, merged.videos, url.videos, union);
+            merged.links = match self.links {
+                Resolution::First => merged.links,
+                Resolution::Last => url.links,
+                Resolution::Combine => union(merged.links, url.links),
+            };
+            merged.extensions = match self.extensions {
+                Resolution::First => merged.extensions,
+                Resolution::Last => url.extensions,
+                Resolution::Combine => union(merged.extensions, url.extensions),
+            };
+        }
+
+        merged
+    }
+}
+
+impl Default for Merger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for Conflict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} duplicates disagree on {}",
+            self.location,
+            self.duplicates,
+            self.fields.join(", ")
+        )
+    }
+}
+
+/// Normalizes `location` for comparison, so that URLs that only differ in how they're written are treated as duplicates.
+///
+/// The scheme and host are lowercased, default ports (`:80` for http and `:443` for https) and fragments are removed,
+/// and an empty path becomes `/`.
+/// The path and query are left alone, since servers may treat them as case-sensitive.
+#[must_use]
+pub fn normalize_location(location: &str) -> String {
+    let location: &str = location.trim();
+    let location: &str = location.split('#').next().unwrap_or_default();
+    let Some((scheme, rest)) = location.split_once("://") else {
+        return location.to_string();
+    };
+    let scheme: String = scheme.to_ascii_lowercase();
+
+    let authority_end: usize = rest.find(['/', '?']).unwrap_or(rest.len());
+    let (authority, path): (&str, &str) = rest.split_at(authority_end);
+    let mut authority: String = authority.to_ascii_lowercase();
+    let default_port: &str = match scheme.as_str() {
+        "http" => ":80",
+        "https" => ":443",
+        _ => "",
+    };
+    if !default_port.is_empty() && authority.ends_with(default_port) {
+        authority.truncate(authority.len() - default_port.len());
+    }
+
+    let slash: &str = if path.starts_with('/') { "" } else { "/" };
+    format!("{scheme}://{authority}{slash}{path}")
+}
+
+/// Picks between the values of two duplicates, or combines them with `combine` if both are set.
+fn pick<T>(
+    resolution: Resolution,
+    first: Option<T>,
+    second: Option<T>,
+    combine: impl FnOnce(T, T) -> T,
+) -> Option<T> {
+    match (resolution, first, second) {
+        (Resolution::First, first, _) => first,
+        (Resolution::Last, _, second) => second,
+        (Resolution::Combine, Some(first), Some(second)) => Some(combine(first, second)),
+        (Resolution::Combine, first, second) => first.or(second),
+    }
+}
+
+/// `first` followed by the items of `second` it doesn't already contain.
+fn union<T: Eq + Hash>(mut first: Vec<T>, second: Vec<T>) -> Vec<T> {
+    let mut seen: HashSet<&T> = first.iter().collect();
+    let keep: Vec<bool> = second.iter().map(|item| seen.insert(item)).collect();
+    first.extend(
+        second
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(item, keep)| keep.then_some(item)),
+    );
+    first
+}
+
+/// Whether two URLs agree on a single field.
+type FieldEquals = fn(&Url, &Url) -> bool;
+
+/// The fields that any of `group` disagree on.
+fn disagreements(group: &[Url]) -> Vec<&'static str> {
+    let Some((first, rest)) = group.split_first() else {
+        return vec![];
+    };
+
+    let fields: [(&'static str, FieldEquals); 8] = [
+        ("lastmod", |a, b| a.last_modified == b.last_modified),
+        ("changefreq", |a, b| {
+            a.change_frequency == b.change_frequency
+        }),
+        ("priority", |a, b| a.priority == b.priority),
+        ("images", |a, b| a.images == b.images),
+        ("videos", |a, b| a.videos == b.videos),
+        ("news", |a, b| a.news == b.news),
+        ("links", |a, b| a.links == b.links),
+        ("extensions", |a, b| a.extensions == b.extensions),
+    ];
+
+    fields
+        .into_iter()
+        .filter(|(_, equal)| rest.iter().any(|url| !equal(first, url)))
+        .map(|(field, _)| field)
+        .collect()
+}
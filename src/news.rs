@@ -1,9 +1,13 @@
-use crate::{RFC_3339_SECONDS_FORMAT, RFC_3339_USE_Z};
+use crate::NEWS_NAMESPACE;
+use crate::date::{IntoDateTime, TryIntoDateTime};
+use crate::extension::{Extension, Namespace};
+use crate::news_builder::{NewsBuilder, PublicationBuilder};
+use crate::xml_writer::XmlWriter;
 use chrono::{DateTime, FixedOffset};
-use xml_builder::{XMLElement, XMLError};
+use std::io;
 
 /// A sitemap news.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct News {
     /// The publication where the article appears.
     pub publication: Publication,
@@ -24,46 +28,51 @@ pub struct News {
 
 impl News {
     #[must_use]
-    pub const fn new(
+    pub fn new(
         publication: Publication,
-        publication_date: DateTime<FixedOffset>,
+        publication_date: impl IntoDateTime,
         title: String,
     ) -> Self {
         Self {
             publication,
-            publication_date,
+            publication_date: publication_date.into_date_time(),
             title,
         }
     }
 
-    /// # Errors
-    ///
-    /// Will return `XMLError` if there is a problem creating XML elements.
-    pub fn to_xml(self) -> Result<XMLElement, XMLError> {
-        let mut news: XMLElement = XMLElement::new("news:news");
+    #[must_use]
+    pub fn builder(
+        publication: Publication,
+        publication_date: impl TryIntoDateTime,
+        title: String,
+    ) -> NewsBuilder {
+        NewsBuilder::new(publication, publication_date, title)
+    }
+}
+
+impl Extension for News {
+    fn namespace(&self) -> Namespace {
+        Namespace::new("news", NEWS_NAMESPACE)
+    }
+
+    fn write_xml(&self, writer: &mut XmlWriter<'_>) -> io::Result<()> {
+        writer.start("news:news", &[])?;
 
         // add <news:publication>
-        news.add_child(self.publication.to_xml()?)?;
+        self.publication.write_xml(writer)?;
 
         // add <news:publication_date>
-        let mut publication_date: XMLElement = XMLElement::new("news:publication_date");
-        publication_date.add_text(
-            self.publication_date
-                .to_rfc3339_opts(RFC_3339_SECONDS_FORMAT, RFC_3339_USE_Z),
-        )?;
-        news.add_child(publication_date)?;
+        writer.date("news:publication_date", &self.publication_date)?;
 
         // add <news:title>
-        let mut title: XMLElement = XMLElement::new("news:title");
-        title.add_text(self.title)?;
-        news.add_child(title)?;
+        writer.text("news:title", &[], &self.title)?;
 
-        Ok(news)
+        writer.end("news:news")
     }
 }
 
 /// The publication where the article appears.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Publication {
     /// The <name> tag is the name of the news publication.
     ///
@@ -83,22 +92,23 @@ impl Publication {
         Self { name, language }
     }
 
+    #[must_use]
+    pub const fn builder(name: String, language: String) -> PublicationBuilder {
+        PublicationBuilder::new(name, language)
+    }
+
     /// # Errors
     ///
-    /// Will return `XMLError` if there is a problem creating XML elements.
-    pub fn to_xml(self) -> Result<XMLElement, XMLError> {
-        let mut publication: XMLElement = XMLElement::new("news:publication");
+    /// Will return an IO Error if there is a problem writing to the underlying writer.
+    pub(crate) fn write_xml(&self, writer: &mut XmlWriter<'_>) -> io::Result<()> {
+        writer.start("news:publication", &[])?;
 
         // add <news:name>
-        let mut name: XMLElement = XMLElement::new("news:name");
-        name.add_text(self.name)?;
-        publication.add_child(name)?;
+        writer.text("news:name", &[], &self.name)?;
 
         // add <news:language>
-        let mut language: XMLElement = XMLElement::new("news:language");
-        language.add_text(self.language)?;
-        publication.add_child(language)?;
+        writer.text("news:language", &[], &self.language)?;
 
-        Ok(publication)
+        writer.end("news:publication")
     }
 }
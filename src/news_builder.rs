@@ -0,0 +1,109 @@
+use crate::date::TryIntoDateTime;
+use crate::date_error::DateError;
+use crate::news::{News, Publication};
+use chrono::{DateTime, FixedOffset};
+
+/// A sitemap news.
+///
+/// This is a Builder for News.
+#[derive(Debug, Clone)]
+pub struct NewsBuilder {
+    /// The publication where the article appears.
+    pub publication: Publication,
+
+    /// The article publication date in W3C format.
+    pub publication_date: DateTime<FixedOffset>,
+
+    /// The title of the news article.
+    pub title: String,
+
+    /// The error from converting the date given to `publication_date`, returned by `build`.
+    publication_date_error: Option<DateError>,
+}
+
+impl NewsBuilder {
+    /// If `publication_date` can't be converted, `build` returns the error.
+    #[must_use]
+    pub fn new(
+        publication: Publication,
+        publication_date: impl TryIntoDateTime,
+        title: String,
+    ) -> Self {
+        let mut builder: Self = Self {
+            publication,
+            publication_date: DateTime::default(),
+            title,
+            publication_date_error: None,
+        };
+        builder.publication_date(publication_date);
+        builder
+    }
+
+    pub fn publication(&mut self, publication: Publication) -> &mut Self {
+        self.publication = publication;
+        self
+    }
+
+    /// Sets `publication_date`; if the date can't be converted, `build` returns the error.
+    pub fn publication_date(&mut self, publication_date: impl TryIntoDateTime) -> &mut Self {
+        let publication_date: Result<DateTime<FixedOffset>, DateError> =
+            publication_date.try_into_date_time();
+        self.publication_date_error = publication_date.as_ref().err().cloned();
+        self.publication_date = publication_date.unwrap_or_default();
+        self
+    }
+
+    pub fn title(&mut self, title: String) -> &mut Self {
+        self.title = title;
+        self
+    }
+
+    /// # Errors
+    ///
+    /// Will return the `DateError` if the date given to `publication_date` couldn't be converted.
+    pub fn build(&self) -> Result<News, DateError> {
+        if let Some(error) = &self.publication_date_error {
+            return Err(error.clone());
+        }
+
+        Ok(News::new(
+            self.publication.clone(),
+            self.publication_date,
+            self.title.clone(),
+        ))
+    }
+}
+
+/// The publication where the article appears.
+///
+/// This is a Builder for Publication.
+#[derive(Debug, Clone)]
+pub struct PublicationBuilder {
+    /// The name of the news publication.
+    pub name: String,
+
+    /// The language of your publication, as an ISO 639 language code.
+    pub language: String,
+}
+
+impl PublicationBuilder {
+    #[must_use]
+    pub const fn new(name: String, language: String) -> Self {
+        Self { name, language }
+    }
+
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.name = name;
+        self
+    }
+
+    pub fn language(&mut self, language: String) -> &mut Self {
+        self.language = language;
+        self
+    }
+
+    #[must_use]
+    pub fn build(&self) -> Publication {
+        Publication::new(self.name.clone(), self.language.clone())
+    }
+}
@@ -0,0 +1,1116 @@
+use crate::date::parse_w3c_datetime;
+use crate::extension::{Extension, ExtensionElement, Namespace};
+use crate::image::Image;
+use crate::news::{News, Publication};
+use crate::parse_error::ParseError;
+use crate::sitemap::Sitemap;
+use crate::sitemap_index::SitemapIndex;
+use crate::url::{ChangeFrequency, Link, Priority, Url};
+use crate::url_set::UrlSet;
+use crate::video::{
+    Platform, PlatformType, Relationship, Restriction, TagList, Uploader, Video, VideoDuration,
+    VideoRating,
+};
+use crate::xml_version::XmlVersion;
+use crate::{
+    ENCODING, IMAGE_NAMESPACE, NAMESPACE, NEWS_NAMESPACE, VIDEO_NAMESPACE, XHTML_NAMESPACE,
+};
+use chrono::{DateTime, FixedOffset};
+use quick_xml::NsReader;
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::{BytesPI, BytesRef, BytesStart, Event};
+use quick_xml::name::ResolveResult;
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// The largest a sitemap file can be, uncompressed: 50MB.
+pub const MAX_FILE_SIZE: usize = 52_428_800;
+
+/// A sitemap file read by `parse()`.
+#[derive(Debug, Clone)]
+pub enum Document {
+    /// A file with a \<urlset\> root element.
+    UrlSet(UrlSet),
+
+    /// A file with a \<sitemapindex\> root element.
+    SitemapIndex(SitemapIndex),
+}
+
+/// A sitemap file, along with every rule it broke.
+#[derive(Debug, Clone)]
+pub struct Parsed {
+    /// Everything that could be read from the file.
+    ///
+    /// Values that broke a rule, such as a `priority` of `4.2`, are left out.
+    pub document: Document,
+
+    /// Every rule the file broke, in the order they appear within the file.
+    pub problems: Vec<Problem>,
+}
+
+/// A single rule violation found within a sitemap file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    /// The line of the file the problem was found on, starting at 1.
+    pub line: usize,
+
+    /// The path to the offending field, such as `urls[17].videos[2].description`.
+    pub path: String,
+
+    /// What went wrong.
+    pub message: String,
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}: {}", self.line, self.path, self.message)
+    }
+}
+
+/// Reads a \<urlset\> or \<sitemapindex\> file, and checks it against every rule this library knows.
+///
+/// Besides the rules checked by `UrlSet::validate()` and `Sitemap::validate()`, this checks that:
+/// the elements are in the right namespaces, required elements are present,
+/// locations are absolute `http` or `https` URLs, and dates, numbers, and other values are well-formed.
+///
+/// # Errors
+///
+/// Will return `ParseError::Io` if there is an IO Error reading from `reader`.
+/// Will return `ParseError::TooLarge` if the file is larger than `50MB` (`MAX_FILE_SIZE`), without reading the rest of it.
+/// Will return `ParseError::NotUtf8` if the file is not UTF-8 encoded.
+/// Will return `ParseError::Xml` if the file is not well-formed XML.
+/// Will return `ParseError::NotASitemap` if the root element is neither a \<urlset\> nor a \<sitemapindex\>.
+pub fn parse(reader: impl Read) -> Result<Parsed, ParseError> {
+    // sitemaps can be no larger than 50MB, uncompressed; reading one byte more tells if it is
+    let mut bytes: Vec<u8> = vec![];
+    reader
+        .take(MAX_FILE_SIZE as u64 + 1)
+        .read_to_end(&mut bytes)?;
+    if bytes.len() > MAX_FILE_SIZE {
+        return Err(ParseError::TooLarge);
+    }
+    let text: &str = std::str::from_utf8(&bytes).map_err(ParseError::NotUtf8)?;
+
+    let (prolog, root): (Prolog, Element) = read_tree(text)?;
+    let mut parser: Parser = Parser {
+        sitemap_namespace: root.namespace.clone(),
+        problems: vec![],
+    };
+
+    if root.name != "urlset" && root.name != "sitemapindex" {
+        return Err(ParseError::NotASitemap(root.name));
+    }
+
+    // the root element must be in the sitemap namespace
+    if root.namespace.as_deref() != Some(NAMESPACE) {
+        parser.problem(
+            root.line,
+            "xmlns",
+            format!(
+                "root element must be in the sitemap namespace {NAMESPACE}: {}",
+                root.namespace.as_deref().unwrap_or("no namespace")
+            ),
+        );
+    }
+
+    let document: Document = if root.name == "urlset" {
+        Document::UrlSet(parser.url_set(prolog, &root))
+    } else {
+        Document::SitemapIndex(parser.sitemap_index(prolog, &root))
+    };
+
+    let mut problems: Vec<Problem> = parser.problems;
+    problems.sort_by_key(|problem| problem.line);
+    Ok(Parsed { document, problems })
+}
+
+/// The XML declaration and processing instructions before the root element.
+struct Prolog {
+    xml_version: XmlVersion,
+    xml_encoding: String,
+    stylesheet: Option<String>,
+}
+
+/// A single XML element, with its namespace already resolved.
+struct Element {
+    /// The namespace URI, if the element is in one.
+    namespace: Option<String>,
+
+    /// The namespace prefix, if the element's name has one.
+    prefix: Option<String>,
+
+    /// The name without its namespace prefix.
+    name: String,
+
+    /// Every attribute other than namespace declarations.
+    attributes: Vec<(String, String)>,
+
+    /// Namespaces declared on this element with `xmlns:`.
+    namespaces: Vec<Namespace>,
+
+    /// All text directly within this element, trimmed.
+    text: String,
+
+    children: Vec<Element>,
+
+    /// The line the element starts on.
+    line: usize,
+}
+
+impl Element {
+    /// The name of the element as written in the file, such as `video:title`.
+    fn qualified_name(&self) -> String {
+        match &self.prefix {
+            Some(prefix) => format!("{prefix}:{}", self.name),
+            None => self.name.clone(),
+        }
+    }
+
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Reads `text` into a tree of elements.
+fn read_tree(text: &str) -> Result<(Prolog, Element), ParseError> {
+    let newlines: Vec<usize> = text
+        .bytes()
+        .enumerate()
+        .filter(|(_, byte)| *byte == b'\n')
+        .map(|(index, _)| index)
+        .collect();
+    let line = |position: u64| -> usize {
+        let position: usize = usize::try_from(position).unwrap_or(usize::MAX);
+        newlines.partition_point(|newline| *newline < position) + 1
+    };
+
+    let mut reader: NsReader<&[u8]> = NsReader::from_reader(text.as_bytes());
+    let mut buf: Vec<u8> = vec![];
+    let mut prolog: Prolog = Prolog {
+        xml_version: XmlVersion::Xml1_0,
+        xml_encoding: ENCODING.to_string(),
+        stylesheet: None,
+    };
+    let mut stack: Vec<Element> = vec![];
+    let mut root: Option<Element> = None;
+
+    loop {
+        buf.clear();
+        let start: usize = line(reader.buffer_position());
+        let (namespace, event): (Option<String>, Event<'_>) =
+            match reader.read_resolved_event_into(&mut buf) {
+                Ok((ResolveResult::Bound(namespace), event)) => {
+                    (Some(namespace.as_ref().to_string()), event)
+                }
+                Ok((ResolveResult::Unbound | ResolveResult::Unknown(_), event)) => (None, event),
+                Err(error) => {
+                    let message: String = error.to_string();
+                    return Err(ParseError::Xml {
+                        line: line(reader.error_position()),
+                        message,
+                    });
+                }
+            };
+
+        match event {
+            Event::Start(start_tag) => {
+                stack.push(element(&start_tag, namespace, start)?);
+            }
+            Event::Empty(start_tag) => {
+                let element: Element = element(&start_tag, namespace, start)?;
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(element),
+                    None => set_root(&mut root, element)?,
+                }
+            }
+            Event::End(_) => {
+                let mut element: Element = stack.pop().expect("the reader checks end tags");
+                element.text = element.text.trim().to_string();
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(element),
+                    None => set_root(&mut root, element)?,
+                }
+            }
+            Event::Text(text) => {
+                if let Some(element) = stack.last_mut() {
+                    element.text.push_str(&text.xml10_content());
+                }
+            }
+            Event::CData(text) => {
+                if let Some(element) = stack.last_mut() {
+                    element.text.push_str(&text.xml10_content());
+                }
+            }
+            Event::GeneralRef(reference) => {
+                let resolved: String = resolve_reference(&reference, start)?;
+                if let Some(element) = stack.last_mut() {
+                    element.text.push_str(&resolved);
+                }
+            }
+            Event::Decl(declaration) => {
+                if declaration.version().is_ok_and(|version| version == "1.1") {
+                    prolog.xml_version = XmlVersion::Xml1_1;
+                }
+                if let Some(Ok(encoding)) = declaration.encoding() {
+                    prolog.xml_encoding = encoding.to_string();
+                }
+            }
+            Event::PI(instruction) => {
+                if instruction.target() == "xml-stylesheet" {
+                    prolog.stylesheet = stylesheet(&instruction, start)?;
+                }
+            }
+            Event::Eof => break,
+            Event::Comment(_) | Event::DocType(_) => {}
+        }
+    }
+
+    let root: Element = root.ok_or_else(|| ParseError::Xml {
+        line: line(reader.buffer_position()),
+        message: String::from("there is no root element"),
+    })?;
+    Ok((prolog, root))
+}
+
+/// Reads the unescaped `href` of an `<?xml-stylesheet ... ?>` processing instruction.
+fn stylesheet(instruction: &BytesPI<'_>, line: usize) -> Result<Option<String>, ParseError> {
+    instruction
+        .attributes()
+        .flatten()
+        .find(|attribute| attribute.key.as_ref() == "href")
+        .map(|attribute| {
+            attribute
+                .normalized_value(quick_xml::XmlVersion::Implicit1_0)
+                .map(|value| value.to_string())
+                .map_err(|error| ParseError::Xml {
+                    line,
+                    message: error.to_string(),
+                })
+        })
+        .transpose()
+}
+
+/// Stores the root element, unless one has already been read.
+fn set_root(root: &mut Option<Element>, element: Element) -> Result<(), ParseError> {
+    if root.is_some() {
+        return Err(ParseError::Xml {
+            line: element.line,
+            message: format!("<{}> is a second root element", element.name),
+        });
+    }
+    *root = Some(element);
+    Ok(())
+}
+
+/// Resolves a character reference (such as `&#38;`) or one of the predefined entities (such as `&amp;`).
+fn resolve_reference(reference: &BytesRef<'_>, line: usize) -> Result<String, ParseError> {
+    match reference.resolve_char_ref() {
+        Ok(Some(character)) => Ok(character.to_string()),
+        Ok(None) => resolve_predefined_entity(reference)
+            .map(str::to_string)
+            .ok_or_else(|| ParseError::Xml {
+                line,
+                message: format!("unknown entity &{};", &**reference),
+            }),
+        Err(error) => Err(ParseError::Xml {
+            line,
+            message: error.to_string(),
+        }),
+    }
+}
+
+fn element(
+    start_tag: &BytesStart<'_>,
+    namespace: Option<String>,
+    line: usize,
+) -> Result<Element, ParseError> {
+    let name: String = start_tag.name().as_ref().to_string();
+    let (prefix, local_name): (Option<&str>, &str) = match name.split_once(':') {
+        Some((prefix, local_name)) => (Some(prefix), local_name),
+        None => (None, &name),
+    };
+
+    let mut attributes: Vec<(String, String)> = vec![];
+    let mut namespaces: Vec<Namespace> = vec![];
+    for attribute in start_tag.attributes() {
+        let attribute = attribute.map_err(|error| ParseError::Xml {
+            line,
+            message: error.to_string(),
+        })?;
+        let key: String = attribute.key.as_ref().to_string();
+        let value: String = attribute
+            .normalized_value(quick_xml::XmlVersion::Implicit1_0)
+            .map_err(|error| ParseError::Xml {
+                line,
+                message: error.to_string(),
+            })?
+            .to_string();
+
+        match key.strip_prefix("xmlns:") {
+            Some(prefix) => namespaces.push(Namespace::new(prefix.to_string(), value)),
+            None if key == "xmlns" => {}
+            None => attributes.push((key, value)),
+        }
+    }
+
+    Ok(Element {
+        namespace,
+        prefix: prefix.map(str::to_string),
+        name: local_name.to_string(),
+        attributes,
+        namespaces,
+        text: String::new(),
+        children: vec![],
+        line,
+    })
+}
+
+/// Which of the known namespaces an element is in.
+enum Kind<'a> {
+    Sitemap(&'a str),
+    Xhtml(&'a str),
+    Image(&'a str),
+    Video(&'a str),
+    News(&'a str),
+    Other,
+}
+
+/// The built-in extension prefixes and the namespaces they must be bound to.
+const BUILT_IN_NAMESPACES: [(&str, &str); 4] = [
+    ("xhtml", XHTML_NAMESPACE),
+    ("image", IMAGE_NAMESPACE),
+    ("video", VIDEO_NAMESPACE),
+    ("news", NEWS_NAMESPACE),
+];
+
+/// Turns a tree of elements into library types, collecting every problem along the way.
+struct Parser {
+    /// The namespace of the root element, which sitemap elements are expected to share.
+    sitemap_namespace: Option<String>,
+    problems: Vec<Problem>,
+}
+
+impl Parser {
+    fn problem(&mut self, line: usize, path: &str, message: String) {
+        self.problems.push(Problem {
+            line,
+            path: path.to_string(),
+            message,
+        });
+    }
+
+    fn kind<'a>(&self, element: &'a Element) -> Kind<'a> {
+        let name: &str = element.name.as_str();
+        match element.namespace.as_deref() {
+            namespace if namespace == self.sitemap_namespace.as_deref() => Kind::Sitemap(name),
+            Some(XHTML_NAMESPACE) => Kind::Xhtml(name),
+            Some(IMAGE_NAMESPACE) => Kind::Image(name),
+            Some(VIDEO_NAMESPACE) => Kind::Video(name),
+            Some(NEWS_NAMESPACE) => Kind::News(name),
+            _ => Kind::Other,
+        }
+    }
+
+    fn unexpected(&mut self, element: &Element, path: &str) {
+        self.problem(
+            element.line,
+            path,
+            format!("unexpected element <{}>", element.qualified_name()),
+        );
+    }
+
+    fn missing(&mut self, line: usize, path: &str, name: &str) {
+        self.problem(line, path, format!("<{name}> is required"));
+    }
+
+    /// Stores `value` in `slot`, unless the element was already seen.
+    fn once<T>(&mut self, slot: &mut Option<T>, value: Option<T>, element: &Element, path: &str) {
+        if slot.is_some() {
+            self.problem(
+                element.line,
+                path,
+                format!("<{}> must only appear once", element.qualified_name()),
+            );
+        } else if value.is_some() {
+            *slot = value;
+        }
+    }
+
+    fn location(&mut self, element: &Element, path: &str) -> String {
+        let location: &str = element.text.as_str();
+        let host: Option<&str> = location
+            .strip_prefix("https://")
+            .or_else(|| location.strip_prefix("http://"))
+            .and_then(|rest| rest.split(['/', '?', '#']).next());
+
+        if host.is_none_or(str::is_empty) || location.contains(char::is_whitespace) {
+            self.problem(
+                element.line,
+                path,
+                format!("location must be an absolute http or https URL: {location}"),
+            );
+        }
+        location.to_string()
+    }
+
+    fn date(&mut self, element: &Element, path: &str) -> Option<DateTime<FixedOffset>> {
+        let date: Option<DateTime<FixedOffset>> = parse_w3c_datetime(&element.text);
+        if date.is_none() {
+            self.problem(
+                element.line,
+                path,
+                format!("must be a W3C Datetime: {}", element.text),
+            );
+        }
+        date
+    }
+
+    /// Parses the text of `element` as a number, then checks it with `new`.
+    fn number<N: FromStr, T, E: Display>(
+        &mut self,
+        element: &Element,
+        path: &str,
+        expected: &str,
+        new: impl FnOnce(N) -> Result<T, E>,
+    ) -> Option<T> {
+        let Ok(value) = element.text.parse::<N>() else {
+            self.problem(element.line, path, format!("{expected}: {}", element.text));
+            return None;
+        };
+
+        new(value)
+            .map_err(|error| self.problem(element.line, path, error.to_string()))
+            .ok()
+    }
+
+    fn yes_no(&mut self, element: &Element, path: &str) -> Option<bool> {
+        match element.text.as_str() {
+            "yes" => Some(true),
+            "no" => Some(false),
+            text => {
+                self.problem(element.line, path, format!("must be yes or no: {text}"));
+                None
+            }
+        }
+    }
+
+    fn relationship(&mut self, element: &Element, path: &str) -> Relationship {
+        match element.attribute("relationship") {
+            Some("allow") => Relationship::Allow,
+            Some("deny") => Relationship::Deny,
+            relationship => {
+                self.problem(
+                    element.line,
+                    path,
+                    format!(
+                        "relationship must be allow or deny: {}",
+                        relationship.unwrap_or("missing")
+                    ),
+                );
+                Relationship::Allow
+            }
+        }
+    }
+
+    fn url_set(&mut self, prolog: Prolog, root: &Element) -> UrlSet {
+        let mut urls: Vec<Url> = vec![];
+        let mut lines: Vec<usize> = vec![];
+
+        for child in &root.children {
+            match self.kind(child) {
+                Kind::Sitemap("url") => {
+                    let index: usize = urls.len();
+                    urls.push(self.url(child, &format!("urls[{index}]")));
+                    lines.push(child.line);
+                }
+                _ => self.unexpected(child, "urls"),
+            }
+        }
+
+        // the library checks the rest, such as limits and the lengths of fields
+        for error in UrlSet::validate(&urls) {
+            let line: usize = error.index.map_or(root.line, |index| lines[index]);
+            self.problem(line, &error.path, error.kind.to_string());
+        }
+
+        UrlSet {
+            xml_version: prolog.xml_version,
+            xml_encoding: prolog.xml_encoding,
+            stylesheet: prolog.stylesheet,
+            xmlns: root.namespace.clone().unwrap_or_default(),
+            namespaces: root.namespaces.clone(),
+            urls,
+        }
+    }
+
+    fn url(&mut self, element: &Element, path: &str) -> Url {
+        let mut location: Option<String> = None;
+        let mut last_modified: Option<DateTime<FixedOffset>> = None;
+        let mut change_frequency: Option<ChangeFrequency> = None;
+        let mut priority: Option<Priority> = None;
+        let mut links: Vec<Link> = vec![];
+        let mut images: Vec<Image> = vec![];
+        let mut videos: Vec<Video> = vec![];
+        let mut news: Option<News> = None;
+        let mut extensions: Vec<Arc<dyn Extension + Send + Sync>> = vec![];
+
+        for child in &element.children {
+            match self.kind(child) {
+                Kind::Sitemap("loc") => {
+                    let field: String = format!("{path}.location");
+                    let value: Option<String> = Some(self.location(child, &field));
+                    self.once(&mut location, value, child, &field);
+                }
+                Kind::Sitemap("lastmod") => {
+                    let field: String = format!("{path}.last_modified");
+                    let value: Option<DateTime<FixedOffset>> = self.date(child, &field);
+                    self.once(&mut last_modified, value, child, &field);
+                }
+                Kind::Sitemap("changefreq") => {
+                    let field: String = format!("{path}.change_frequency");
+                    let value: Option<ChangeFrequency> = match child.text.parse() {
+                        Ok(change_frequency) => Some(change_frequency),
+                        Err(error) => {
+                            self.problem(child.line, &field, error.to_string());
+                            None
+                        }
+                    };
+                    self.once(&mut change_frequency, value, child, &field);
+                }
+                Kind::Sitemap("priority") => {
+                    let field: String = format!("{path}.priority");
+                    let value: Option<Priority> =
+                        self.number(child, &field, "priority must be a number", Priority::new);
+                    self.once(&mut priority, value, child, &field);
+                }
+                Kind::Xhtml("link") => {
+                    let field: String = format!("{path}.links[{}]", links.len());
+                    if let Some(link) = self.link(child, &field) {
+                        links.push(link);
+                    }
+                }
+                Kind::Image("image") => {
+                    let field: String = format!("{path}.images[{}]", images.len());
+                    if let Some(image) = self.image(child, &field) {
+                        images.push(image);
+                    }
+                }
+                Kind::Video("video") => {
+                    let field: String = format!("{path}.videos[{}]", videos.len());
+                    if let Some(video) = self.video(child, &field) {
+                        videos.push(video);
+                    }
+                }
+                Kind::News("news") => {
+                    let field: String = format!("{path}.news");
+                    let value: Option<News> = self.news(child, &field);
+                    self.once(&mut news, value, child, &field);
+                }
+                Kind::Other => self.extension_or_unexpected(child, path, &mut extensions),
+                _ => self.unexpected(child, path),
+            }
+        }
+
+        if location.is_none() {
+            self.missing(element.line, &format!("{path}.location"), "loc");
+        }
+
+        Url {
+            location: location.unwrap_or_default(),
+            links,
+            last_modified,
+            change_frequency,
+            priority,
+            images: (!images.is_empty()).then_some(images),
+            videos: (!videos.is_empty()).then_some(videos),
+            news,
+            extensions,
+        }
+    }
+
+    /// Keeps `element` as a custom extension, unless it was meant to be one of the built-in extensions.
+    fn extension_or_unexpected(
+        &mut self,
+        element: &Element,
+        path: &str,
+        extensions: &mut Vec<Arc<dyn Extension + Send + Sync>>,
+    ) {
+        let (Some(uri), Some(prefix)) = (&element.namespace, &element.prefix) else {
+            match &element.prefix {
+                Some(prefix) => self.problem(
+                    element.line,
+                    path,
+                    format!("namespace prefix '{prefix}' must be declared"),
+                ),
+                None => self.unexpected(element, path),
+            }
+            return;
+        };
+
+        // a built-in prefix bound to the wrong namespace (such as an outdated version) is ignored by search engines
+        if let Some((_, expected)) = BUILT_IN_NAMESPACES
+            .iter()
+            .find(|(built_in, _)| built_in == prefix)
+        {
+            self.problem(
+                element.line,
+                path,
+                format!(
+                    "<{}> must be in the namespace {expected}: {uri}",
+                    element.qualified_name()
+                ),
+            );
+            return;
+        }
+
+        extensions.push(Arc::new(extension_element(
+            &Namespace::new(prefix.clone(), uri.clone()),
+            element,
+        )));
+    }
+
+    fn link(&mut self, element: &Element, path: &str) -> Option<Link> {
+        if element.attribute("rel") != Some("alternate") {
+            self.problem(
+                element.line,
+                path,
+                format!(
+                    "rel must be alternate: {}",
+                    element.attribute("rel").unwrap_or("missing")
+                ),
+            );
+        }
+
+        let hreflang: Option<&str> = element.attribute("hreflang");
+        if hreflang.is_none() {
+            self.problem(
+                element.line,
+                &format!("{path}.hreflang"),
+                String::from("hreflang is required"),
+            );
+        }
+
+        let href: Option<&str> = element.attribute("href");
+        match href {
+            None => self.problem(
+                element.line,
+                &format!("{path}.href"),
+                String::from("href is required"),
+            ),
+            Some(href) if !href.starts_with("http://") && !href.starts_with("https://") => self
+                .problem(
+                    element.line,
+                    &format!("{path}.href"),
+                    format!("href must be an absolute http or https URL: {href}"),
+                ),
+            Some(_) => {}
+        }
+
+        Some(Link::new(hreflang?.to_string(), href?.to_string()))
+    }
+
+    fn image(&mut self, element: &Element, path: &str) -> Option<Image> {
+        let mut location: Option<String> = None;
+
+        for child in &element.children {
+            match self.kind(child) {
+                Kind::Image("loc") => {
+                    let field: String = format!("{path}.location");
+                    let value: Option<String> = Some(self.location(child, &field));
+                    self.once(&mut location, value, child, &field);
+                }
+                _ => self.unexpected(child, path),
+            }
+        }
+
+        if location.is_none() {
+            self.missing(element.line, &format!("{path}.location"), "image:loc");
+        }
+        location.map(Image::new)
+    }
+
+    #[expect(clippy::too_many_lines)]
+    fn video(&mut self, element: &Element, path: &str) -> Option<Video> {
+        let mut thumbnail_location: Option<String> = None;
+        let mut title: Option<String> = None;
+        let mut description: Option<String> = None;
+        let mut content_location: Option<String> = None;
+        let mut player_location: Option<String> = None;
+        let mut duration: Option<VideoDuration> = None;
+        let mut expiration_date: Option<DateTime<FixedOffset>> = None;
+        let mut rating: Option<VideoRating> = None;
+        let mut view_count: Option<usize> = None;
+        let mut publication_date: Option<DateTime<FixedOffset>> = None;
+        let mut family_friendly: Option<bool> = None;
+        let mut restriction: Option<Restriction> = None;
+        let mut platform: Option<Platform> = None;
+        let mut requires_subscription: Option<bool> = None;
+        let mut uploader: Option<Uploader> = None;
+        let mut live: Option<bool> = None;
+        let mut tags: Vec<String> = vec![];
+
+        for child in &element.children {
+            let Kind::Video(name) = self.kind(child) else {
+                self.unexpected(child, path);
+                continue;
+            };
+
+            match name {
+                "thumbnail_loc" => {
+                    let field: String = format!("{path}.thumbnail_location");
+                    let value: Option<String> = Some(self.location(child, &field));
+                    self.once(&mut thumbnail_location, value, child, &field);
+                }
+                "title" => {
+                    let field: String = format!("{path}.title");
+                    self.once(&mut title, Some(child.text.clone()), child, &field);
+                }
+                "description" => {
+                    let field: String = format!("{path}.description");
+                    self.once(&mut description, Some(child.text.clone()), child, &field);
+                }
+                "content_loc" => {
+                    let field: String = format!("{path}.content_location");
+                    let value: Option<String> = Some(self.location(child, &field));
+                    self.once(&mut content_location, value, child, &field);
+                }
+                "player_loc" => {
+                    let field: String = format!("{path}.player_location");
+                    let value: Option<String> = Some(self.location(child, &field));
+                    self.once(&mut player_location, value, child, &field);
+                }
+                "duration" => {
+                    let field: String = format!("{path}.duration");
+                    let value: Option<VideoDuration> = self.number(
+                        child,
+                        &field,
+                        "duration must be a whole number of seconds from 1 to 28,800",
+                        VideoDuration::new,
+                    );
+                    self.once(&mut duration, value, child, &field);
+                }
+                "expiration_date" => {
+                    let field: String = format!("{path}.expiration_date");
+                    let value: Option<DateTime<FixedOffset>> = self.date(child, &field);
+                    self.once(&mut expiration_date, value, child, &field);
+                }
+                "rating" => {
+                    let field: String = format!("{path}.rating");
+                    let value: Option<VideoRating> =
+                        self.number(child, &field, "rating must be a number", VideoRating::new);
+                    self.once(&mut rating, value, child, &field);
+                }
+                "view_count" => {
+                    let field: String = format!("{path}.view_count");
+                    let value: Option<usize> = child
+                        .text
+                        .parse()
+                        .map_err(|_| {
+                            self.problem(
+                                child.line,
+                                &field,
+                                format!("view count must be a whole number: {}", child.text),
+                            );
+                        })
+                        .ok();
+                    self.once(&mut view_count, value, child, &field);
+                }
+                "publication_date" => {
+                    let field: String = format!("{path}.publication_date");
+                    let value: Option<DateTime<FixedOffset>> = self.date(child, &field);
+                    self.once(&mut publication_date, value, child, &field);
+                }
+                "family_friendly" => {
+                    let field: String = format!("{path}.family_friendly");
+                    let value: Option<bool> = self.yes_no(child, &field);
+                    self.once(&mut family_friendly, value, child, &field);
+                }
+                "restriction" => {
+                    let field: String = format!("{path}.restriction");
+                    let country_codes: BTreeSet<String> =
+                        child.text.split_whitespace().map(str::to_string).collect();
+                    if let Some(country_code) = country_codes.iter().find(|country_code| {
+                        country_code.len() != 2
+                            || !country_code.bytes().all(|byte| byte.is_ascii_uppercase())
+                    }) {
+                        self.problem(
+                            child.line,
+                            &field,
+                            format!(
+                                "country codes must be in ISO 3166 format, such as US: {country_code}"
+                            ),
+                        );
+                    }
+                    let value: Restriction =
+                        Restriction::new(country_codes, self.relationship(child, &field));
+                    self.once(&mut restriction, Some(value), child, &field);
+                }
+                "platform" => {
+                    let field: String = format!("{path}.platform");
+                    let mut platforms: BTreeSet<PlatformType> = BTreeSet::new();
+                    for platform_type in child.text.split_whitespace() {
+                        match platform_type {
+                            "web" => platforms.insert(PlatformType::Web),
+                            "mobile" => platforms.insert(PlatformType::Mobile),
+                            "tv" => platforms.insert(PlatformType::Tv),
+                            _ => {
+                                self.problem(
+                                    child.line,
+                                    &field,
+                                    format!(
+                                        "platform must be one of web, mobile, or tv: {platform_type}"
+                                    ),
+                                );
+                                false
+                            }
+                        };
+                    }
+                    let value: Platform =
+                        Platform::new(platforms, self.relationship(child, &field));
+                    self.once(&mut platform, Some(value), child, &field);
+                }
+                "requires_subscription" => {
+                    let field: String = format!("{path}.requires_subscription");
+                    let value: Option<bool> = self.yes_no(child, &field);
+                    self.once(&mut requires_subscription, value, child, &field);
+                }
+                "uploader" => {
+                    let field: String = format!("{path}.uploader");
+                    let value: Uploader = Uploader::new(
+                        child.text.clone(),
+                        child.attribute("info").map(str::to_string),
+                    );
+                    self.once(&mut uploader, Some(value), child, &field);
+                }
+                "live" => {
+                    let field: String = format!("{path}.live");
+                    let value: Option<bool> = self.yes_no(child, &field);
+                    self.once(&mut live, value, child, &field);
+                }
+                "tag" => tags.push(child.text.clone()),
+                _ => self.unexpected(child, path),
+            }
+        }
+
+        let tags: Option<TagList> = if tags.is_empty() {
+            None
+        } else {
+            TagList::new(tags)
+                .map_err(|error| {
+                    self.problem(element.line, &format!("{path}.tags"), error.to_string());
+                })
+                .ok()
+        };
+
+        if thumbnail_location.is_none() {
+            self.missing(
+                element.line,
+                &format!("{path}.thumbnail_location"),
+                "video:thumbnail_loc",
+            );
+        }
+        if title.is_none() {
+            self.missing(element.line, &format!("{path}.title"), "video:title");
+        }
+        if description.is_none() {
+            self.missing(
+                element.line,
+                &format!("{path}.description"),
+                "video:description",
+            );
+        }
+        if content_location.is_none() && player_location.is_none() {
+            self.problem(
+                element.line,
+                &format!("{path}.content_location"),
+                String::from("<video:content_loc> or <video:player_loc> is required"),
+            );
+        }
+
+        Some(Video {
+            thumbnail_location: thumbnail_location?,
+            title: title?,
+            description: description?,
+            content_location: content_location.unwrap_or_default(),
+            player_location: player_location.unwrap_or_default(),
+            duration,
+            expiration_date,
+            rating,
+            view_count,
+            publication_date,
+            family_friendly,
+            restriction,
+            platform,
+            requires_subscription,
+            uploader,
+            live,
+            tags,
+        })
+    }
+
+    fn news(&mut self, element: &Element, path: &str) -> Option<News> {
+        let mut publication: Option<Publication> = None;
+        let mut publication_date: Option<DateTime<FixedOffset>> = None;
+        let mut title: Option<String> = None;
+
+        for child in &element.children {
+            match self.kind(child) {
+                Kind::News("publication") => {
+                    let field: String = format!("{path}.publication");
+                    let value: Option<Publication> = self.publication(child, &field);
+                    self.once(&mut publication, value, child, &field);
+                }
+                Kind::News("publication_date") => {
+                    let field: String = format!("{path}.publication_date");
+                    let value: Option<DateTime<FixedOffset>> = self.date(child, &field);
+                    self.once(&mut publication_date, value, child, &field);
+                }
+                Kind::News("title") => {
+                    let field: String = format!("{path}.title");
+                    self.once(&mut title, Some(child.text.clone()), child, &field);
+                }
+                _ => self.unexpected(child, path),
+            }
+        }
+
+        if publication.is_none() {
+            self.missing(
+                element.line,
+                &format!("{path}.publication"),
+                "news:publication",
+            );
+        }
+        if publication_date.is_none() {
+            self.missing(
+                element.line,
+                &format!("{path}.publication_date"),
+                "news:publication_date",
+            );
+        }
+        if title.is_none() {
+            self.missing(element.line, &format!("{path}.title"), "news:title");
+        }
+
+        Some(News::new(publication?, publication_date?, title?))
+    }
+
+    fn publication(&mut self, element: &Element, path: &str) -> Option<Publication> {
+        let mut name: Option<String> = None;
+        let mut language: Option<String> = None;
+
+        for child in &element.children {
+            match self.kind(child) {
+                Kind::News("name") => {
+                    let field: String = format!("{path}.name");
+                    self.once(&mut name, Some(child.text.clone()), child, &field);
+                }
+                Kind::News("language") => {
+                    let field: String = format!("{path}.language");
+                    self.once(&mut language, Some(child.text.clone()), child, &field);
+                }
+                _ => self.unexpected(child, path),
+            }
+        }
+
+        if name.is_none() {
+            self.missing(element.line, &format!("{path}.name"), "news:name");
+        }
+        if language.is_none() {
+            self.missing(element.line, &format!("{path}.language"), "news:language");
+        }
+
+        Some(Publication::new(name?, language?))
+    }
+
+    fn sitemap_index(&mut self, prolog: Prolog, root: &Element) -> SitemapIndex {
+        let mut sitemaps: Vec<Sitemap> = vec![];
+
+        for child in &root.children {
+            match self.kind(child) {
+                Kind::Sitemap("sitemap") => {
+                    let index: usize = sitemaps.len();
+                    let path: String = format!("sitemaps[{index}]");
+                    let sitemap: Sitemap = self.sitemap(child, &path);
+
+                    // the library checks the rest, such as the lengths of fields
+                    for error in sitemap.validate() {
+                        self.problem(
+                            child.line,
+                            &format!("{path}.{}", error.field()),
+                            error.to_string(),
+                        );
+                    }
+                    sitemaps.push(sitemap);
+                }
+                _ => self.unexpected(child, "sitemaps"),
+            }
+        }
+
+        // SitemapIndex cannot contain more than 50,000 sitemaps
+        if sitemaps.len() > 50_000 {
+            self.problem(
+                root.line,
+                "sitemaps",
+                format!(
+                    "must not contain more than 50,000 sitemaps: {}",
+                    sitemaps.len()
+                ),
+            );
+        }
+
+        SitemapIndex {
+            xml_version: prolog.xml_version,
+            xml_encoding: prolog.xml_encoding,
+            stylesheet: prolog.stylesheet,
+            xmlns: root.namespace.clone().unwrap_or_default(),
+            sitemaps,
+        }
+    }
+
+    fn sitemap(&mut self, element: &Element, path: &str) -> Sitemap {
+        let mut location: Option<String> = None;
+        let mut last_modified: Option<DateTime<FixedOffset>> = None;
+
+        for child in &element.children {
+            match self.kind(child) {
+                Kind::Sitemap("loc") => {
+                    let field: String = format!("{path}.location");
+                    let value: Option<String> = Some(self.location(child, &field));
+                    self.once(&mut location, value, child, &field);
+                }
+                Kind::Sitemap("lastmod") => {
+                    let field: String = format!("{path}.last_modified");
+                    let value: Option<DateTime<FixedOffset>> = self.date(child, &field);
+                    self.once(&mut last_modified, value, child, &field);
+                }
+                _ => self.unexpected(child, path),
+            }
+        }
+
+        if location.is_none() {
+            self.missing(element.line, &format!("{path}.location"), "loc");
+        }
+        Sitemap::new(location.unwrap_or_default(), last_modified)
+    }
+}
+
+/// Converts `element` into an `ExtensionElement`, keeping its attributes, text, and children.
+fn extension_element(namespace: &Namespace, element: &Element) -> ExtensionElement {
+    ExtensionElement {
+        namespace: namespace.clone(),
+        name: element.name.clone(),
+        attributes: element.attributes.clone(),
+        text: (!element.text.is_empty()).then(|| element.text.clone()),
+        children: element
+            .children
+            .iter()
+            .map(|child| extension_element(namespace, child))
+            .collect(),
+    }
+}
@@ -0,0 +1,59 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::str::Utf8Error;
+
+/// An error that stops a sitemap file from being read at all.
+///
+/// Rule violations within a readable file are reported as `Problem`s instead.
+#[derive(Debug)]
+pub enum ParseError {
+    /// Returned when there is an IO Error reading the file.
+    Io(io::Error),
+
+    /// Returned when the file is larger than `50MB` uncompressed; reading stops right after the limit.
+    TooLarge,
+
+    /// Returned when the file isn't UTF-8, which every sitemap must be.
+    NotUtf8(Utf8Error),
+
+    /// Returned when the file isn't well-formed XML.
+    Xml { line: usize, message: String },
+
+    /// Returned when the root element is neither a \<urlset\> nor a \<sitemapindex\>.
+    NotASitemap(String),
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::NotUtf8(error) => Some(error),
+            Self::TooLarge | Self::Xml { .. } | Self::NotASitemap(_) => None,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to read sitemap: {error}"),
+            Self::TooLarge => write!(
+                f,
+                "sitemap must be no larger than 50MB (52,428,800 bytes) uncompressed"
+            ),
+            Self::NotUtf8(error) => write!(f, "sitemap must be UTF-8 encoded: {error}"),
+            Self::Xml { line, message } => write!(f, "line {line}: malformed XML: {message}"),
+            Self::NotASitemap(name) => write!(
+                f,
+                "root element must be <urlset> or <sitemapindex>, not <{name}>"
+            ),
+        }
+    }
+}
+
+impl From<io::Error> for ParseError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
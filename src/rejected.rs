@@ -0,0 +1,23 @@
+/// An entry that was left out during lenient construction, along with why.
+#[derive(Debug)]
+pub struct Rejected<T, E> {
+    /// The index of the entry within the original input.
+    pub index: usize,
+
+    /// The entry that failed validation.
+    pub item: T,
+
+    /// Every rule the entry broke.
+    pub errors: Vec<E>,
+}
+
+impl<T, E> Rejected<T, E> {
+    #[must_use]
+    pub const fn new(index: usize, item: T, errors: Vec<E>) -> Self {
+        Self {
+            index,
+            item,
+            errors,
+        }
+    }
+}
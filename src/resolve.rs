@@ -0,0 +1,132 @@
+use crate::merge::normalize_location;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Component, Path, PathBuf};
+
+/// How much of a file is read to find its root element.
+const PROLOG_LIMIT: u64 = 65_536;
+
+/// The kind of file a \<sitemap\> entry of a sitemap index references.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SitemapKind {
+    /// A file with a \<urlset\> root element.
+    UrlSet,
+
+    /// A file with a \<sitemapindex\> root element, which a sitemap index must not reference.
+    SitemapIndex,
+
+    /// A file that couldn't be found or read, or that is neither of the above (such as an RSS feed or a text file).
+    Unknown,
+}
+
+/// Tells what kind of file the location of a \<sitemap\> entry references, so that sitemap indexes referencing other sitemap indexes can be detected.
+pub trait SitemapResolver {
+    fn resolve(&self, location: &str) -> SitemapKind;
+}
+
+impl<F: Fn(&str) -> SitemapKind> SitemapResolver for F {
+    fn resolve(&self, location: &str) -> SitemapKind {
+        self(location)
+    }
+}
+
+/// Resolves locations to the files within a local directory that is served at a base URL, such as the output directory of the generator.
+///
+/// Each file is identified by its root element; gzipped files are decompressed first if the `gzip` feature is enabled.
+/// Locations outside of the base URL, and files that don't exist, are `SitemapKind::Unknown`.
+#[derive(Debug, Clone)]
+pub struct LocalResolver {
+    dir: PathBuf,
+    base_url: String,
+}
+
+impl LocalResolver {
+    #[must_use]
+    pub const fn new(dir: PathBuf, base_url: String) -> Self {
+        Self { dir, base_url }
+    }
+}
+
+impl SitemapResolver for LocalResolver {
+    fn resolve(&self, location: &str) -> SitemapKind {
+        let Some(path) = local_path(&self.dir, &self.base_url, location) else {
+            return SitemapKind::Unknown;
+        };
+        let Ok(file) = File::open(path) else {
+            return SitemapKind::Unknown;
+        };
+
+        let mut reader: BufReader<File> = BufReader::new(file);
+        let mut prolog: Vec<u8> = vec![];
+        let gzipped: bool = reader
+            .fill_buf()
+            .is_ok_and(|bytes| bytes.starts_with(&[0x1f, 0x8b]));
+        let read: std::io::Result<usize> = if gzipped {
+            #[cfg(feature = "gzip")]
+            {
+                flate2::read::GzDecoder::new(reader)
+                    .take(PROLOG_LIMIT)
+                    .read_to_end(&mut prolog)
+            }
+            #[cfg(not(feature = "gzip"))]
+            {
+                return SitemapKind::Unknown;
+            }
+        } else {
+            reader.take(PROLOG_LIMIT).read_to_end(&mut prolog)
+        };
+        if read.is_err() {
+            return SitemapKind::Unknown;
+        }
+
+        match root_element(&String::from_utf8_lossy(&prolog)) {
+            Some("urlset") => SitemapKind::UrlSet,
+            Some("sitemapindex") => SitemapKind::SitemapIndex,
+            _ => SitemapKind::Unknown,
+        }
+    }
+}
+
+/// The file within `dir` that `location` refers to, if `dir` is served at `base_url` and `location` is within it.
+pub(crate) fn local_path(dir: &Path, base_url: &str, location: &str) -> Option<PathBuf> {
+    let location: String = normalize_location(location);
+    let base_url: String = normalize_location(base_url);
+    let rest: &str = location.strip_prefix(base_url.trim_end_matches('/'))?;
+    if !rest.is_empty() && !rest.starts_with('/') {
+        return None;
+    }
+    let rest: &str = rest.split(['?', '#']).next().unwrap_or_default();
+
+    let relative: &Path = Path::new(rest.trim_start_matches('/'));
+    // never leave the directory
+    if relative
+        .components()
+        .any(|component| !matches!(component, Component::Normal(_)))
+    {
+        return None;
+    }
+    Some(dir.join(relative))
+}
+
+/// The local name of the root element of an XML document, skipping the declaration, processing instructions, comments, and doctype.
+fn root_element(xml: &str) -> Option<&str> {
+    let mut rest: &str = xml;
+    loop {
+        rest = &rest[rest.find('<')? + 1..];
+        let end: &str = if rest.starts_with('?') {
+            "?>"
+        } else if rest.starts_with("!--") {
+            "-->"
+        } else if rest.starts_with('!') {
+            ">"
+        } else {
+            let name: &str = rest
+                .split(|character: char| {
+                    character.is_whitespace() || character == '>' || character == '/'
+                })
+                .next()?;
+            return Some(name.rsplit(':').next().unwrap_or(name));
+        };
+        rest = &rest[rest.find(end)? + end.len()..];
+    }
+}
@@ -1,9 +1,11 @@
-use crate::{RFC_3339_SECONDS_FORMAT, RFC_3339_USE_Z};
+use crate::sitemap_builder::SitemapBuilder;
+use crate::sitemap_index_error::SitemapIndexError;
+use crate::xml_writer::XmlWriter;
 use chrono::{DateTime, FixedOffset};
-use xml_builder::{XMLElement, XMLError};
+use std::io;
 
 /// Encapsulates information about an individual Sitemap.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Sitemap {
     /// Identifies the location of the Sitemap.
     ///
@@ -28,25 +30,43 @@ impl Sitemap {
         }
     }
 
+    #[must_use]
+    pub const fn builder(location: String) -> SitemapBuilder {
+        SitemapBuilder::new(location)
+    }
+
+    /// Checks every rule that applies to this sitemap entry and returns all violations.
+    #[must_use]
+    pub fn validate(&self) -> Vec<SitemapIndexError> {
+        let mut errors: Vec<SitemapIndexError> = vec![];
+
+        // make sure location is less than 2,048 characters
+        let characters: usize = self.location.chars().count();
+        if characters >= 2048 {
+            errors.push(SitemapIndexError::LocationTooLong {
+                location: self.location.clone(),
+                characters,
+                bytes: self.location.len(),
+            });
+        }
+
+        errors
+    }
+
     /// # Errors
     ///
-    /// Will return `XMLError` if there is a problem creating XML elements.
-    pub fn to_xml(self) -> Result<XMLElement, XMLError> {
-        let mut sitemap: XMLElement = XMLElement::new("sitemap");
+    /// Will return an IO Error if there is a problem writing to the underlying writer.
+    pub(crate) fn write_xml(&self, writer: &mut XmlWriter<'_>) -> io::Result<()> {
+        writer.start("sitemap", &[])?;
 
         // add <loc>
-        let mut loc: XMLElement = XMLElement::new("loc");
-        loc.add_text(self.location)?;
-        sitemap.add_child(loc)?;
+        writer.text("loc", &[], &self.location)?;
 
         // add <lastmod>, if it exists
         if let Some(last_modified) = self.last_modified {
-            let mut last_mod: XMLElement = XMLElement::new("lastmod");
-            last_mod
-                .add_text(last_modified.to_rfc3339_opts(RFC_3339_SECONDS_FORMAT, RFC_3339_USE_Z))?;
-            sitemap.add_child(last_mod)?;
+            writer.date("lastmod", &last_modified)?;
         }
 
-        Ok(sitemap)
+        writer.end("sitemap")
     }
 }
@@ -0,0 +1,64 @@
+use crate::date::TryIntoDateTime;
+use crate::date_error::DateError;
+use crate::sitemap::Sitemap;
+use crate::sitemap_index_error::SitemapIndexError;
+use chrono::{DateTime, FixedOffset};
+
+/// Encapsulates information about an individual Sitemap.
+///
+/// This is a Builder for Sitemap.
+#[derive(Debug, Clone)]
+pub struct SitemapBuilder {
+    /// Identifies the location of the Sitemap.
+    ///
+    /// This location can be a Sitemap, an Atom file, RSS file or a simple text file.
+    pub location: String,
+
+    /// Identifies the time that the corresponding Sitemap file was modified.
+    ///
+    /// It does not correspond to the time that any of the pages listed in that Sitemap were changed.
+    pub last_modified: Option<DateTime<FixedOffset>>,
+
+    /// The error from converting the date given to `last_modified`, returned by `build`.
+    last_modified_error: Option<DateError>,
+}
+
+impl SitemapBuilder {
+    #[must_use]
+    pub const fn new(location: String) -> Self {
+        Self {
+            location,
+            last_modified: None,
+            last_modified_error: None,
+        }
+    }
+
+    /// Sets `last_modified`; if the date can't be converted, `build` returns the error.
+    pub fn last_modified(&mut self, last_modified: impl TryIntoDateTime) -> &mut Self {
+        let last_modified: Result<DateTime<FixedOffset>, DateError> =
+            last_modified.try_into_date_time();
+        self.last_modified_error = last_modified.as_ref().err().cloned();
+        self.last_modified = last_modified.ok();
+        self
+    }
+
+    /// # Errors
+    ///
+    /// Will return `SitemapIndexError::LocationTooLong` if `location` is 2,048 characters or more.
+    /// Will return `SitemapIndexError::Date` if the date given to `last_modified` couldn't be converted.
+    pub fn build(&self) -> Result<Sitemap, SitemapIndexError> {
+        if let Some(error) = &self.last_modified_error {
+            return Err(SitemapIndexError::Date {
+                field: "last_modified",
+                error: error.clone(),
+            });
+        }
+
+        let sitemap: Sitemap = Sitemap::new(self.location.clone(), self.last_modified);
+
+        match sitemap.validate().into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(sitemap),
+        }
+    }
+}
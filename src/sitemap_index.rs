@@ -1,17 +1,30 @@
+use crate::Error;
+use crate::location::host;
+use crate::rejected::Rejected;
+use crate::resolve::{SitemapKind, SitemapResolver};
 use crate::sitemap::Sitemap;
+use crate::sitemap_index_builder::SitemapIndexBuilder;
 use crate::sitemap_index_error::SitemapIndexError;
+use crate::write_options::WriteOptions;
+use crate::xml_version::XmlVersion;
+use crate::xml_writer::XmlWriter;
 use crate::{ENCODING, NAMESPACE};
 use std::io::Write;
-use xml_builder::{XML, XMLBuilder, XMLElement, XMLError, XMLVersion};
 
 /// Encapsulates information about all the Sitemaps in the file.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SitemapIndex {
     /// The XML version.
-    pub xml_version: XMLVersion,
+    pub xml_version: XmlVersion,
 
     /// The XML encoding.
     pub xml_encoding: String,
 
+    /// The location of an XSL stylesheet that browsers use to render the sitemap index, if any.
+    ///
+    /// See the `stylesheet` module for default stylesheets.
+    pub stylesheet: Option<String>,
+
     /// The namespace for the \<sitemapindex\>.
     pub xmlns: String,
 
@@ -23,50 +36,181 @@ impl SitemapIndex {
     /// # Errors
     ///
     /// Will return `SitemapIndexError::TooManySitemaps` if the length of `sitemaps` is above `50,000`.
+    /// Will return `SitemapIndexError::LocationTooLong` if any of the `sitemaps` has a `location` of 2,048 characters or more.
     pub fn new(sitemaps: Vec<Sitemap>) -> Result<Self, SitemapIndexError> {
         // SitemapIndex cannot contain more than 50,000 sitemaps
         if sitemaps.len() > 50_000 {
             return Err(SitemapIndexError::TooManySitemaps(sitemaps.len()));
         }
 
+        // every sitemap must be valid
+        if let Some(error) = sitemaps.iter().flat_map(Sitemap::validate).next() {
+            return Err(error);
+        }
+
         Ok(Self {
-            xml_version: XMLVersion::XML1_0,
+            xml_version: XmlVersion::Xml1_0,
             xml_encoding: ENCODING.to_string(),
+            stylesheet: None,
             xmlns: NAMESPACE.to_string(),
             sitemaps,
         })
     }
 
+    #[must_use]
+    pub fn builder() -> SitemapIndexBuilder {
+        SitemapIndexBuilder::new()
+    }
+
+    /// Creates a `SitemapIndex`, also rejecting entries that `resolver` finds to be sitemap indexes themselves.
+    ///
     /// # Errors
     ///
-    /// Will return `XMLError` if there is a problem creating XML elements.
-    pub fn to_xml(self) -> Result<XML, XMLError> {
-        // create XML document
-        let mut xml = XMLBuilder::new()
-            .version(self.xml_version)
-            .encoding(self.xml_encoding)
-            .build();
-
-        // create <sitemapindex>
-        let mut sitemap_index: XMLElement = XMLElement::new("sitemapindex");
-        sitemap_index.add_attribute("xmlns", self.xmlns.as_str());
+    /// Will return `SitemapIndexError::TooManySitemaps` if the length of `sitemaps` is above `50,000`.
+    /// Will return `SitemapIndexError::LocationTooLong` if any of the `sitemaps` has a `location` of 2,048 characters or more.
+    /// Will return `SitemapIndexError::NestedIndex` if any of the `sitemaps` references another sitemap index.
+    pub fn new_resolved(
+        sitemaps: Vec<Sitemap>,
+        resolver: &impl SitemapResolver,
+    ) -> Result<Self, SitemapIndexError> {
+        let sitemap_index: Self = Self::new(sitemaps)?;
+        if let Some(location) = sitemap_index.nested(resolver).next() {
+            return Err(SitemapIndexError::NestedIndex(location.to_string()));
+        }
+        Ok(sitemap_index)
+    }
+
+    /// Checks the files that the entries reference, once this sitemap index is hosted at `location`.
+    ///
+    /// Returns a `SitemapIndexError::NestedIndex` for each entry that `resolver` finds to be a sitemap index,
+    /// and a `SitemapIndexError::CrossHost` for each entry on a different host than `location`.
+    #[must_use]
+    pub fn check_references(
+        &self,
+        location: &str,
+        resolver: &impl SitemapResolver,
+    ) -> Vec<SitemapIndexError> {
+        let mut errors: Vec<SitemapIndexError> = self
+            .nested(resolver)
+            .map(|location| SitemapIndexError::NestedIndex(location.to_string()))
+            .collect();
+
+        if let Some(index_host) = host(location) {
+            for sitemap in &self.sitemaps {
+                if host(&sitemap.location).is_some_and(|host| host != index_host) {
+                    errors.push(SitemapIndexError::CrossHost {
+                        location: sitemap.location.clone(),
+                        host: index_host.clone(),
+                    });
+                }
+            }
+        }
+
+        errors
+    }
+
+    /// The locations of the entries that `resolver` finds to be sitemap indexes.
+    fn nested<'a>(&'a self, resolver: &'a impl SitemapResolver) -> impl Iterator<Item = &'a str> {
+        self.sitemaps
+            .iter()
+            .map(|sitemap| sitemap.location.as_str())
+            .filter(|location| resolver.resolve(location) == SitemapKind::SitemapIndex)
+    }
+
+    /// Creates a `SitemapIndex` from only the valid entries of `sitemaps`, instead of rejecting the whole index.
+    ///
+    /// Entries that break any rule are left out and returned alongside the `SitemapIndex`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `SitemapIndexError::TooManySitemaps` if the number of valid `sitemaps` is above `50,000`.
+    pub fn new_lenient(
+        sitemaps: Vec<Sitemap>,
+    ) -> Result<(Self, Vec<Rejected<Sitemap, SitemapIndexError>>), SitemapIndexError> {
+        let mut valid: Vec<Sitemap> = Vec::with_capacity(sitemaps.len());
+        let mut rejected: Vec<Rejected<Sitemap, SitemapIndexError>> = vec![];
+
+        for (index, sitemap) in sitemaps.into_iter().enumerate() {
+            let errors: Vec<SitemapIndexError> = sitemap.validate();
+            if errors.is_empty() {
+                valid.push(sitemap);
+            } else {
+                rejected.push(Rejected::new(index, sitemap, errors));
+            }
+        }
+
+        Ok((Self::new(valid)?, rejected))
+    }
+
+    /// Writes the sitemap using the default `WriteOptions`: pretty-printed with tabs.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::Io` if there is an IO Error dealing with the
+    /// underlying writer.
+    pub fn write<W: Write>(&self, writer: W) -> Result<(), Error> {
+        self.write_with_options(writer, &WriteOptions::default())
+    }
+
+    /// Writes the sitemap laid out according to `options`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::Io` if there is an IO Error dealing with the
+    /// underlying writer.
+    pub fn write_with_options<W: Write>(
+        &self,
+        mut writer: W,
+        options: &WriteOptions,
+    ) -> Result<(), Error> {
+        let mut writer: XmlWriter<'_> = XmlWriter::new(&mut writer, options);
+
+        // write XML declaration
+        writer.declaration(self.xml_version, &self.xml_encoding)?;
+
+        // write XSL stylesheet processing instruction, if it exists
+        if let Some(stylesheet) = &self.stylesheet {
+            writer.stylesheet(stylesheet)?;
+        }
+
+        // an empty <sitemapindex> is self-closing
+        let attributes: [(&str, &str); 1] = [("xmlns", self.xmlns.as_str())];
+        if self.sitemaps.is_empty() {
+            writer.empty("sitemapindex", &attributes)?;
+            return Ok(());
+        }
 
         // add each <sitemap>
-        for sitemap in self.sitemaps {
-            sitemap_index.add_child(sitemap.to_xml()?)?;
+        writer.start("sitemapindex", &attributes)?;
+        let mut sitemaps: Vec<&Sitemap> = self.sitemaps.iter().collect();
+        if let Some(order) = options.order {
+            sitemaps.sort_by(|first, second| {
+                order.compare(
+                    (&first.location, first.last_modified),
+                    (&second.location, second.last_modified),
+                )
+            });
         }
+        for sitemap in sitemaps {
+            sitemap.write_xml(&mut writer)?;
+        }
+        writer.end("sitemapindex")?;
 
-        // set root element and we're done!
-        xml.set_root_element(sitemap_index);
-        Ok(xml)
+        Ok(())
     }
 
+    /// Writes the sitemap gzip-compressed, laid out according to `options`.
+    ///
     /// # Errors
     ///
-    /// Will return `XMLError` if there is an IO Error dealing with the
-    /// underlying writer or if there is an error generating XML.
-    pub fn write<W: Write>(self, writer: W) -> Result<(), XMLError> {
-        let xml: XML = self.to_xml()?;
-        xml.generate(writer)
+    /// Will return `Error::Io` if there is an IO Error dealing with the
+    /// underlying writer.
+    #[cfg(feature = "gzip")]
+    pub fn write_gzip<W: Write>(&self, writer: W, options: &WriteOptions) -> Result<(), Error> {
+        let mut encoder: flate2::write::GzEncoder<W> =
+            flate2::write::GzEncoder::new(writer, flate2::Compression::default());
+        self.write_with_options(&mut encoder, options)?;
+        encoder.finish()?;
+        Ok(())
     }
 }
@@ -0,0 +1,106 @@
+use crate::rejected::Rejected;
+use crate::sitemap::Sitemap;
+use crate::sitemap_index::SitemapIndex;
+use crate::sitemap_index_error::SitemapIndexError;
+use crate::xml_version::XmlVersion;
+use crate::{ENCODING, NAMESPACE};
+
+/// Encapsulates information about all the Sitemaps in the file.
+///
+/// This is a Builder for `SitemapIndex`.
+/// Every \<sitemap\> is validated as it is pushed, so building never fails.
+#[derive(Debug, Clone)]
+pub struct SitemapIndexBuilder {
+    xml_version: XmlVersion,
+    xml_encoding: String,
+    stylesheet: Option<String>,
+    xmlns: String,
+    sitemaps: Vec<Sitemap>,
+}
+
+impl SitemapIndexBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            xml_version: XmlVersion::Xml1_0,
+            xml_encoding: ENCODING.to_string(),
+            stylesheet: None,
+            xmlns: NAMESPACE.to_string(),
+            sitemaps: vec![],
+        }
+    }
+
+    pub const fn xml_version(&mut self, xml_version: XmlVersion) -> &mut Self {
+        self.xml_version = xml_version;
+        self
+    }
+
+    pub fn xml_encoding(&mut self, xml_encoding: String) -> &mut Self {
+        self.xml_encoding = xml_encoding;
+        self
+    }
+
+    pub fn stylesheet(&mut self, stylesheet: String) -> &mut Self {
+        self.stylesheet = Some(stylesheet);
+        self
+    }
+
+    pub fn xmlns(&mut self, xmlns: String) -> &mut Self {
+        self.xmlns = xmlns;
+        self
+    }
+
+    /// Adds `sitemap` to the end of the index, if it and the index would still be valid.
+    ///
+    /// # Errors
+    ///
+    /// Will return the rejected `sitemap` along with every rule it broke.
+    /// This includes `SitemapIndexError::TooManySitemaps` if adding `sitemap` would put the index above `50,000` sitemaps.
+    pub fn push(
+        &mut self,
+        sitemap: Sitemap,
+    ) -> Result<&mut Self, Box<Rejected<Sitemap, SitemapIndexError>>> {
+        let index: usize = self.sitemaps.len();
+        let mut errors: Vec<SitemapIndexError> = sitemap.validate();
+
+        // SitemapIndex cannot contain more than 50,000 sitemaps
+        if index + 1 > 50_000 {
+            errors.push(SitemapIndexError::TooManySitemaps(index + 1));
+        }
+
+        if !errors.is_empty() {
+            return Err(Box::new(Rejected::new(index, sitemap, errors)));
+        }
+
+        self.sitemaps.push(sitemap);
+        Ok(self)
+    }
+
+    /// The number of \<sitemap\> pushed so far.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.sitemaps.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.sitemaps.is_empty()
+    }
+
+    #[must_use]
+    pub fn build(&self) -> SitemapIndex {
+        SitemapIndex {
+            xml_version: self.xml_version,
+            xml_encoding: self.xml_encoding.clone(),
+            stylesheet: self.stylesheet.clone(),
+            xmlns: self.xmlns.clone(),
+            sitemaps: self.sitemaps.clone(),
+        }
+    }
+}
+
+impl Default for SitemapIndexBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,3 +1,4 @@
+use crate::date_error::DateError;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
@@ -6,6 +7,43 @@ use std::fmt::{Display, Formatter};
 pub enum SitemapIndexError {
     /// Returned when a \<sitemapindex\> contains more than `50,000` \<sitemap\>.
     TooManySitemaps(usize),
+
+    /// Returned when a \<sitemap\> entry's `loc` is 2,048 characters or more.
+    ///
+    /// Reports both the Unicode character count (which is what the limit applies to) and the byte count.
+    LocationTooLong {
+        location: String,
+        characters: usize,
+        bytes: usize,
+    },
+
+    /// Returned when a \<sitemap\> entry's `loc` references another sitemap index, which the protocol forbids.
+    NestedIndex(String),
+
+    /// Returned when a \<sitemap\> entry's `loc` is on a different host than the sitemap index itself.
+    ///
+    /// Search engines only accept such entries if the other host has been verified as well.
+    CrossHost { location: String, host: String },
+
+    /// Returned when the `last_modified` given to a `SitemapBuilder` can't be converted to a sitemap date.
+    Date {
+        field: &'static str,
+        error: DateError,
+    },
+}
+
+impl SitemapIndexError {
+    /// The name of the `SitemapIndex` or `Sitemap` field this error is about.
+    #[must_use]
+    pub const fn field(&self) -> &'static str {
+        match self {
+            Self::TooManySitemaps(_) => "sitemaps",
+            Self::LocationTooLong { .. } | Self::NestedIndex(_) | Self::CrossHost { .. } => {
+                "location"
+            }
+            Self::Date { field, .. } => field,
+        }
+    }
 }
 
 impl Error for SitemapIndexError {}
@@ -16,6 +54,29 @@ impl Display for SitemapIndexError {
             Self::TooManySitemaps(count) => {
                 write!(f, "must not contain more than 50,000 sitemaps: {count}")
             }
+            Self::LocationTooLong {
+                location,
+                characters,
+                bytes,
+            } => {
+                write!(
+                    f,
+                    "location must be less than 2,048 characters: {characters} characters ({bytes} bytes): {location}"
+                )
+            }
+            Self::NestedIndex(location) => {
+                write!(
+                    f,
+                    "location must not reference another sitemap index: {location}"
+                )
+            }
+            Self::CrossHost { location, host } => {
+                write!(
+                    f,
+                    "location should be on the same host as the sitemap index ({host}): {location}"
+                )
+            }
+            Self::Date { field, error } => write!(f, "{field} is not a valid date: {error}"),
         }
     }
 }
@@ -0,0 +1,76 @@
+use crate::sitemap::Sitemap;
+use crate::sitemap_index::SitemapIndex;
+use crate::sitemap_index_error::SitemapIndexError;
+use crate::url::Url;
+use crate::url_set::UrlSet;
+use crate::url_set_error::UrlSetError;
+use chrono::{DateTime, FixedOffset};
+
+/// The most \<url\> a single \<urlset\> can contain.
+pub const MAX_URLS: usize = 50_000;
+
+/// The most \<url\> a single \<urlset\> can contain, if any of them contain news.
+pub const MAX_NEWS_URLS: usize = 1_000;
+
+/// Splits `urls` into as few `UrlSet`s as the sitemap limits allow, keeping their order.
+///
+/// A `UrlSet` holds up to `50,000` URLs, or up to `1,000` once it contains any news.
+///
+/// # Errors
+///
+/// Will return `UrlSetError::NamespaceConflict` if two extensions within the same `UrlSet` use the same namespace prefix for different namespace URIs.
+pub fn split_urls(urls: Vec<Url>) -> Result<Vec<UrlSet>, UrlSetError> {
+    let mut url_sets: Vec<UrlSet> = vec![];
+    let mut chunk: Vec<Url> = vec![];
+    let mut chunk_has_news: bool = false;
+
+    for url in urls {
+        let has_news: bool = chunk_has_news || url.news.is_some();
+        let limit: usize = if has_news { MAX_NEWS_URLS } else { MAX_URLS };
+
+        // start a new chunk if this URL wouldn't fit in the current one
+        if chunk.len() + 1 > limit {
+            url_sets.push(UrlSet::new(std::mem::take(&mut chunk))?);
+            chunk_has_news = url.news.is_some();
+        } else {
+            chunk_has_news = has_news;
+        }
+
+        chunk.push(url);
+    }
+
+    if !chunk.is_empty() || url_sets.is_empty() {
+        url_sets.push(UrlSet::new(chunk)?);
+    }
+
+    Ok(url_sets)
+}
+
+/// Creates a `SitemapIndex` that references each of `url_sets`.
+///
+/// `location` is given the index of each `UrlSet` and returns where it is hosted, such as `https://www.toddgriffin.me/sitemap-1.xml`.
+/// The last modification date of each \<sitemap\> is the latest `last_modified` of its URLs.
+///
+/// # Errors
+///
+/// Will return `SitemapIndexError::TooManySitemaps` if the length of `url_sets` is above `50,000`.
+/// Will return `SitemapIndexError::LocationTooLong` if any `location` is 2,048 characters or more.
+pub fn sitemap_index(
+    url_sets: &[UrlSet],
+    location: impl Fn(usize) -> String,
+) -> Result<SitemapIndex, SitemapIndexError> {
+    let sitemaps: Vec<Sitemap> = url_sets
+        .iter()
+        .enumerate()
+        .map(|(index, url_set)| {
+            let last_modified: Option<DateTime<FixedOffset>> = url_set
+                .urls
+                .iter()
+                .filter_map(|url| url.last_modified)
+                .max();
+            Sitemap::new(location(index), last_modified)
+        })
+        .collect();
+
+    SitemapIndex::new(sitemaps)
+}
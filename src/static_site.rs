@@ -0,0 +1,554 @@
+use crate::html::{Tag, tags};
+use crate::image::Image;
+use crate::split::split_urls;
+use crate::static_site_error::StaticSiteError;
+use crate::url::{Link, Url};
+use crate::url_builder::UrlBuilder;
+use crate::url_error::UrlError;
+use crate::url_set::UrlSet;
+use crate::video::Video;
+use crate::video_builder::VideoBuilder;
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter, Write};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::time::SystemTime;
+
+/// A URL can list no more than `1,000` images.
+const MAX_IMAGES: usize = 1000;
+
+/// Generates sitemap URLs from the build directory of a static site, such as the output of Hugo, Zola, or mdBook.
+///
+/// Every `*.html` file becomes a URL under `base_url`, and `index.html` files become the URL of their directory, with a trailing slash.
+/// Hidden files and directories (starting with `.`) are skipped.
+#[derive(Debug, Clone)]
+#[expect(clippy::struct_excessive_bools)]
+pub struct StaticSite {
+    root: PathBuf,
+    base_url: String,
+    last_modified: bool,
+    honor_noindex: bool,
+    honor_canonical: bool,
+    collect_images: bool,
+    collect_alternates: bool,
+    collect_videos: bool,
+    exclude: Vec<PathBuf>,
+}
+
+impl StaticSite {
+    /// Walks `root`, which is served at `base_url`.
+    ///
+    /// `404.html` at the root of the site is excluded by default.
+    #[must_use]
+    pub fn new(root: PathBuf, base_url: String) -> Self {
+        Self {
+            root,
+            base_url,
+            last_modified: true,
+            honor_noindex: true,
+            honor_canonical: true,
+            collect_images: true,
+            collect_alternates: true,
+            collect_videos: true,
+            exclude: vec![PathBuf::from("404.html")],
+        }
+    }
+
+    /// Whether to use the modification time of each file as its `last_modified`.
+    ///
+    /// Defaults to `true`.
+    pub const fn last_modified(&mut self, last_modified: bool) -> &mut Self {
+        self.last_modified = last_modified;
+        self
+    }
+
+    /// Whether to leave out pages with `<meta name="robots" content="noindex">`.
+    ///
+    /// Defaults to `true`.
+    pub const fn honor_noindex(&mut self, honor_noindex: bool) -> &mut Self {
+        self.honor_noindex = honor_noindex;
+        self
+    }
+
+    /// Whether to list pages with `<link rel="canonical">` under their canonical URL instead.
+    ///
+    /// Pages whose canonical URL is outside of `base_url` are left out, and pages that share a canonical URL are listed once.
+    /// Defaults to `true`.
+    pub const fn honor_canonical(&mut self, honor_canonical: bool) -> &mut Self {
+        self.honor_canonical = honor_canonical;
+        self
+    }
+
+    /// Whether to list the `<img src>` of each page as its images.
+    ///
+    /// Defaults to `true`.
+    pub const fn collect_images(&mut self, collect_images: bool) -> &mut Self {
+        self.collect_images = collect_images;
+        self
+    }
+
+    /// Whether to list the `<link rel="alternate" hreflang>` of each page as its links.
+    ///
+    /// Defaults to `true`.
+    pub const fn collect_alternates(&mut self, collect_alternates: bool) -> &mut Self {
+        self.collect_alternates = collect_alternates;
+        self
+    }
+
+    /// Whether to list the `<video>` elements of each page as its videos, along with any JSON-LD `VideoObject` with the `json-ld` feature.
+    ///
+    /// A `<video>` needs a `poster` and a `src` (of its own or of a `<source>`), and is titled and described by the page's `<title>` and `<meta name="description">` unless it has a `title` or `aria-label` of its own.
+    /// Videos missing any of these are left out.
+    /// Defaults to `true`.
+    pub const fn collect_videos(&mut self, collect_videos: bool) -> &mut Self {
+        self.collect_videos = collect_videos;
+        self
+    }
+
+    /// Leaves out `path`, relative to the root of the site; a directory leaves out everything within it.
+    pub fn exclude(&mut self, path: PathBuf) -> &mut Self {
+        self.exclude.push(path);
+        self
+    }
+
+    /// Walks the site and returns a `Url` for every page, sorted by file path.
+    ///
+    /// Pages with more than `1,000` images are listed with only their first `1,000`; see `StaticSite::urls_with_warnings()` to find out which.
+    ///
+    /// # Errors
+    ///
+    /// Will return `StaticSiteError::Io` if a file or directory within the site can't be read.
+    /// Will return `StaticSiteError::Url` if the URL of a page breaks a rule, such as being 2,048 characters or more.
+    pub fn urls(&self) -> Result<Vec<Url>, StaticSiteError> {
+        self.urls_with_warnings().map(|(urls, _)| urls)
+    }
+
+    /// Walks the site and returns a `Url` for every page, sorted by file path, along with everything that had to be left out of them.
+    ///
+    /// # Errors
+    ///
+    /// Will return the same errors as `StaticSite::urls()`.
+    pub fn urls_with_warnings(
+        &self,
+    ) -> Result<(Vec<Url>, Vec<StaticSiteWarning>), StaticSiteError> {
+        let mut pages: Vec<PathBuf> = vec![];
+        self.walk(&self.root, &mut pages)?;
+
+        let mut urls: Vec<Url> = vec![];
+        let mut warnings: Vec<StaticSiteWarning> = vec![];
+        let mut indexes: HashMap<String, usize> = HashMap::new();
+
+        for path in pages {
+            let Some(url) = self.page(&path, &mut warnings)? else {
+                continue;
+            };
+
+            // pages that share a canonical URL are listed once, as recently modified as the newest of them
+            if let Some(&index) = indexes.get(&url.location) {
+                let existing: &mut Url = &mut urls[index];
+                existing.last_modified = existing.last_modified.max(url.last_modified);
+                continue;
+            }
+
+            indexes.insert(url.location.clone(), urls.len());
+            urls.push(url);
+        }
+
+        Ok((urls, warnings))
+    }
+
+    /// Walks the site and splits its URLs into as few `UrlSet`s as the sitemap limits allow.
+    ///
+    /// # Errors
+    ///
+    /// Will return the same errors as `StaticSite::urls()`.
+    /// Will return `StaticSiteError::UrlSet` if the pages can't be split into `UrlSet`s.
+    pub fn url_sets(&self) -> Result<Vec<UrlSet>, StaticSiteError> {
+        split_urls(self.urls()?).map_err(StaticSiteError::UrlSet)
+    }
+
+    /// Collects every `*.html` file within `directory`, sorted by path.
+    fn walk(&self, directory: &Path, pages: &mut Vec<PathBuf>) -> Result<(), StaticSiteError> {
+        let io_error = |error| StaticSiteError::Io {
+            path: directory.to_path_buf(),
+            error,
+        };
+
+        let mut entries: Vec<fs::DirEntry> = fs::read_dir(directory)
+            .map_err(io_error)?
+            .collect::<Result<_, _>>()
+            .map_err(io_error)?;
+        entries.sort_by_key(fs::DirEntry::file_name);
+
+        for entry in entries {
+            let path: PathBuf = entry.path();
+            if entry.file_name().to_string_lossy().starts_with('.') || self.is_excluded(&path) {
+                continue;
+            }
+
+            let file_type: fs::FileType = entry.file_type().map_err(io_error)?;
+            if file_type.is_dir() {
+                self.walk(&path, pages)?;
+            } else if path
+                .extension()
+                .is_some_and(|extension| extension == "html")
+            {
+                pages.push(path);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_excluded(&self, path: &Path) -> bool {
+        path.strip_prefix(&self.root).is_ok_and(|relative| {
+            self.exclude
+                .iter()
+                .any(|excluded| relative.starts_with(excluded))
+        })
+    }
+
+    /// Returns the `Url` of the page at `path`, or `None` if it should be left out.
+    fn page(
+        &self,
+        path: &Path,
+        warnings: &mut Vec<StaticSiteWarning>,
+    ) -> Result<Option<Url>, StaticSiteError> {
+        let io_error = |error| StaticSiteError::Io {
+            path: path.to_path_buf(),
+            error,
+        };
+
+        let page_location: String = self.location(path);
+        let mut builder: UrlBuilder = Url::builder(page_location.clone());
+
+        if self.honor_noindex
+            || self.honor_canonical
+            || self.collect_images
+            || self.collect_alternates
+            || self.collect_videos
+        {
+            let html: Vec<u8> = fs::read(path).map_err(io_error)?;
+            let tags: Vec<Tag> = tags(&String::from_utf8_lossy(&html));
+            let head = || tags.iter().filter(|tag| tag.head);
+
+            if self.honor_noindex && head().any(is_noindex) {
+                return Ok(None);
+            }
+
+            if self.honor_canonical
+                && let Some(canonical) = head().find_map(canonical)
+            {
+                let canonical: String = resolve(canonical, &page_location);
+                // `https://example.com.evil.net` starts with `https://example.com`, but is not within it
+                let within_site: bool = canonical
+                    .strip_prefix(self.base_url.trim_end_matches('/'))
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'));
+                if !within_site {
+                    return Ok(None);
+                }
+                builder.location = canonical;
+            }
+
+            if self.collect_alternates {
+                builder.links(head().filter_map(|tag| alternate(tag, &page_location)));
+            }
+
+            if self.collect_images {
+                let mut images: Vec<Image> = images(&tags, &page_location);
+                if images.len() > MAX_IMAGES {
+                    warnings.push(StaticSiteWarning::Url {
+                        path: path.to_path_buf(),
+                        error: UrlError::TooManyImages(images.len()),
+                    });
+                    images.truncate(MAX_IMAGES);
+                }
+                if !images.is_empty() {
+                    builder.images(images);
+                }
+            }
+
+            if self.collect_videos {
+                // long text is truncated and out-of-range durations are left out, so building a video can't fail
+                let videos: Vec<Video> = videos(&tags, &page_location)
+                    .iter()
+                    .filter_map(|video| video.build().ok())
+                    .collect();
+                if !videos.is_empty() {
+                    builder.videos(videos);
+                }
+            }
+        }
+
+        if self.last_modified {
+            let modified: SystemTime = fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .map_err(io_error)?;
+            builder.last_modified(DateTime::<Utc>::from(modified));
+        }
+
+        builder
+            .build()
+            .map(Some)
+            .map_err(|error| StaticSiteError::Url {
+                path: path.to_path_buf(),
+                error,
+            })
+    }
+
+    /// The URL of the page at `path`, such as `https://www.toddgriffin.me/blog/` for `blog/index.html`.
+    fn location(&self, path: &Path) -> String {
+        let relative: &Path = path.strip_prefix(&self.root).unwrap_or(path);
+        let mut segments: Vec<String> = relative
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(segment) => Some(encode(&segment.to_string_lossy())),
+                _ => None,
+            })
+            .collect();
+
+        // index.html is served as its directory
+        if segments
+            .last()
+            .is_some_and(|segment| segment == "index.html")
+        {
+            segments.pop();
+            segments.push(String::new());
+        }
+
+        format!(
+            "{}/{}",
+            self.base_url.trim_end_matches('/'),
+            segments.join("/")
+        )
+    }
+}
+
+/// Something that had to be left out of a page's URL; the page and the rest of the site are still listed.
+#[derive(Debug)]
+pub enum StaticSiteWarning {
+    /// Returned when the URL of a page breaks a rule that is worked around instead of failing.
+    ///
+    /// For `UrlError::TooManyImages`, only the first `1,000` images of the page are listed.
+    Url { path: PathBuf, error: UrlError },
+}
+
+impl Display for StaticSiteWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Url {
+                path,
+                error: error @ UrlError::TooManyImages(_),
+            } => write!(
+                f,
+                "{}: {error}; only the first 1,000 are listed",
+                path.display()
+            ),
+            Self::Url { path, error } => write!(f, "{}: {error}", path.display()),
+        }
+    }
+}
+
+/// Whether `tag` is `<meta name="robots" content="noindex">`, or the same for Googlebot.
+fn is_noindex(tag: &Tag) -> bool {
+    tag.name == "meta"
+        && tag.attribute("name").is_some_and(|name| {
+            name.eq_ignore_ascii_case("robots") || name.eq_ignore_ascii_case("googlebot")
+        })
+        && (tag.attribute_contains("content", "noindex")
+            || tag.attribute_contains("content", "none"))
+}
+
+/// The URL of `tag`, if it is `<link rel="canonical">`.
+fn canonical(tag: &Tag) -> Option<&str> {
+    if tag.name == "link" && tag.attribute_contains("rel", "canonical") {
+        tag.attribute("href").filter(|href| !href.is_empty())
+    } else {
+        None
+    }
+}
+
+/// The `Link` of `tag`, if it is `<link rel="alternate" hreflang>`.
+fn alternate(tag: &Tag, page: &str) -> Option<Link> {
+    if tag.name != "link" || !tag.attribute_contains("rel", "alternate") {
+        return None;
+    }
+    let hreflang: &str = tag
+        .attribute("hreflang")
+        .filter(|hreflang| !hreflang.is_empty())?;
+    let href: &str = tag.attribute("href").filter(|href| !href.is_empty())?;
+    Some(Link::new(hreflang.to_string(), resolve(href, page)))
+}
+
+/// The `<img src>` of a page, once each, leaving out inline `data:` images.
+fn images(tags: &[Tag], page: &str) -> Vec<Image> {
+    let mut seen: HashSet<String> = HashSet::new();
+    tags.iter()
+        .filter(|tag| tag.name == "img")
+        .filter_map(|tag| tag.attribute("src"))
+        .filter(|src| !src.is_empty() && !src.starts_with("data:"))
+        .map(|src| resolve(src, page))
+        .filter(|location| seen.insert(location.clone()))
+        .map(Image::new)
+        .collect()
+}
+
+/// The `<video>` elements of a page that have every required field, followed by its JSON-LD `VideoObject`s, once each.
+fn videos(tags: &[Tag], page: &str) -> Vec<VideoBuilder> {
+    let title: Option<&str> = tags
+        .iter()
+        .find(|tag| tag.name == "title")
+        .map(|tag| tag.text.as_str())
+        .filter(|title| !title.is_empty());
+    let description: Option<&str> = tags
+        .iter()
+        .filter(|tag| tag.head && tag.name == "meta")
+        .find(|tag| {
+            tag.attribute("name")
+                .is_some_and(|name| name.eq_ignore_ascii_case("description"))
+                || tag.attribute("property") == Some("og:description")
+        })
+        .and_then(|tag| tag.attribute("content"))
+        .filter(|description| !description.is_empty());
+
+    let mut videos: Vec<VideoBuilder> = vec![];
+    let mut video: Option<(&Tag, Option<&str>)> = None;
+    for tag in tags {
+        match tag.name.as_str() {
+            "video" => video = Some((tag, tag.attribute("src").filter(|src| !src.is_empty()))),
+            "source" => {
+                if let Some((_, source @ None)) = &mut video {
+                    *source = tag.attribute("src").filter(|src| !src.is_empty());
+                }
+            }
+            "/video" => {
+                let Some((video_tag, Some(source))) = video.take() else {
+                    continue;
+                };
+                let video_title: Option<&str> = video_tag
+                    .attribute("title")
+                    .or_else(|| video_tag.attribute("aria-label"))
+                    .filter(|title| !title.is_empty())
+                    .or(title);
+                if let (Some(poster), Some(video_title), Some(description)) = (
+                    video_tag
+                        .attribute("poster")
+                        .filter(|poster| !poster.is_empty()),
+                    video_title,
+                    description,
+                ) {
+                    let mut builder: VideoBuilder = VideoBuilder::new(
+                        poster.to_string(),
+                        video_title.to_string(),
+                        description.to_string(),
+                        source.to_string(),
+                        String::new(),
+                    );
+                    builder.truncate_long_text(true);
+                    videos.push(builder);
+                }
+            }
+            #[cfg(feature = "json-ld")]
+            "script" if tag.attribute("type") == Some("application/ld+json") => {
+                videos.extend(crate::json_ld::videos(&tag.text));
+            }
+            _ => {}
+        }
+    }
+
+    // URLs are resolved against the page, and a video described both ways is listed once
+    let mut seen: HashSet<(String, String)> = HashSet::new();
+    videos
+        .into_iter()
+        .filter_map(|mut video| {
+            for location in [
+                &mut video.thumbnail_location,
+                &mut video.content_location,
+                &mut video.player_location,
+            ] {
+                if !location.is_empty() {
+                    *location = resolve(location, page);
+                }
+            }
+            seen.insert((
+                video.content_location.clone(),
+                video.player_location.clone(),
+            ))
+            .then_some(video)
+        })
+        .collect()
+}
+
+/// Resolves `href` against the URL of the page it was found on, without its fragment.
+fn resolve(href: &str, page: &str) -> String {
+    let href: &str = href.split('#').next().unwrap_or_default();
+    let origin_end: usize = page
+        .find("://")
+        .and_then(|scheme_end| {
+            page[scheme_end + 3..]
+                .find('/')
+                .map(|path_start| scheme_end + 3 + path_start)
+        })
+        .unwrap_or(page.len());
+
+    let resolved: String = if href.starts_with("http://") || href.starts_with("https://") {
+        href.to_string()
+    } else if href.starts_with("//") {
+        let scheme: &str = page.split("://").next().unwrap_or("https");
+        format!("{scheme}:{href}")
+    } else if href.starts_with('/') {
+        format!("{}{href}", &page[..origin_end])
+    } else {
+        let directory: &str = page.rfind('/').map_or(page, |end| &page[..=end]);
+        format!("{directory}{href}")
+    };
+
+    remove_dot_segments(&resolved)
+}
+
+/// Resolves `.` and `..` within the path of `url`.
+fn remove_dot_segments(url: &str) -> String {
+    let Some(path_start) = url.find("://").and_then(|scheme_end| {
+        url[scheme_end + 3..]
+            .find('/')
+            .map(|start| scheme_end + 3 + start)
+    }) else {
+        return url.to_string();
+    };
+    let (origin, rest): (&str, &str) = url.split_at(path_start);
+    let (path, query): (&str, &str) = rest
+        .find('?')
+        .map_or((rest, ""), |start| rest.split_at(start));
+
+    let mut segments: Vec<&str> = vec![];
+    let mut trailing_slash: bool = false;
+    for segment in path.split('/').skip(1) {
+        trailing_slash = matches!(segment, "" | "." | "..");
+        match segment {
+            "." => {}
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+    if trailing_slash && segments.last().is_some_and(|segment| !segment.is_empty()) {
+        segments.push("");
+    }
+
+    format!("{origin}/{}{query}", segments.join("/"))
+}
+
+/// Percent-encodes the characters of a path segment that aren't allowed within a URL.
+fn encode(segment: &str) -> String {
+    let mut encoded: String = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~!$&'()*+,;=:@".contains(&byte) {
+            encoded.push(char::from(byte));
+        } else {
+            write!(encoded, "%{byte:02X}").expect("writing to a String can't fail");
+        }
+    }
+    encoded
+}
@@ -0,0 +1,39 @@
+use crate::url_error::UrlError;
+use crate::url_set_error::UrlSetError;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::PathBuf;
+
+/// An error when generating a sitemap from a static site's build directory.
+#[derive(Debug)]
+pub enum StaticSiteError {
+    /// Returned when a file or directory within the site can't be read.
+    Io { path: PathBuf, error: io::Error },
+
+    /// Returned when the URL of a page breaks a rule, such as being 2,048 characters or more.
+    Url { path: PathBuf, error: UrlError },
+
+    /// Returned when the pages can't be split into `UrlSet`s.
+    UrlSet(UrlSetError),
+}
+
+impl Error for StaticSiteError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { error, .. } => Some(error),
+            Self::Url { error, .. } => Some(error),
+            Self::UrlSet(error) => Some(error),
+        }
+    }
+}
+
+impl Display for StaticSiteError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { path, error } => write!(f, "{}: {error}", path.display()),
+            Self::Url { path, error } => write!(f, "{}: {error}", path.display()),
+            Self::UrlSet(error) => write!(f, "{error}"),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+//! Default XSL stylesheets, so browsers render sitemaps as an HTML table instead of raw XML.
+//!
+//! Write one of these next to your sitemap (for example as `sitemap.xsl`) and point the sitemap's `stylesheet` at it.
+
+/// An XSL stylesheet for \<urlset\> documents.
+pub const URL_SET_XSL: &str = include_str!("xsl/urlset.xsl");
+
+/// An XSL stylesheet for \<sitemapindex\> documents.
+pub const SITEMAP_INDEX_XSL: &str = include_str!("xsl/sitemapindex.xsl");
@@ -0,0 +1,28 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Truncates `text` to at most `max_characters` Unicode characters.
+///
+/// The cut is always made on a grapheme cluster boundary, so combining marks, emoji sequences,
+/// and similar multi-character graphemes are never split in half.
+/// This means the result may be a few characters shorter than `max_characters`.
+#[must_use]
+pub fn truncate_to_characters(text: &str, max_characters: usize) -> &str {
+    let mut characters: usize = 0;
+    let mut end: usize = 0;
+    for (index, grapheme) in text.grapheme_indices(true) {
+        characters += grapheme.chars().count();
+        if characters > max_characters {
+            return &text[..end];
+        }
+        end = index + grapheme.len();
+    }
+    text
+}
+
+/// Truncates `text` in place to at most `max_characters` Unicode characters.
+///
+/// See `truncate_to_characters()` for how the grapheme cluster boundary is chosen.
+pub fn truncate_string(text: &mut String, max_characters: usize) {
+    let length: usize = truncate_to_characters(text, max_characters).len();
+    text.truncate(length);
+}
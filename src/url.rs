@@ -1,18 +1,24 @@
+use crate::XHTML_NAMESPACE;
+use crate::extension::{Extension, Namespace};
 use crate::image::Image;
 use crate::news::News;
 use crate::url_builder::UrlBuilder;
-use crate::url_error::UrlError;
+use crate::url_error::{ParseChangeFrequencyError, UrlError};
 use crate::video::Video;
-use crate::{RFC_3339_SECONDS_FORMAT, RFC_3339_USE_Z};
+use crate::xml_writer::XmlWriter;
 use chrono::{DateTime, FixedOffset};
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
-use xml_builder::{XMLElement, XMLError};
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::str::FromStr;
+use std::sync::Arc;
 
 /// The default `priority` of a sitemap.xml <url>.
 pub const DEFAULT_PRIORITY: f32 = 0.5;
 
 /// A \<url\> entry within a sitemap.xml.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Url {
     /// URL of the page.
     ///
@@ -50,7 +56,7 @@ pub struct Url {
     /// Search engines may use this information when selecting between URLs on the same site, so you can use this tag to increase the likelihood that your most important pages are present in a search index.
     /// Also, please note that assigning a high priority to all the URLs on your site is not likely to help you.
     /// Since the priority is relative, it is only used to select between URLs on your site.
-    pub priority: Option<f32>,
+    pub priority: Option<Priority>,
 
     /// Images associated with this URL.
     pub images: Option<Vec<Image>>,
@@ -60,6 +66,9 @@ pub struct Url {
 
     /// News associated with this URL.
     pub news: Option<News>,
+
+    /// Other sitemap extensions associated with this URL, such as `ExtensionElement`s or your own `Extension`s, written after all other elements.
+    pub extensions: Vec<Arc<dyn Extension + Send + Sync>>,
 }
 
 impl Url {
@@ -68,6 +77,7 @@ impl Url {
     /// Will return `UrlError::LocationTooLong` if `location` is 2,048 characters or more.
     /// Will return `UrlError::PriorityTooLow` if `priority` is below `0.0`.
     /// Will return `UrlError::PriorityTooHigh` if `priority` is above `1.0`.
+    /// Will return `UrlError::PriorityNotANumber` if `priority` is NaN.
     /// Will return `UrlError::TooManyImages` if the length of `images` is above `1,000`.
     #[expect(clippy::too_many_arguments)]
     pub fn new(
@@ -80,38 +90,12 @@ impl Url {
         videos: Option<Vec<Video>>,
         news: Option<News>,
     ) -> Result<Self, UrlError> {
-        // make sure location is less than 2,048 characters
-        if location.len() >= 2048 {
-            return Err(UrlError::LocationTooLong(location));
-        }
+        let priority: Option<Priority> = priority.map(Priority::new).transpose()?;
 
-        // make sure priority is within bounds: 0.0 <= priority <= 1.0
-        if let Some(p) = priority {
-            if p < 0.0 {
-                return Err(UrlError::PriorityTooLow(p));
-            }
-            if p > 1.0 {
-                return Err(UrlError::PriorityTooHigh(p));
-            }
-        }
+        // an empty list of images is the same as no images at all
+        let images: Option<Vec<Image>> = images.filter(|images| !images.is_empty());
 
-        // make sure there aren't too many images
-        let images: Option<Vec<Image>> = match images {
-            None => None,
-            Some(images) => {
-                if images.len() > 1000 {
-                    return Err(UrlError::TooManyImages(images.len()));
-                }
-
-                if images.is_empty() {
-                    None
-                } else {
-                    Some(images)
-                }
-            }
-        };
-
-        Ok(Self {
+        let url: Self = Self {
             location,
             links,
             last_modified,
@@ -120,7 +104,41 @@ impl Url {
             images,
             videos,
             news,
-        })
+            extensions: vec![],
+        };
+
+        match url.validate().into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(url),
+        }
+    }
+
+    /// Checks every rule that applies to this URL entry and returns all violations, instead of only the first one.
+    ///
+    /// This does not check the URL's `videos`; see `Video::validate()`.
+    /// A `Priority` is always valid, so it isn't checked either.
+    #[must_use]
+    pub fn validate(&self) -> Vec<UrlError> {
+        let mut errors: Vec<UrlError> = vec![];
+
+        // make sure location is less than 2,048 characters
+        let characters: usize = self.location.chars().count();
+        if characters >= 2048 {
+            errors.push(UrlError::LocationTooLong {
+                location: self.location.clone(),
+                characters,
+                bytes: self.location.len(),
+            });
+        }
+
+        // make sure there aren't too many images
+        if let Some(images) = &self.images
+            && images.len() > 1000
+        {
+            errors.push(UrlError::TooManyImages(images.len()));
+        }
+
+        errors
     }
 
     #[must_use]
@@ -128,72 +146,190 @@ impl Url {
         UrlBuilder::new(location)
     }
 
+    /// Every extension within this URL entry: alternate language links, images, videos, news, and then all other `extensions`.
+    pub fn extensions(&self) -> impl Iterator<Item = &dyn Extension> {
+        let links = self.links.iter().map(|link| link as &dyn Extension);
+        let images = self
+            .images
+            .iter()
+            .flatten()
+            .map(|image| image as &dyn Extension);
+        let videos = self
+            .videos
+            .iter()
+            .flatten()
+            .map(|video| video as &dyn Extension);
+        let news = self.news.iter().map(|news| news as &dyn Extension);
+        let extensions = self
+            .extensions
+            .iter()
+            .map(|extension| extension.as_ref() as &dyn Extension);
+
+        links
+            .chain(images)
+            .chain(videos)
+            .chain(news)
+            .chain(extensions)
+    }
+
     /// # Errors
     ///
-    /// Will return `XMLError` if there is a problem creating XML elements.
-    pub fn to_xml(self) -> Result<XMLElement, XMLError> {
-        let mut url: XMLElement = XMLElement::new("url");
+    /// Will return an IO Error if there is a problem writing to the underlying writer.
+    pub(crate) fn write_xml(&self, writer: &mut XmlWriter<'_>) -> io::Result<()> {
+        writer.start("url", &[])?;
 
         // add <loc>
-        let mut loc: XMLElement = XMLElement::new("loc");
-        loc.add_text(self.location)?;
-        url.add_child(loc)?;
+        writer.text("loc", &[], &self.location)?;
+
+        // when sorting, everything within the <url> is written in a fixed order too
+        let sorted: bool = writer.options().order.is_some();
 
         // add <xhtml:link>, if any exists
-        for link in self.links {
-            let mut xhtml_link = XMLElement::new("xhtml:link");
-            xhtml_link.add_attribute("rel", "alternate");
-            xhtml_link.add_attribute("hreflang", &link.hreflang);
-            xhtml_link.add_attribute("href", &link.href);
-            url.add_child(xhtml_link)?;
+        let mut links: Vec<&Link> = self.links.iter().collect();
+        if sorted {
+            links.sort();
+        }
+        for link in links {
+            link.write_xml(writer)?;
         }
 
         // add <lastmod>, if it exists
         if let Some(last_modified) = self.last_modified {
-            let mut lastmod: XMLElement = XMLElement::new("lastmod");
-            lastmod
-                .add_text(last_modified.to_rfc3339_opts(RFC_3339_SECONDS_FORMAT, RFC_3339_USE_Z))?;
-            url.add_child(lastmod)?;
+            writer.date("lastmod", &last_modified)?;
         }
 
         // add <changefreq>, if it exists
         if let Some(change_frequency) = self.change_frequency {
-            let mut changefreq: XMLElement = XMLElement::new("changefreq");
-            changefreq.add_text(change_frequency.to_string())?;
-            url.add_child(changefreq)?;
+            writer.text("changefreq", &[], change_frequency.as_str())?;
         }
 
         // add <priority>, if it exists
-        if let Some(p) = self.priority {
-            let mut priority: XMLElement = XMLElement::new("priority");
-            priority.add_text(p.to_string())?;
-            url.add_child(priority)?;
+        if let Some(priority) = self.priority {
+            writer.text("priority", &[], &priority.to_string())?;
         }
 
         // add <image:image>, if any exist
-        if let Some(images) = self.images {
+        if let Some(images) = &self.images {
+            let mut images: Vec<&Image> = images.iter().collect();
+            if sorted {
+                images.sort();
+            }
             for image in images {
-                url.add_child(image.to_xml()?)?;
+                image.write_xml(writer)?;
             }
         }
 
         // add <video:video>, if any exist
-        if let Some(videos) = self.videos {
+        if let Some(videos) = &self.videos {
+            let mut videos: Vec<&Video> = videos.iter().collect();
+            if sorted {
+                videos.sort();
+            }
             for video in videos {
-                url.add_child(video.to_xml()?)?;
+                video.write_xml(writer)?;
             }
         }
 
         // add <news:news>, if any exist
-        if let Some(news) = self.news {
-            url.add_child(news.to_xml()?)?;
+        if let Some(news) = &self.news {
+            news.write_xml(writer)?;
+        }
+
+        // add elements of other extensions, if any exist
+        let mut extensions: Vec<&(dyn Extension + Send + Sync)> =
+            self.extensions.iter().map(AsRef::as_ref).collect();
+        if sorted {
+            extensions.sort();
+        }
+        for extension in extensions {
+            extension.write_xml(writer)?;
+        }
+
+        writer.end("url")
+    }
+}
+
+/// The priority of a URL relative to other URLs on your site, checked to be within `0.0` to `1.0`.
+///
+/// It is displayed as given, the same as `<priority>` is written.
+/// As it is never NaN, it is totally ordered, and can be hashed.
+#[derive(Debug, Copy, Clone)]
+pub struct Priority(f32);
+
+impl Priority {
+    /// # Errors
+    ///
+    /// Will return `UrlError::PriorityTooLow` if `priority` is below `0.0`.
+    /// Will return `UrlError::PriorityTooHigh` if `priority` is above `1.0`.
+    /// Will return `UrlError::PriorityNotANumber` if `priority` is NaN.
+    pub fn new(priority: f32) -> Result<Self, UrlError> {
+        if priority.is_nan() {
+            return Err(UrlError::PriorityNotANumber);
         }
+        if priority < 0.0 {
+            return Err(UrlError::PriorityTooLow(priority));
+        }
+        if priority > 1.0 {
+            return Err(UrlError::PriorityTooHigh(priority));
+        }
+
+        // adding 0.0 turns -0.0 into 0.0, so that equal priorities have equal bits
+        Ok(Self(priority + 0.0))
+    }
+
+    #[must_use]
+    pub const fn get(self) -> f32 {
+        self.0
+    }
+}
 
-        Ok(url)
+impl PartialEq for Priority {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
     }
 }
 
-#[derive(Debug, Clone)]
+impl Eq for Priority {}
+
+impl Hash for Priority {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl PartialOrd for Priority {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Priority {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Default for Priority {
+    fn default() -> Self {
+        Self(DEFAULT_PRIORITY)
+    }
+}
+
+impl TryFrom<f32> for Priority {
+    type Error = UrlError;
+
+    fn try_from(priority: f32) -> Result<Self, Self::Error> {
+        Self::new(priority)
+    }
+}
+
+impl Display for Priority {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Link {
     // Locale of the link
     // TODO: Use a predefined list of all possible locales?
@@ -213,6 +349,23 @@ impl Link {
     }
 }
 
+impl Extension for Link {
+    fn namespace(&self) -> Namespace {
+        Namespace::new("xhtml", XHTML_NAMESPACE)
+    }
+
+    fn write_xml(&self, writer: &mut XmlWriter<'_>) -> io::Result<()> {
+        writer.empty(
+            "xhtml:link",
+            &[
+                ("rel", "alternate"),
+                ("hreflang", &self.hreflang),
+                ("href", &self.href),
+            ],
+        )
+    }
+}
+
 /// How frequently the page is likely to change.
 ///
 /// This value provides general information to search engines and may not correlate exactly to how often they crawl the page.
@@ -223,7 +376,7 @@ impl Link {
 /// Please note that the value of this tag is considered a hint and not a command.
 /// Even though search engine crawlers may consider this information when making decisions, they may crawl pages marked "hourly" less frequently than that, and they may crawl pages marked "yearly" more frequently than that.
 /// Crawlers may periodically crawl pages marked "never" so that they can handle unexpected changes to those pages.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum ChangeFrequency {
     /// The value "always" should be used to describe documents that change each time they are accessed.
     Always,
@@ -256,3 +409,20 @@ impl Display for ChangeFrequency {
         write!(f, "{}", self.as_str())
     }
 }
+
+impl FromStr for ChangeFrequency {
+    type Err = ParseChangeFrequencyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "always" => Ok(Self::Always),
+            "hourly" => Ok(Self::Hourly),
+            "daily" => Ok(Self::Daily),
+            "weekly" => Ok(Self::Weekly),
+            "monthly" => Ok(Self::Monthly),
+            "yearly" => Ok(Self::Yearly),
+            "never" => Ok(Self::Never),
+            _ => Err(ParseChangeFrequencyError(s.to_string())),
+        }
+    }
+}
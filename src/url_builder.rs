@@ -1,9 +1,13 @@
+use crate::date::TryIntoDateTime;
+use crate::date_error::DateError;
+use crate::extension::Extension;
 use crate::image::Image;
 use crate::news::News;
 use crate::url::{ChangeFrequency, Link, Url};
 use crate::url_error::UrlError;
 use crate::video::Video;
 use chrono::{DateTime, FixedOffset};
+use std::sync::Arc;
 
 /// A \<url\> entry within a sitemap.xml.
 ///
@@ -56,6 +60,12 @@ pub struct UrlBuilder {
 
     /// News associated with this URL.
     pub news: Option<News>,
+
+    /// Other sitemap extensions associated with this URL, such as `ExtensionElement`s or your own `Extension`s, written after all other elements.
+    pub extensions: Vec<Arc<dyn Extension + Send + Sync>>,
+
+    /// The error from converting the date given to `last_modified`, returned by `build`.
+    last_modified_error: Option<DateError>,
 }
 
 impl UrlBuilder {
@@ -70,6 +80,8 @@ impl UrlBuilder {
             images: None,
             videos: None,
             news: None,
+            extensions: vec![],
+            last_modified_error: None,
         }
     }
 
@@ -78,8 +90,12 @@ impl UrlBuilder {
         self
     }
 
-    pub fn last_modified(&mut self, last_modified: DateTime<FixedOffset>) -> &mut Self {
-        self.last_modified = Some(last_modified);
+    /// Sets `last_modified`; if the date can't be converted, `build` returns the error.
+    pub fn last_modified(&mut self, last_modified: impl TryIntoDateTime) -> &mut Self {
+        let last_modified: Result<DateTime<FixedOffset>, DateError> =
+            last_modified.try_into_date_time();
+        self.last_modified_error = last_modified.as_ref().err().cloned();
+        self.last_modified = last_modified.ok();
         self
     }
 
@@ -108,13 +124,38 @@ impl UrlBuilder {
         self
     }
 
+    pub fn extensions(
+        &mut self,
+        extensions: impl IntoIterator<Item = impl Extension + Send + Sync + 'static>,
+    ) -> &mut Self {
+        self.extensions = extensions
+            .into_iter()
+            .map(|extension| Arc::new(extension) as Arc<dyn Extension + Send + Sync>)
+            .collect();
+        self
+    }
+
+    /// Adds `extension` after the extensions already set, so that extensions of different types can be combined.
+    pub fn extension(&mut self, extension: impl Extension + Send + Sync + 'static) -> &mut Self {
+        self.extensions.push(Arc::new(extension));
+        self
+    }
+
     /// # Errors
     ///
     /// Will return `UrlError::PriorityTooLow` if `priority` is below `0.0`.
     /// Will return `UrlError::PriorityTooHigh` if `priority` is above `1.0`.
     /// Will return `UrlError::TooManyImages` if the length of `images` is above `1,000`.
+    /// Will return `UrlError::Date` if the date given to `last_modified` couldn't be converted.
     pub fn build(&self) -> Result<Url, UrlError> {
-        Url::new(
+        if let Some(error) = &self.last_modified_error {
+            return Err(UrlError::Date {
+                field: "last_modified",
+                error: error.clone(),
+            });
+        }
+
+        let mut url: Url = Url::new(
             self.location.clone(),
             self.links.clone(),
             self.last_modified,
@@ -123,6 +164,8 @@ impl UrlBuilder {
             self.images.clone(),
             self.videos.clone(),
             self.news.clone(),
-        )
+        )?;
+        url.extensions.clone_from(&self.extensions);
+        Ok(url)
     }
 }
@@ -1,3 +1,4 @@
+use crate::date_error::DateError;
 use std::error;
 use std::fmt::{Display, Formatter};
 
@@ -5,7 +6,13 @@ use std::fmt::{Display, Formatter};
 #[derive(Debug)]
 pub enum UrlError {
     /// Returned when a sitemap URL entry's `loc` is 2,048 characters or more.
-    LocationTooLong(String),
+    ///
+    /// Reports both the Unicode character count (which is what the limit applies to) and the byte count.
+    LocationTooLong {
+        location: String,
+        characters: usize,
+        bytes: usize,
+    },
 
     /// Returned when a sitemap URL entry's `priority` is below 0.
     PriorityTooLow(f32),
@@ -13,8 +20,32 @@ pub enum UrlError {
     /// Returned when a sitemap URL entry's `priority` is above 1.
     PriorityTooHigh(f32),
 
+    /// Returned when a sitemap URL entry's `priority` is NaN.
+    PriorityNotANumber,
+
     /// Returned when a sitemap URL entry's `images` is more than 1,000.
     TooManyImages(usize),
+
+    /// Returned when a date given to a `UrlBuilder`, such as `last_modified`, can't be converted to a sitemap date.
+    Date {
+        field: &'static str,
+        error: DateError,
+    },
+}
+
+impl UrlError {
+    /// The name of the `Url` field this error is about.
+    #[must_use]
+    pub const fn field(&self) -> &'static str {
+        match self {
+            Self::LocationTooLong { .. } => "location",
+            Self::PriorityTooLow(_) | Self::PriorityTooHigh(_) | Self::PriorityNotANumber => {
+                "priority"
+            }
+            Self::TooManyImages(_) => "images",
+            Self::Date { field, .. } => field,
+        }
+    }
 }
 
 impl error::Error for UrlError {}
@@ -22,8 +53,15 @@ impl error::Error for UrlError {}
 impl Display for UrlError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::LocationTooLong(location) => {
-                write!(f, "location must be less than 2,048 characters: {location}")
+            Self::LocationTooLong {
+                location,
+                characters,
+                bytes,
+            } => {
+                write!(
+                    f,
+                    "location must be less than 2,048 characters: {characters} characters ({bytes} bytes): {location}"
+                )
             }
             Self::PriorityTooLow(priority) => {
                 write!(f, "priority must not be below 0.0: {priority}")
@@ -31,9 +69,27 @@ impl Display for UrlError {
             Self::PriorityTooHigh(priority) => {
                 write!(f, "priority must not be above 1.0: {priority}")
             }
+            Self::PriorityNotANumber => write!(f, "priority must be a number, not NaN"),
             Self::TooManyImages(count) => {
                 write!(f, "must not contain more tha 1,000 images: {count}")
             }
+            Self::Date { field, error } => write!(f, "{field} is not a valid date: {error}"),
         }
     }
 }
+
+/// An error when parsing a `ChangeFrequency` from text that isn't one of its values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseChangeFrequencyError(pub String);
+
+impl error::Error for ParseChangeFrequencyError {}
+
+impl Display for ParseChangeFrequencyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "change frequency must be one of always, hourly, daily, weekly, monthly, yearly, or never: {}",
+            self.0
+        )
+    }
+}
@@ -1,33 +1,39 @@
+use crate::Error;
+use crate::extension::Namespace;
+use crate::rejected::Rejected;
 use crate::url::Url;
+use crate::url_set_builder::UrlSetBuilder;
 use crate::url_set_error::UrlSetError;
+use crate::validation_error::ValidationError;
+use crate::write_options::WriteOptions;
+use crate::xml_version::XmlVersion;
+use crate::xml_writer::XmlWriter;
 use crate::{
     ENCODING, IMAGE_NAMESPACE, NAMESPACE, NEWS_NAMESPACE, VIDEO_NAMESPACE, XHTML_NAMESPACE,
 };
+use chrono::{DateTime, FixedOffset};
+use std::cmp::Ordering;
 use std::io::Write;
-use xml_builder::{XML, XMLBuilder, XMLElement, XMLError, XMLVersion};
 
 /// Encapsulates the file and references the current protocol standard.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UrlSet {
     /// The XML version.
-    pub xml_version: XMLVersion,
+    pub xml_version: XmlVersion,
 
     /// The XML encoding.
     pub xml_encoding: String,
 
+    /// The location of an XSL stylesheet that browsers use to render the sitemap, if any.
+    ///
+    /// See the `stylesheet` module for default stylesheets.
+    pub stylesheet: Option<String>,
+
     /// The namespace for the \<urlset\>.
     pub xmlns: String,
 
-    /// A namespace extension for allowing \<xhtml:link\> (alternate language links) in the `UrlSet`.
-    pub xmlns_xhtml: Option<String>,
-
-    /// A namespace extension for allowing \<image\> in the `UrlSet`.
-    pub xmlns_image: Option<String>,
-
-    /// A namespace extension for allowing \<video\> in the `UrlSet`.
-    pub xmlns_video: Option<String>,
-
-    /// A namespace extension for allowing \<news\> in the `UrlSet`.
-    pub xmlns_news: Option<String>,
+    /// The namespaces of every extension used within the `UrlSet`, such as \<xhtml:link\> (alternate language links), \<image\>, \<video\>, and \<news\>.
+    pub namespaces: Vec<Namespace>,
 
     /// All the URLs that will become indexed.
     pub urls: Vec<Url>,
@@ -37,114 +43,344 @@ impl UrlSet {
     /// # Errors
     ///
     /// Will return `UrlSetError::TooManyUrls` if the length of `urls` is above `50,000`.
+    /// Will return `UrlSetError::TooMuchNews` if any of `urls` contain news and the length of `urls` is above `1,000`.
+    /// Will return `UrlSetError::NamespaceConflict` if two extensions within `urls` use the same namespace prefix for different namespace URIs.
     pub fn new(urls: Vec<Url>) -> Result<Self, UrlSetError> {
-        // UrlSets cannot contain more than 50,000 URLs
-        if urls.len() > 50_000 {
-            return Err(UrlSetError::TooManyUrls(urls.len()));
-        }
-
-        // check if we even need namespaces for alternative language links, images, videos, or news
-        let mut xmlns_xhtml: Option<String> = None;
-        let mut xmlns_image: Option<String> = None;
-        let mut xmlns_video: Option<String> = None;
-        let mut xmlns_news: Option<String> = None;
-        let mut news_exists: bool = false;
-        for url in &urls {
-            // if any <url>s exist that contain alternate language links, set the xhtml namespace
-            if !url.links.is_empty() {
-                xmlns_xhtml = Some(XHTML_NAMESPACE.to_string());
-            }
+        if let Some(error) = Self::validate_limits(&urls).into_iter().next() {
+            return Err(error);
+        }
 
-            // if any <url>s exist that contain an image, set the image namespace
-            if let Some(images) = &url.images
-                && !images.is_empty()
-            {
-                xmlns_image = Some(IMAGE_NAMESPACE.to_string());
-            }
+        // only declare the namespaces of extensions that are actually used
+        let namespaces: Vec<Namespace> = Self::collect_namespaces(&urls)?;
 
-            // if any <url>s exist that contain a video, set the video namespace
-            if let Some(videos) = &url.videos
-                && !videos.is_empty()
-            {
-                xmlns_video = Some(VIDEO_NAMESPACE.to_string());
+        Ok(Self {
+            xml_version: XmlVersion::Xml1_0,
+            xml_encoding: ENCODING.to_string(),
+            stylesheet: None,
+            xmlns: NAMESPACE.to_string(),
+            namespaces,
+            urls,
+        })
+    }
+
+    #[must_use]
+    pub fn builder() -> UrlSetBuilder {
+        UrlSetBuilder::new()
+    }
+
+    /// Sorts the URLs by `order`, so that the same URLs are always written in the same order.
+    ///
+    /// The sort is stable, so URLs that compare equal (such as duplicate locations) keep their relative order.
+    pub fn sort(&mut self, order: UrlOrder) {
+        self.urls.sort_by(|first, second| {
+            order.compare(
+                (&first.location, first.last_modified),
+                (&second.location, second.last_modified),
+            )
+        });
+    }
+
+    /// Creates a `UrlSet`, checking every rule for every entry instead of stopping at the first violation.
+    ///
+    /// # Errors
+    ///
+    /// Will return every `ValidationError` found by `UrlSet::validate()`, if there are any.
+    pub fn new_validated(urls: Vec<Url>) -> Result<Self, Vec<ValidationError>> {
+        let errors: Vec<ValidationError> = Self::validate(&urls);
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Self::new(urls).map_err(|error| vec![ValidationError::url_set(error)])
+    }
+
+    /// Creates a `UrlSet` from only the valid entries of `urls`, instead of rejecting the whole set.
+    ///
+    /// Entries that break any rule (including rules for their videos) are left out and returned alongside the `UrlSet`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `UrlSetError::TooManyUrls` if the number of valid `urls` is above `50,000`.
+    /// Will return `UrlSetError::TooMuchNews` if any of the valid `urls` contain news and the number of valid `urls` is above `1,000`.
+    /// Will return `UrlSetError::NamespaceConflict` if two extensions within the valid `urls` use the same namespace prefix for different namespace URIs.
+    pub fn new_lenient(
+        urls: Vec<Url>,
+    ) -> Result<(Self, Vec<Rejected<Url, ValidationError>>), UrlSetError> {
+        let mut valid: Vec<Url> = Vec::with_capacity(urls.len());
+        let mut rejected: Vec<Rejected<Url, ValidationError>> = vec![];
+
+        for (index, url) in urls.into_iter().enumerate() {
+            let errors: Vec<ValidationError> = Self::validate_url(index, &url);
+
+            if errors.is_empty() {
+                valid.push(url);
+            } else {
+                rejected.push(Rejected::new(index, url, errors));
             }
+        }
 
-            // check if any URLs have news
-            if url.news.is_some() {
-                news_exists = true;
-                if xmlns_news.is_none() {
-                    xmlns_news = Some(NEWS_NAMESPACE.to_string());
-                }
+        Ok((Self::new(valid)?, rejected))
+    }
+
+    /// Checks every rule for every entry, and for the set as a whole, and returns all violations.
+    ///
+    /// Each `ValidationError` records the index of the offending entry and the path to the offending field (such as `urls[17].videos[2].description`).
+    /// This makes it possible to fix all problems in one pass, or to drop only the invalid entries.
+    #[must_use]
+    pub fn validate(urls: &[Url]) -> Vec<ValidationError> {
+        let mut errors: Vec<ValidationError> = vec![];
+
+        for (index, url) in urls.iter().enumerate() {
+            errors.extend(Self::validate_url(index, url));
+        }
+
+        errors.extend(
+            Self::validate_limits(urls)
+                .into_iter()
+                .map(ValidationError::url_set),
+        );
+
+        errors
+    }
+
+    pub(crate) fn validate_url(index: usize, url: &Url) -> Vec<ValidationError> {
+        let mut errors: Vec<ValidationError> = url
+            .validate()
+            .into_iter()
+            .map(|error| ValidationError::url(index, error))
+            .collect();
+
+        if let Some(videos) = &url.videos {
+            for (video_index, video) in videos.iter().enumerate() {
+                errors.extend(
+                    video
+                        .validate()
+                        .into_iter()
+                        .map(|error| ValidationError::video(index, video_index, error)),
+                );
             }
         }
 
+        errors
+    }
+
+    fn validate_limits(urls: &[Url]) -> Vec<UrlSetError> {
+        let mut errors: Vec<UrlSetError> = vec![];
+
+        // UrlSets cannot contain more than 50,000 URLs
+        if urls.len() > 50_000 {
+            errors.push(UrlSetError::TooManyUrls(urls.len()));
+        }
+
         // cannot have more than 1,000 news URLs in a single UrlSet
-        if news_exists && urls.len() > 1000 {
-            return Err(UrlSetError::TooMuchNews(urls.len()));
+        if urls.len() > 1000 && urls.iter().any(|url| url.news.is_some()) {
+            errors.push(UrlSetError::TooMuchNews(urls.len()));
         }
 
-        Ok(Self {
-            xml_version: XMLVersion::XML1_0,
-            xml_encoding: ENCODING.to_string(),
-            xmlns: NAMESPACE.to_string(),
-            xmlns_xhtml,
-            xmlns_image,
-            xmlns_video,
-            xmlns_news,
-            urls,
-        })
+        // a namespace prefix can only be bound to a single namespace URI
+        if let Err(error) = Self::collect_namespaces(urls) {
+            errors.push(error);
+        }
+
+        errors
     }
 
-    /// # Errors
+    /// Collects the namespaces of every extension within `urls`.
     ///
-    /// Will return `XMLError` if there is a problem creating XML elements.
-    pub fn to_xml(self) -> Result<XML, XMLError> {
-        // create XML document
-        let mut xml = XMLBuilder::new()
-            .version(self.xml_version)
-            .encoding(self.xml_encoding)
-            .build();
+    /// The built-in extensions come first (xhtml, image, video, then news), followed by all other namespaces in order of first use.
+    fn collect_namespaces(urls: &[Url]) -> Result<Vec<Namespace>, UrlSetError> {
+        let mut namespaces: Vec<Namespace> = vec![];
+        for url in urls {
+            let added: Vec<Namespace> = Self::new_namespaces(&namespaces, url)?;
+            namespaces.extend(added);
+        }
+
+        Self::sort_namespaces(&mut namespaces);
+        Ok(namespaces)
+    }
+
+    /// Returns the namespaces of the extensions within `url` that aren't in `namespaces` yet.
+    pub(crate) fn new_namespaces(
+        namespaces: &[Namespace],
+        url: &Url,
+    ) -> Result<Vec<Namespace>, UrlSetError> {
+        let mut added: Vec<Namespace> = vec![];
 
-        // create <urlset>
-        let mut urlset = XMLElement::new("urlset");
-        urlset.add_attribute("xmlns", self.xmlns.as_str());
+        for extension in url.extensions() {
+            let namespace: Namespace = extension.namespace();
 
-        // set xhtml namespace, if it exists
-        if let Some(xmlns_xhtml) = self.xmlns_xhtml {
-            urlset.add_attribute("xmlns:xhtml", xmlns_xhtml.as_str());
+            match namespaces
+                .iter()
+                .chain(&added)
+                .find(|existing| existing.prefix == namespace.prefix)
+            {
+                Some(existing) if existing.uri == namespace.uri => {}
+                Some(existing) => {
+                    return Err(UrlSetError::NamespaceConflict {
+                        prefix: namespace.prefix.into_owned(),
+                        uri: existing.uri.to_string(),
+                        other_uri: namespace.uri.into_owned(),
+                    });
+                }
+                None => added.push(namespace),
+            }
         }
 
-        // set image namespace, if it exists
-        if let Some(xmlns_image) = self.xmlns_image {
-            urlset.add_attribute("xmlns:image", xmlns_image.as_str());
+        Ok(added)
+    }
+
+    /// Puts the built-in namespaces first, so they're always declared in the same order.
+    pub(crate) fn sort_namespaces(namespaces: &mut [Namespace]) {
+        // `sort_by_key` is stable, so other namespaces keep their order of first use
+        namespaces.sort_by_key(built_in_position);
+    }
+
+    /// Writes the sitemap using the default `WriteOptions`: pretty-printed with tabs.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::Io` if there is an IO Error dealing with the
+    /// underlying writer.
+    pub fn write<W: Write>(&self, writer: W) -> Result<(), Error> {
+        self.write_with_options(writer, &WriteOptions::default())
+    }
+
+    /// Writes the sitemap laid out according to `options`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::Io` if there is an IO Error dealing with the
+    /// underlying writer.
+    pub fn write_with_options<W: Write>(
+        &self,
+        mut writer: W,
+        options: &WriteOptions,
+    ) -> Result<(), Error> {
+        let mut writer: XmlWriter<'_> = XmlWriter::new(&mut writer, options);
+
+        // write XML declaration
+        writer.declaration(self.xml_version, &self.xml_encoding)?;
+
+        // write XSL stylesheet processing instruction, if it exists
+        if let Some(stylesheet) = &self.stylesheet {
+            writer.stylesheet(stylesheet)?;
         }
 
-        // set video namespace, if it exists
-        if let Some(xmlns_video) = self.xmlns_video {
-            urlset.add_attribute("xmlns:video", xmlns_video.as_str());
+        // set namespaces for <urlset>
+        let mut attributes: Vec<(&str, &str)> = vec![("xmlns", self.xmlns.as_str())];
+
+        // set the namespace of each extension; when sorting, other namespaces are declared by prefix instead of by first use
+        let mut namespaces: Vec<&Namespace> = self.namespaces.iter().collect();
+        if options.order.is_some() {
+            namespaces.sort_by(|first, second| {
+                (built_in_position(first), &first.prefix)
+                    .cmp(&(built_in_position(second), &second.prefix))
+            });
+        }
+        let namespace_attributes: Vec<(String, &str)> = namespaces
+            .iter()
+            .map(|namespace| (namespace.attribute_name(), namespace.uri.as_ref()))
+            .collect();
+        for (name, uri) in &namespace_attributes {
+            attributes.push((name.as_str(), uri));
         }
 
-        // set news namespace, if it exists
-        if let Some(xmlns_news) = self.xmlns_news {
-            urlset.add_attribute("xmlns:news", xmlns_news.as_str());
+        // an empty <urlset> is self-closing
+        if self.urls.is_empty() {
+            writer.empty("urlset", &attributes)?;
+            return Ok(());
         }
 
         // add each <url>
-        for url in self.urls {
-            urlset.add_child(url.to_xml()?)?;
+        writer.start("urlset", &attributes)?;
+        let mut urls: Vec<&Url> = self.urls.iter().collect();
+        if let Some(order) = options.order {
+            urls.sort_by(|first, second| {
+                order.compare(
+                    (&first.location, first.last_modified),
+                    (&second.location, second.last_modified),
+                )
+            });
         }
+        for url in urls {
+            url.write_xml(&mut writer)?;
+        }
+        writer.end("urlset")?;
 
-        // set root element and we're done!
-        xml.set_root_element(urlset);
-        Ok(xml)
+        Ok(())
     }
 
+    /// Writes the sitemap gzip-compressed, laid out according to `options`.
+    ///
     /// # Errors
     ///
-    /// Will return `XMLError` if there is an IO Error dealing with the
-    /// underlying writer or if there is an error generating XML.
-    pub fn write<W: Write>(self, writer: W) -> Result<(), XMLError> {
-        let xml: XML = self.to_xml()?;
-        xml.generate(writer)
+    /// Will return `Error::Io` if there is an IO Error dealing with the
+    /// underlying writer.
+    #[cfg(feature = "gzip")]
+    pub fn write_gzip<W: Write>(&self, writer: W, options: &WriteOptions) -> Result<(), Error> {
+        let mut encoder: flate2::write::GzEncoder<W> =
+            flate2::write::GzEncoder::new(writer, flate2::Compression::default());
+        self.write_with_options(&mut encoder, options)?;
+        encoder.finish()?;
+        Ok(())
+    }
+}
+
+/// The order of built-in namespaces, with all other namespaces after them.
+fn built_in_position(namespace: &Namespace) -> usize {
+    [
+        XHTML_NAMESPACE,
+        IMAGE_NAMESPACE,
+        VIDEO_NAMESPACE,
+        NEWS_NAMESPACE,
+    ]
+    .iter()
+    .position(|uri| namespace.uri == *uri)
+    .unwrap_or(usize::MAX)
+}
+
+/// The order to sort the URLs of a `UrlSet` in, with `UrlSet::sort()`.
+///
+/// Also sorts the entries of a `UrlSet` or `SitemapIndex` as they are written, with `WriteOptions::order`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum UrlOrder {
+    /// Alphabetically by \<loc\>.
+    Location,
+
+    /// Oldest \<lastmod\> first, and then alphabetically by \<loc\>.
+    ///
+    /// URLs without a \<lastmod\> come last.
+    LastModified,
+
+    /// Newest \<lastmod\> first, and then alphabetically by \<loc\>.
+    ///
+    /// URLs without a \<lastmod\> come last.
+    LastModifiedDescending,
+}
+
+impl UrlOrder {
+    /// Compares two entries by their location and last modification date.
+    pub(crate) fn compare(
+        self,
+        (first_location, first_last_modified): (&str, Option<DateTime<FixedOffset>>),
+        (second_location, second_last_modified): (&str, Option<DateTime<FixedOffset>>),
+    ) -> Ordering {
+        match self {
+            Self::Location => first_location.cmp(second_location),
+            // entries without a last modification date come last
+            Self::LastModified => (
+                first_last_modified.is_none(),
+                first_last_modified,
+                first_location,
+            )
+                .cmp(&(
+                    second_last_modified.is_none(),
+                    second_last_modified,
+                    second_location,
+                )),
+            Self::LastModifiedDescending => {
+                (second_last_modified, first_location).cmp(&(first_last_modified, second_location))
+            }
+        }
     }
 }
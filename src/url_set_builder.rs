@@ -0,0 +1,156 @@
+use crate::extension::Namespace;
+use crate::rejected::Rejected;
+use crate::url::Url;
+use crate::url_set::UrlSet;
+use crate::url_set_error::UrlSetError;
+use crate::validation_error::ValidationError;
+use crate::xml_version::XmlVersion;
+use crate::{ENCODING, NAMESPACE};
+
+/// Encapsulates the file and references the current protocol standard.
+///
+/// This is a Builder for `UrlSet`.
+/// Every \<url\> is validated as it is pushed, so building never fails.
+#[derive(Debug, Clone)]
+pub struct UrlSetBuilder {
+    xml_version: XmlVersion,
+    xml_encoding: String,
+    stylesheet: Option<String>,
+    xmlns: String,
+    namespaces: Vec<Namespace>,
+    namespace_overrides: Vec<Namespace>,
+    urls: Vec<Url>,
+    has_news: bool,
+}
+
+impl UrlSetBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            xml_version: XmlVersion::Xml1_0,
+            xml_encoding: ENCODING.to_string(),
+            stylesheet: None,
+            xmlns: NAMESPACE.to_string(),
+            namespaces: vec![],
+            namespace_overrides: vec![],
+            urls: vec![],
+            has_news: false,
+        }
+    }
+
+    pub const fn xml_version(&mut self, xml_version: XmlVersion) -> &mut Self {
+        self.xml_version = xml_version;
+        self
+    }
+
+    pub fn xml_encoding(&mut self, xml_encoding: String) -> &mut Self {
+        self.xml_encoding = xml_encoding;
+        self
+    }
+
+    pub fn stylesheet(&mut self, stylesheet: String) -> &mut Self {
+        self.stylesheet = Some(stylesheet);
+        self
+    }
+
+    pub fn xmlns(&mut self, xmlns: String) -> &mut Self {
+        self.xmlns = xmlns;
+        self
+    }
+
+    /// Declares `namespace` on the \<urlset\>, replacing any namespace with the same prefix.
+    ///
+    /// This can declare namespaces that no extension uses, or change the URI of a built-in one.
+    pub fn namespace(&mut self, namespace: Namespace) -> &mut Self {
+        self.namespace_overrides
+            .retain(|existing| existing.prefix != namespace.prefix);
+        self.namespace_overrides.push(namespace);
+        self
+    }
+
+    /// Adds `url` to the end of the set, if it and the set would still be valid.
+    ///
+    /// # Errors
+    ///
+    /// Will return the rejected `url` along with every rule it broke, the same as `UrlSet::validate()` would report them.
+    /// This includes `UrlSetError::TooManyUrls` and `UrlSetError::TooMuchNews` if adding `url` would break the set's limits,
+    /// and `UrlSetError::NamespaceConflict` if one of its extensions uses a namespace prefix already bound to a different URI.
+    pub fn push(&mut self, url: Url) -> Result<&mut Self, Box<Rejected<Url, ValidationError>>> {
+        let index: usize = self.urls.len();
+        let count: usize = index + 1;
+        let has_news: bool = self.has_news || url.news.is_some();
+
+        let mut errors: Vec<ValidationError> = UrlSet::validate_url(index, &url);
+
+        // UrlSets cannot contain more than 50,000 URLs
+        if count > 50_000 {
+            errors.push(ValidationError::url_set(UrlSetError::TooManyUrls(count)));
+        }
+
+        // cannot have more than 1,000 news URLs in a single UrlSet
+        if has_news && count > 1000 {
+            errors.push(ValidationError::url_set(UrlSetError::TooMuchNews(count)));
+        }
+
+        // a namespace prefix can only be bound to a single namespace URI
+        let namespaces: Vec<Namespace> = match UrlSet::new_namespaces(&self.namespaces, &url) {
+            Ok(namespaces) => namespaces,
+            Err(error) => {
+                errors.push(ValidationError::url_set(error));
+                vec![]
+            }
+        };
+
+        if !errors.is_empty() {
+            return Err(Box::new(Rejected::new(index, url, errors)));
+        }
+
+        self.namespaces.extend(namespaces);
+        self.urls.push(url);
+        self.has_news = has_news;
+        Ok(self)
+    }
+
+    /// The number of \<url\> pushed so far.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.urls.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.urls.is_empty()
+    }
+
+    #[must_use]
+    pub fn build(&self) -> UrlSet {
+        let mut namespaces: Vec<Namespace> = self.namespaces.clone();
+        UrlSet::sort_namespaces(&mut namespaces);
+
+        // overrides replace collected namespaces in place, or are declared after them
+        for namespace in &self.namespace_overrides {
+            match namespaces
+                .iter_mut()
+                .find(|existing| existing.prefix == namespace.prefix)
+            {
+                Some(existing) => existing.clone_from(namespace),
+                None => namespaces.push(namespace.clone()),
+            }
+        }
+
+        UrlSet {
+            xml_version: self.xml_version,
+            xml_encoding: self.xml_encoding.clone(),
+            stylesheet: self.stylesheet.clone(),
+            xmlns: self.xmlns.clone(),
+            namespaces,
+            urls: self.urls.clone(),
+        }
+    }
+}
+
+impl Default for UrlSetBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
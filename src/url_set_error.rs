@@ -9,6 +9,23 @@ pub enum UrlSetError {
 
     /// Returned when a \<urlset\> contains more than `1,000` \<news\>.
     TooMuchNews(usize),
+
+    /// Returned when two extensions within a \<urlset\> use the same namespace prefix for different namespace URIs.
+    NamespaceConflict {
+        prefix: String,
+        uri: String,
+        other_uri: String,
+    },
+}
+
+impl UrlSetError {
+    /// The name of the `UrlSet` field this error is about.
+    #[must_use]
+    pub const fn field(&self) -> &'static str {
+        match self {
+            Self::TooManyUrls(_) | Self::TooMuchNews(_) | Self::NamespaceConflict { .. } => "urls",
+        }
+    }
 }
 
 impl Error for UrlSetError {}
@@ -22,6 +39,16 @@ impl Display for UrlSetError {
             Self::TooMuchNews(count) => {
                 write!(f, "must not contains more than 1,000 news URLs: {count}")
             }
+            Self::NamespaceConflict {
+                prefix,
+                uri,
+                other_uri,
+            } => {
+                write!(
+                    f,
+                    "namespace prefix '{prefix}' must not be used for both '{uri}' and '{other_uri}'"
+                )
+            }
         }
     }
 }
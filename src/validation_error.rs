@@ -0,0 +1,88 @@
+use crate::url_error::UrlError;
+use crate::url_set_error::UrlSetError;
+use crate::video_error::VideoError;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// A single rule violation found while validating a whole `UrlSet`.
+#[derive(Debug)]
+pub struct ValidationError {
+    /// The index of the offending entry within `urls`, if the error belongs to a single entry.
+    ///
+    /// This is `None` for errors about the set as a whole, such as `UrlSetError::TooManyUrls`.
+    pub index: Option<usize>,
+
+    /// The path to the offending field, such as `urls[17].videos[2].description`.
+    pub path: String,
+
+    /// What went wrong.
+    pub kind: ValidationErrorKind,
+}
+
+impl ValidationError {
+    #[must_use]
+    pub fn url(index: usize, error: UrlError) -> Self {
+        Self {
+            index: Some(index),
+            path: format!("urls[{index}].{}", error.field()),
+            kind: ValidationErrorKind::Url(error),
+        }
+    }
+
+    #[must_use]
+    pub fn video(index: usize, video_index: usize, error: VideoError) -> Self {
+        Self {
+            index: Some(index),
+            path: format!("urls[{index}].videos[{video_index}].{}", error.field()),
+            kind: ValidationErrorKind::Video(error),
+        }
+    }
+
+    #[must_use]
+    pub fn url_set(error: UrlSetError) -> Self {
+        Self {
+            index: None,
+            path: error.field().to_string(),
+            kind: ValidationErrorKind::UrlSet(error),
+        }
+    }
+}
+
+impl Error for ValidationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            ValidationErrorKind::Url(error) => Some(error),
+            ValidationErrorKind::Video(error) => Some(error),
+            ValidationErrorKind::UrlSet(error) => Some(error),
+        }
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.kind)
+    }
+}
+
+/// The underlying error of a `ValidationError`.
+#[derive(Debug)]
+pub enum ValidationErrorKind {
+    /// A \<url\> entry broke a rule.
+    Url(UrlError),
+
+    /// A \<video:video\> within a \<url\> entry broke a rule.
+    Video(VideoError),
+
+    /// The \<urlset\> as a whole broke a rule.
+    UrlSet(UrlSetError),
+}
+
+impl Display for ValidationErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Url(error) => write!(f, "{error}"),
+            Self::Video(error) => write!(f, "{error}"),
+            Self::UrlSet(error) => write!(f, "{error}"),
+        }
+    }
+}
@@ -1,10 +1,20 @@
+use crate::VIDEO_NAMESPACE;
+use crate::extension::{Extension, Namespace};
 use crate::video_builder::VideoBuilder;
 use crate::video_error::VideoError;
-use crate::{RFC_3339_SECONDS_FORMAT, RFC_3339_USE_Z};
+use crate::xml_writer::XmlWriter;
 use chrono::{DateTime, FixedOffset};
+use std::cmp::Ordering;
 use std::collections::BTreeSet;
 use std::fmt::{Display, Formatter};
-use xml_builder::{XMLElement, XMLError};
+use std::hash::{Hash, Hasher};
+use std::io;
+
+/// The maximum number of characters in a video's `description`.
+pub const MAX_DESCRIPTION_CHARACTERS: usize = 2048;
+
+/// The maximum number of characters in a video uploader's `name`.
+pub const MAX_UPLOADER_NAME_CHARACTERS: usize = 255;
 
 /// A sitemap video.
 ///
@@ -12,7 +22,7 @@ use xml_builder::{XMLElement, XMLError};
 /// We recommend that your provide the <video:content_loc> tag, if possible.
 /// This is the most effective way for Google to fetch your video content files.
 /// If <video:content_loc> isn't available, provide <video:player_loc> as an alternative.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Video {
     /// A URL pointing to the video thumbnail image file.
     pub thumbnail_location: String,
@@ -50,7 +60,7 @@ pub struct Video {
     /// The duration of the video, in seconds.
     ///
     /// Value must be from 1 to 28800 (8 hours) inclusive.
-    pub duration: Option<u16>,
+    pub duration: Option<VideoDuration>,
 
     /// The date after which the video is no longer be available, in W3C format.
     ///
@@ -62,7 +72,7 @@ pub struct Video {
     /// The rating of the video.
     ///
     /// Supported values are float numbers in the range 0.0 (low) to 5.0 (high), inclusive.
-    pub rating: Option<f32>,
+    pub rating: Option<VideoRating>,
 
     /// The number of times the video has been viewed.
     pub view_count: Option<usize>,
@@ -110,7 +120,7 @@ pub struct Video {
     /// For example, a video about grilling food may belong in the "grilling" category, but could be tagged "steak", "meat", "summer", and "outdoor".
     /// Create a new <video:tag> element for each tag associated with a video.
     /// A maximum of 32 tags is permitted.
-    pub tags: Option<Vec<String>>,
+    pub tags: Option<TagList>,
 }
 
 impl Video {
@@ -121,6 +131,7 @@ impl Video {
     /// Will return `VideoError::DurationTooLong` if `duration` is above `28,800` seconds (`8` hours).
     /// Will return `VideoError::RatingTooLow` if `rating` is below `0.0`.
     /// Will return `VideoError::RatingTooHigh` if `rating` is above `5.0`.
+    /// Will return `VideoError::RatingNotANumber` if `rating` is NaN.
     /// Will return `VideoError::UploaderNameTooLong` if `uploader` `name` is longer than `255` characters.
     /// Will return `VideoError::TooManyTags` if there are more than `32` `tags`.
     #[expect(clippy::too_many_arguments)]
@@ -143,49 +154,11 @@ impl Video {
         live: Option<bool>,
         tags: Option<Vec<String>>,
     ) -> Result<Self, VideoError> {
-        // description must be no longer than `2048` characters
-        if description.len() > 2048 {
-            return Err(VideoError::DescriptionTooLong(description.len()));
-        }
-
-        if let Some(duration) = duration {
-            // duration should be at least `1` second
-            if duration < 1 {
-                return Err(VideoError::DurationTooShort(duration));
-            }
-            // duration should be no longer than `28,800` seconds (8 hours)
-            if duration > 28800 {
-                return Err(VideoError::DurationTooLong(duration));
-            }
-        }
-
-        if let Some(rating) = rating {
-            // rating should be no lower than `0.0`
-            if rating < 0.0 {
-                return Err(VideoError::RatingTooLow(rating));
-            }
+        let duration: Option<VideoDuration> = duration.map(VideoDuration::new).transpose()?;
+        let rating: Option<VideoRating> = rating.map(VideoRating::new).transpose()?;
+        let tags: Option<TagList> = tags.map(TagList::new).transpose()?;
 
-            // rating should be no higher than `5.0`
-            if rating > 5.0 {
-                return Err(VideoError::RatingTooHigh(rating));
-            }
-        }
-
-        if let Some(uploader) = &uploader {
-            // uploader name should be no longer than `255` characters
-            if uploader.name.len() > 255 {
-                return Err(VideoError::UploaderNameTooLong(uploader.name.len()));
-            }
-        }
-
-        if let Some(tags) = &tags {
-            // there should not be more than `32` tags
-            if tags.len() > 32 {
-                return Err(VideoError::TooManyTags(tags.len()));
-            }
-        }
-
-        Ok(Self {
+        let video: Self = Self {
             thumbnail_location,
             title,
             description,
@@ -203,7 +176,42 @@ impl Video {
             uploader,
             live,
             tags,
-        })
+        };
+
+        match video.validate().into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(video),
+        }
+    }
+
+    /// Checks every rule that applies to this video and returns all violations, instead of only the first one.
+    ///
+    /// A `VideoDuration`, `VideoRating`, or `TagList` is always valid, so they aren't checked.
+    #[must_use]
+    pub fn validate(&self) -> Vec<VideoError> {
+        let mut errors: Vec<VideoError> = vec![];
+
+        // description must be no longer than `2048` characters
+        let characters: usize = self.description.chars().count();
+        if characters > MAX_DESCRIPTION_CHARACTERS {
+            errors.push(VideoError::DescriptionTooLong {
+                characters,
+                bytes: self.description.len(),
+            });
+        }
+
+        if let Some(uploader) = &self.uploader {
+            // uploader name should be no longer than `255` characters
+            let characters: usize = uploader.name.chars().count();
+            if characters > MAX_UPLOADER_NAME_CHARACTERS {
+                errors.push(VideoError::UploaderNameTooLong {
+                    characters,
+                    bytes: uploader.name.len(),
+                });
+            }
+        }
+
+        errors
     }
 
     #[must_use]
@@ -222,132 +230,271 @@ impl Video {
             player_location,
         )
     }
+}
 
-    /// # Errors
-    ///
-    /// Will return `XMLError` if there is a problem creating XML elements.
-    pub fn to_xml(self) -> Result<XMLElement, XMLError> {
-        let mut video: XMLElement = XMLElement::new("video:video");
+impl Extension for Video {
+    fn namespace(&self) -> Namespace {
+        Namespace::new("video", VIDEO_NAMESPACE)
+    }
+
+    fn write_xml(&self, writer: &mut XmlWriter<'_>) -> io::Result<()> {
+        writer.start("video:video", &[])?;
 
         // add <video:thumbnail_loc>
-        let mut thumbnail_loc: XMLElement = XMLElement::new("video:thumbnail_loc");
-        thumbnail_loc.add_text(self.thumbnail_location)?;
-        video.add_child(thumbnail_loc)?;
+        writer.text("video:thumbnail_loc", &[], &self.thumbnail_location)?;
 
         // add <video:title>
-        let mut title: XMLElement = XMLElement::new("video:title");
-        title.add_text(self.title)?;
-        video.add_child(title)?;
+        writer.text("video:title", &[], &self.title)?;
 
         // add <video:description>
-        let mut description: XMLElement = XMLElement::new("video:description");
-        description.add_text(self.description)?;
-        video.add_child(description)?;
+        writer.text("video:description", &[], &self.description)?;
 
         // add <video:content_loc>
-        let mut content_loc: XMLElement = XMLElement::new("video:content_loc");
-        content_loc.add_text(self.content_location)?;
-        video.add_child(content_loc)?;
+        writer.text("video:content_loc", &[], &self.content_location)?;
 
         // add <video:player_loc>
-        let mut player_loc: XMLElement = XMLElement::new("video:player_loc");
-        player_loc.add_text(self.player_location)?;
-        video.add_child(player_loc)?;
+        writer.text("video:player_loc", &[], &self.player_location)?;
 
         // add <video:duration>, if it exists
-        if let Some(d) = self.duration {
-            let mut duration: XMLElement = XMLElement::new("video:duration");
-            duration.add_text(d.to_string())?;
-            video.add_child(duration)?;
+        if let Some(duration) = self.duration {
+            writer.text("video:duration", &[], &duration.to_string())?;
         }
 
         // add <video:expiration_date>, if it exists
-        if let Some(exp_date) = self.expiration_date {
-            let mut expiration_date: XMLElement = XMLElement::new("video:expiration_date");
-            expiration_date
-                .add_text(exp_date.to_rfc3339_opts(RFC_3339_SECONDS_FORMAT, RFC_3339_USE_Z))?;
-            video.add_child(expiration_date)?;
+        if let Some(expiration_date) = self.expiration_date {
+            writer.date("video:expiration_date", &expiration_date)?;
         }
 
         // add <video:rating>, if it exists
-        if let Some(r) = self.rating {
-            let mut rating: XMLElement = XMLElement::new("video:rating");
-            rating.add_text(r.to_string())?;
-            video.add_child(rating)?;
+        if let Some(rating) = self.rating {
+            writer.text("video:rating", &[], &rating.to_string())?;
         }
 
         // add <video:view_count>, if it exists
-        if let Some(vc) = self.view_count {
-            let mut view_count: XMLElement = XMLElement::new("video:view_count");
-            view_count.add_text(vc.to_string())?;
-            video.add_child(view_count)?;
+        if let Some(view_count) = self.view_count {
+            writer.text("video:view_count", &[], &view_count.to_string())?;
         }
 
         // add <video:publication_date>, if it exists
-        if let Some(pub_date) = self.publication_date {
-            let mut publication_date: XMLElement = XMLElement::new("video:publication_date");
-            publication_date
-                .add_text(pub_date.to_rfc3339_opts(RFC_3339_SECONDS_FORMAT, RFC_3339_USE_Z))?;
-            video.add_child(publication_date)?;
+        if let Some(publication_date) = self.publication_date {
+            writer.date("video:publication_date", &publication_date)?;
         }
 
         // add <video:family_friendly>, if it exists
-        if let Some(ff) = self.family_friendly {
-            let ff: &str = if ff { "yes" } else { "no" };
-            let mut family_friendly: XMLElement = XMLElement::new("video:family_friendly");
-            family_friendly.add_text(ff.to_string())?;
-            video.add_child(family_friendly)?;
+        if let Some(family_friendly) = self.family_friendly {
+            writer.text("video:family_friendly", &[], yes_no(family_friendly))?;
         }
 
         // add <video:restriction>, if it exists
-        if let Some(restriction) = self.restriction {
-            video.add_child(restriction.to_xml()?)?;
+        if let Some(restriction) = &self.restriction {
+            restriction.write_xml(writer)?;
         }
 
         // add <video:platform>, if it exists
-        if let Some(platform) = self.platform {
-            video.add_child(platform.to_xml()?)?;
+        if let Some(platform) = &self.platform {
+            platform.write_xml(writer)?;
         }
 
         // add <video:requires_subscription>, if it exists
-        if let Some(requires_sub) = self.requires_subscription {
-            let requires_sub: &str = if requires_sub { "yes" } else { "no" };
-            let mut requires_subscription: XMLElement =
-                XMLElement::new("video:requires_subscription");
-            requires_subscription.add_text(requires_sub.to_string())?;
-            video.add_child(requires_subscription)?;
+        if let Some(requires_subscription) = self.requires_subscription {
+            writer.text(
+                "video:requires_subscription",
+                &[],
+                yes_no(requires_subscription),
+            )?;
         }
 
         // add <video:uploader>, if it exists
-        if let Some(uploader) = self.uploader {
-            video.add_child(uploader.to_xml()?)?;
+        if let Some(uploader) = &self.uploader {
+            uploader.write_xml(writer)?;
         }
 
         // add <video:live>, if it exists
-        if let Some(l) = self.live {
-            let l: &str = if l { "yes" } else { "no" };
-            let mut live: XMLElement = XMLElement::new("video:live");
-            live.add_text(l.to_string())?;
-            video.add_child(live)?;
+        if let Some(live) = self.live {
+            writer.text("video:live", &[], yes_no(live))?;
         }
 
         // add <video:tag>, if it exists
-        if let Some(tags) = self.tags {
-            for t in tags {
-                let mut tag: XMLElement = XMLElement::new("video:tag");
-                tag.add_text(t)?;
-                video.add_child(tag)?;
+        if let Some(tags) = &self.tags {
+            for tag in tags {
+                writer.text("video:tag", &[], tag)?;
             }
         }
 
-        Ok(video)
+        writer.end("video:video")
+    }
+}
+
+/// The duration of a video in seconds, checked to be within `1` to `28,800` seconds (`8` hours).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VideoDuration(u16);
+
+impl VideoDuration {
+    /// # Errors
+    ///
+    /// Will return `VideoError::DurationTooShort` if `duration` is below `1` second.
+    /// Will return `VideoError::DurationTooLong` if `duration` is above `28,800` seconds (`8` hours).
+    pub const fn new(duration: u16) -> Result<Self, VideoError> {
+        // duration should be at least `1` second
+        if duration < 1 {
+            return Err(VideoError::DurationTooShort(duration));
+        }
+        // duration should be no longer than `28,800` seconds (8 hours)
+        if duration > 28800 {
+            return Err(VideoError::DurationTooLong(duration));
+        }
+
+        Ok(Self(duration))
+    }
+
+    #[must_use]
+    pub const fn get(self) -> u16 {
+        self.0
+    }
+}
+
+impl TryFrom<u16> for VideoDuration {
+    type Error = VideoError;
+
+    fn try_from(duration: u16) -> Result<Self, Self::Error> {
+        Self::new(duration)
+    }
+}
+
+impl Display for VideoDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The rating of a video, checked to be within `0.0` to `5.0`.
+///
+/// As it is never NaN, it is totally ordered, and can be hashed.
+#[derive(Debug, Copy, Clone)]
+pub struct VideoRating(f32);
+
+impl VideoRating {
+    /// # Errors
+    ///
+    /// Will return `VideoError::RatingTooLow` if `rating` is below `0.0`.
+    /// Will return `VideoError::RatingTooHigh` if `rating` is above `5.0`.
+    /// Will return `VideoError::RatingNotANumber` if `rating` is NaN.
+    pub fn new(rating: f32) -> Result<Self, VideoError> {
+        if rating.is_nan() {
+            return Err(VideoError::RatingNotANumber);
+        }
+        // rating should be no lower than `0.0`
+        if rating < 0.0 {
+            return Err(VideoError::RatingTooLow(rating));
+        }
+        // rating should be no higher than `5.0`
+        if rating > 5.0 {
+            return Err(VideoError::RatingTooHigh(rating));
+        }
+
+        // adding 0.0 turns -0.0 into 0.0, so that equal ratings have equal bits
+        Ok(Self(rating + 0.0))
+    }
+
+    #[must_use]
+    pub const fn get(self) -> f32 {
+        self.0
+    }
+}
+
+impl PartialEq for VideoRating {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+impl Eq for VideoRating {}
+
+impl Hash for VideoRating {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl PartialOrd for VideoRating {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for VideoRating {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl TryFrom<f32> for VideoRating {
+    type Error = VideoError;
+
+    fn try_from(rating: f32) -> Result<Self, Self::Error> {
+        Self::new(rating)
+    }
+}
+
+impl Display for VideoRating {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The tags describing a video, checked to be no more than `32` tags.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TagList(Vec<String>);
+
+impl TagList {
+    /// # Errors
+    ///
+    /// Will return `VideoError::TooManyTags` if there are more than `32` `tags`.
+    pub fn new(tags: Vec<String>) -> Result<Self, VideoError> {
+        // there should not be more than `32` tags
+        if tags.len() > 32 {
+            return Err(VideoError::TooManyTags(tags.len()));
+        }
+
+        Ok(Self(tags))
+    }
+
+    #[must_use]
+    pub fn as_slice(&self) -> &[String] {
+        &self.0
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, String> {
+        self.0.iter()
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> Vec<String> {
+        self.0
+    }
+}
+
+impl TryFrom<Vec<String>> for TagList {
+    type Error = VideoError;
+
+    fn try_from(tags: Vec<String>) -> Result<Self, Self::Error> {
+        Self::new(tags)
+    }
+}
+
+impl<'a> IntoIterator for &'a TagList {
+    type Item = &'a String;
+    type IntoIter = std::slice::Iter<'a, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
 /// Whether to show or hide your video in search results from specific countries.
 ///
 /// Note that this tag only affects search results; it doesn't prevent a user from finding or playing your video in a restricted location though other means.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Restriction {
     /// Specify a space-delimited list of country codes in ISO 3166 format.
     pub country_codes: BTreeSet<String>,
@@ -369,22 +516,25 @@ impl Restriction {
 
     /// # Errors
     ///
-    /// Will return `XMLError` if there is a problem creating XML elements.
-    pub fn to_xml(self) -> Result<XMLElement, XMLError> {
-        let mut restriction: XMLElement = XMLElement::new("video:restriction");
-
-        // set relationship attribute
-        restriction.add_attribute("relationship", self.relationship.as_str());
-
+    /// Will return an IO Error if there is a problem writing to the underlying writer.
+    pub(crate) fn write_xml(&self, writer: &mut XmlWriter<'_>) -> io::Result<()> {
         // set text as space-delimited country codes in ISO 3166 format
-        let country_codes: String = self.country_codes.into_iter().collect::<Vec<_>>().join(" ");
-        restriction.add_text(country_codes)?;
+        let country_codes: String = self
+            .country_codes
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<&str>>()
+            .join(" ");
 
-        Ok(restriction)
+        writer.text(
+            "video:restriction",
+            &[("relationship", self.relationship.as_str())],
+            &country_codes,
+        )
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Relationship {
     Allow,
     Deny,
@@ -409,7 +559,7 @@ impl Display for Relationship {
 /// Whether to show or hide your video in search results on specified platform types.
 ///
 /// Note that this only affects search results on the specified device types; it does not prevent a user from playing your video on a restricted platform.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Platform {
     pub platforms: BTreeSet<PlatformType>,
 
@@ -430,23 +580,21 @@ impl Platform {
 
     /// # Errors
     ///
-    /// Will return `XMLError` if there is a problem creating XML elements.
-    pub fn to_xml(self) -> Result<XMLElement, XMLError> {
-        let mut platform: XMLElement = XMLElement::new("video:platform");
-
-        // set relationship attribute
-        platform.add_attribute("relationship", self.relationship.as_str());
-
+    /// Will return an IO Error if there is a problem writing to the underlying writer.
+    pub(crate) fn write_xml(&self, writer: &mut XmlWriter<'_>) -> io::Result<()> {
         // set text as space-delimited platform types
         let platform_types: String = self
             .platforms
             .iter()
-            .map(std::string::ToString::to_string)
-            .collect::<Vec<String>>()
+            .map(PlatformType::as_str)
+            .collect::<Vec<&str>>()
             .join(" ");
-        platform.add_text(platform_types)?;
 
-        Ok(platform)
+        writer.text(
+            "video:platform",
+            &[("relationship", self.relationship.as_str())],
+            &platform_types,
+        )
     }
 }
 
@@ -480,7 +628,7 @@ impl Display for PlatformType {
 /// The video uploader's name.
 ///
 /// Only one <video:uploader> is allowed per video.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Uploader {
     /// The string value can be a maximum of 255 characters.
     pub name: String,
@@ -498,18 +646,17 @@ impl Uploader {
 
     /// # Errors
     ///
-    /// Will return `XMLError` if there is a problem creating XML elements.
-    pub fn to_xml(self) -> Result<XMLElement, XMLError> {
-        let mut uploader: XMLElement = XMLElement::new("video:uploader");
-
+    /// Will return an IO Error if there is a problem writing to the underlying writer.
+    pub(crate) fn write_xml(&self, writer: &mut XmlWriter<'_>) -> io::Result<()> {
         // set info attribute, if it exists
-        if let Some(info) = self.info {
-            uploader.add_attribute("info", info.as_str());
+        match &self.info {
+            Some(info) => writer.text("video:uploader", &[("info", info)], &self.name),
+            None => writer.text("video:uploader", &[], &self.name),
         }
-
-        // set uploader name as text
-        uploader.add_text(self.name)?;
-
-        Ok(uploader)
     }
 }
+
+/// Video sitemaps spell booleans as "yes" and "no".
+const fn yes_no(value: bool) -> &'static str {
+    if value { "yes" } else { "no" }
+}
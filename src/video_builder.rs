@@ -1,4 +1,10 @@
-use crate::video::{Platform, Restriction, Uploader, Video};
+use crate::date::TryIntoDateTime;
+use crate::date_error::DateError;
+use crate::truncate::truncate_string;
+use crate::video::{
+    MAX_DESCRIPTION_CHARACTERS, MAX_UPLOADER_NAME_CHARACTERS, Platform, Restriction, Uploader,
+    Video,
+};
 use crate::video_error::VideoError;
 use chrono::{DateTime, FixedOffset};
 
@@ -109,6 +115,18 @@ pub struct VideoBuilder {
     /// Create a new <video:tag> element for each tag associated with a video.
     /// A maximum of 32 tags is permitted.
     pub tags: Option<Vec<String>>,
+
+    /// Whether to truncate an overly long `description` or `uploader` `name` instead of returning an error.
+    ///
+    /// Text is cut on a grapheme cluster boundary.
+    /// Defaults to `false`.
+    pub truncate_long_text: bool,
+
+    /// The error from converting the date given to `expiration_date`, returned by `build`.
+    expiration_date_error: Option<DateError>,
+
+    /// The error from converting the date given to `publication_date`, returned by `build`.
+    publication_date_error: Option<DateError>,
 }
 
 impl VideoBuilder {
@@ -138,6 +156,9 @@ impl VideoBuilder {
             uploader: None,
             live: None,
             tags: None,
+            truncate_long_text: false,
+            expiration_date_error: None,
+            publication_date_error: None,
         }
     }
 
@@ -146,8 +167,12 @@ impl VideoBuilder {
         self
     }
 
-    pub fn expiration_date(&mut self, expiration_date: DateTime<FixedOffset>) -> &mut Self {
-        self.expiration_date = Some(expiration_date);
+    /// Sets `expiration_date`; if the date can't be converted, `build` returns the error.
+    pub fn expiration_date(&mut self, expiration_date: impl TryIntoDateTime) -> &mut Self {
+        let expiration_date: Result<DateTime<FixedOffset>, DateError> =
+            expiration_date.try_into_date_time();
+        self.expiration_date_error = expiration_date.as_ref().err().cloned();
+        self.expiration_date = expiration_date.ok();
         self
     }
 
@@ -161,8 +186,12 @@ impl VideoBuilder {
         self
     }
 
-    pub fn publication_date(&mut self, publication_date: DateTime<FixedOffset>) -> &mut Self {
-        self.publication_date = Some(publication_date);
+    /// Sets `publication_date`; if the date can't be converted, `build` returns the error.
+    pub fn publication_date(&mut self, publication_date: impl TryIntoDateTime) -> &mut Self {
+        let publication_date: Result<DateTime<FixedOffset>, DateError> =
+            publication_date.try_into_date_time();
+        self.publication_date_error = publication_date.as_ref().err().cloned();
+        self.publication_date = publication_date.ok();
         self
     }
 
@@ -201,20 +230,47 @@ impl VideoBuilder {
         self
     }
 
+    pub fn truncate_long_text(&mut self, truncate_long_text: bool) -> &mut Self {
+        self.truncate_long_text = truncate_long_text;
+        self
+    }
+
     /// # Errors
     ///
-    /// Will return `VideoError::DescriptionTooLong` if `description` is longer than `2048` characters .
+    /// Will return `VideoError::DescriptionTooLong` if `description` is longer than `2048` characters and `truncate_long_text` is not set.
     /// Will return `VideoError::DurationTooShort` if `duration` is below `1` second.
     /// Will return `VideoError::DurationTooLong` if `duration` is above `28,800` seconds (`8` hours).
     /// Will return `VideoError::RatingTooLow` if `rating` is below `0.0`.
     /// Will return `VideoError::RatingTooHigh` if `rating` is above `5.0`.
-    /// Will return `VideoError::UploaderNameTooLong` if `uploader` `name` is longer than `255` characters.
+    /// Will return `VideoError::UploaderNameTooLong` if `uploader` `name` is longer than `255` characters and `truncate_long_text` is not set.
     /// Will return `VideoError::TooManyTags` if there are more than `32` `tags`.
+    /// Will return `VideoError::Date` if the date given to `expiration_date` or `publication_date` couldn't be converted.
     pub fn build(&self) -> Result<Video, VideoError> {
+        for (field, error) in [
+            ("expiration_date", &self.expiration_date_error),
+            ("publication_date", &self.publication_date_error),
+        ] {
+            if let Some(error) = error {
+                return Err(VideoError::Date {
+                    field,
+                    error: error.clone(),
+                });
+            }
+        }
+
+        let mut description: String = self.description.clone();
+        let mut uploader: Option<Uploader> = self.uploader.clone();
+        if self.truncate_long_text {
+            truncate_string(&mut description, MAX_DESCRIPTION_CHARACTERS);
+            if let Some(uploader) = &mut uploader {
+                truncate_string(&mut uploader.name, MAX_UPLOADER_NAME_CHARACTERS);
+            }
+        }
+
         Video::new(
             self.thumbnail_location.clone(),
             self.title.clone(),
-            self.description.clone(),
+            description,
             self.content_location.clone(),
             self.player_location.clone(),
             self.duration,
@@ -226,7 +282,7 @@ impl VideoBuilder {
             self.restriction.clone(),
             self.platform.clone(),
             self.requires_subscription,
-            self.uploader.clone(),
+            uploader,
             self.live,
             self.tags.clone(),
         )
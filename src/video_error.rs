@@ -1,3 +1,4 @@
+use crate::date_error::DateError;
 use std::error;
 use std::fmt::{Display, Formatter};
 
@@ -5,7 +6,9 @@ use std::fmt::{Display, Formatter};
 #[derive(Debug)]
 pub enum VideoError {
     /// Returned when a sitemap video's `description` is longer than `2048` characters.
-    DescriptionTooLong(usize),
+    ///
+    /// Reports both the Unicode character count (which is what the limit applies to) and the byte count.
+    DescriptionTooLong { characters: usize, bytes: usize },
 
     /// Returned when a sitemap video's `duration` is below `1` second.
     DurationTooShort(u16),
@@ -19,11 +22,37 @@ pub enum VideoError {
     /// Returned when a sitemap video's `rating` is above `5.0`.
     RatingTooHigh(f32),
 
+    /// Returned when a sitemap video's `rating` is NaN.
+    RatingNotANumber,
+
     /// Returned when a sitemap video's `uploader` `name` is longer than `255` characters.
-    UploaderNameTooLong(usize),
+    ///
+    /// Reports both the Unicode character count (which is what the limit applies to) and the byte count.
+    UploaderNameTooLong { characters: usize, bytes: usize },
 
     /// Returned when a sitemap's video element has more than `32` tags.
     TooManyTags(usize),
+
+    /// Returned when a date given to a `VideoBuilder`, such as `publication_date`, can't be converted to a sitemap date.
+    Date {
+        field: &'static str,
+        error: DateError,
+    },
+}
+
+impl VideoError {
+    /// The name of the `Video` field this error is about.
+    #[must_use]
+    pub const fn field(&self) -> &'static str {
+        match self {
+            Self::DescriptionTooLong { .. } => "description",
+            Self::DurationTooShort(_) | Self::DurationTooLong(_) => "duration",
+            Self::RatingTooLow(_) | Self::RatingTooHigh(_) | Self::RatingNotANumber => "rating",
+            Self::UploaderNameTooLong { .. } => "uploader.name",
+            Self::TooManyTags(_) => "tags",
+            Self::Date { field, .. } => field,
+        }
+    }
 }
 
 impl error::Error for VideoError {}
@@ -31,10 +60,10 @@ impl error::Error for VideoError {}
 impl Display for VideoError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::DescriptionTooLong(count) => {
+            Self::DescriptionTooLong { characters, bytes } => {
                 write!(
                     f,
-                    "description cannot be longer than 2048 characters: {count}"
+                    "description cannot be longer than 2048 characters: {characters} characters ({bytes} bytes)"
                 )
             }
             Self::DurationTooShort(duration) => {
@@ -49,12 +78,17 @@ impl Display for VideoError {
             Self::RatingTooHigh(rating) => {
                 write!(f, "rating is above 5.0: {rating}")
             }
-            Self::UploaderNameTooLong(count) => {
-                write!(f, "uploader name is longer than 255 characters: {count}")
+            Self::RatingNotANumber => write!(f, "rating is not a number: NaN"),
+            Self::UploaderNameTooLong { characters, bytes } => {
+                write!(
+                    f,
+                    "uploader name is longer than 255 characters: {characters} characters ({bytes} bytes)"
+                )
             }
             Self::TooManyTags(count) => {
                 write!(f, "must not have more than 32 tags: {count}")
             }
+            Self::Date { field, error } => write!(f, "{field} is not a valid date: {error}"),
         }
     }
 }
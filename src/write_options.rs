@@ -0,0 +1,112 @@
+use crate::date::DateFormat;
+use crate::url_set::UrlOrder;
+
+/// Controls how a sitemap is laid out when it is written.
+///
+/// The default is pretty-printed output indented with tabs and `\n` line endings.
+#[derive(Debug, Clone, Default)]
+pub struct WriteOptions {
+    /// Whether to write whitespace between elements, and how much.
+    pub layout: Layout,
+
+    /// The line ending written after each element when pretty-printing.
+    ///
+    /// This has no effect on `Layout::Compact` output.
+    pub line_ending: LineEnding,
+
+    /// How dates (such as \<lastmod\>) are written.
+    pub date_format: DateFormat,
+
+    /// The order entries are written in, if they should be sorted instead of written as given.
+    ///
+    /// Sorting also writes everything else in a fixed order: namespaces other than the built-in ones by prefix, \<xhtml:link\> by hreflang, images by location, videos by their fields, other extensions by namespace and contents, and the attributes and children of extension elements.
+    /// Sitemaps with the same contents are then written byte-for-byte the same, no matter the order they were built in.
+    pub order: Option<UrlOrder>,
+}
+
+impl WriteOptions {
+    /// Output without any whitespace between elements.
+    #[must_use]
+    pub const fn compact() -> Self {
+        Self {
+            layout: Layout::Compact,
+            line_ending: LineEnding::Lf,
+            date_format: DateFormat::new(),
+            order: None,
+        }
+    }
+
+    /// Output with one element per line, nested elements indented by `indent`.
+    #[must_use]
+    pub const fn pretty(indent: Indent) -> Self {
+        Self {
+            layout: Layout::Pretty(indent),
+            line_ending: LineEnding::Lf,
+            date_format: DateFormat::new(),
+            order: None,
+        }
+    }
+
+    pub fn line_ending(&mut self, line_ending: LineEnding) -> &mut Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    pub fn date_format(&mut self, date_format: DateFormat) -> &mut Self {
+        self.date_format = date_format;
+        self
+    }
+
+    pub const fn order(&mut self, order: UrlOrder) -> &mut Self {
+        self.order = Some(order);
+        self
+    }
+}
+
+/// Whether to write whitespace between elements.
+#[derive(Debug, Copy, Clone)]
+pub enum Layout {
+    /// No whitespace at all; the smallest possible output.
+    Compact,
+
+    /// One element per line, nested elements indented.
+    Pretty(Indent),
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self::Pretty(Indent::Tab)
+    }
+}
+
+/// The indentation of each nesting level when pretty-printing.
+#[derive(Debug, Copy, Clone, Default)]
+pub enum Indent {
+    /// One tab per level.
+    #[default]
+    Tab,
+
+    /// The given number of spaces per level.
+    Spaces(usize),
+}
+
+/// The line ending written after each element when pretty-printing.
+#[derive(Debug, Copy, Clone, Default)]
+pub enum LineEnding {
+    /// `\n`, as used on Unix.
+    #[default]
+    Lf,
+
+    /// `\r\n`, as used on Windows.
+    CrLf,
+}
+
+impl LineEnding {
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+        }
+    }
+}
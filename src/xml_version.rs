@@ -0,0 +1,28 @@
+use std::fmt::{Display, Formatter};
+
+/// The XML version declared at the top of a sitemap file.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum XmlVersion {
+    /// XML 1.0.
+    #[default]
+    Xml1_0,
+
+    /// XML 1.1.
+    Xml1_1,
+}
+
+impl XmlVersion {
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        match self {
+            Self::Xml1_0 => "1.0",
+            Self::Xml1_1 => "1.1",
+        }
+    }
+}
+
+impl Display for XmlVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
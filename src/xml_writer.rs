@@ -0,0 +1,188 @@
+use crate::write_options::{Indent, Layout, WriteOptions};
+use crate::xml_version::XmlVersion;
+use chrono::{DateTime, FixedOffset};
+use std::io::{Error, ErrorKind, Result, Write};
+
+/// Writes XML straight to the underlying writer, escaping text and attribute values as it goes.
+///
+/// Nothing is buffered besides the names of the elements still open, so memory use doesn't grow with the size of the sitemap.
+/// Extensions use this to write their elements; see `Extension`.
+pub struct XmlWriter<'a> {
+    writer: &'a mut dyn Write,
+    options: &'a WriteOptions,
+    open: Vec<String>,
+}
+
+impl<'a> XmlWriter<'a> {
+    pub(crate) const fn new(writer: &'a mut dyn Write, options: &'a WriteOptions) -> Self {
+        Self {
+            writer,
+            options,
+            open: vec![],
+        }
+    }
+
+    pub(crate) const fn options(&self) -> &WriteOptions {
+        self.options
+    }
+
+    /// Writes the `<?xml ... ?>` declaration.
+    pub(crate) fn declaration(&mut self, version: XmlVersion, encoding: &str) -> Result<()> {
+        self.writer.write_all(b"<?xml version=\"")?;
+        self.writer.write_all(version.as_str().as_bytes())?;
+        self.writer.write_all(b"\" encoding=\"")?;
+        write_escaped(self.writer, encoding, true)?;
+        self.writer.write_all(b"\"?>")?;
+        self.line_break()
+    }
+
+    /// Writes an `<?xml-stylesheet ... ?>` processing instruction for an XSL stylesheet.
+    pub(crate) fn stylesheet(&mut self, href: &str) -> Result<()> {
+        self.writer
+            .write_all(b"<?xml-stylesheet type=\"text/xsl\" href=\"")?;
+        write_escaped(self.writer, href, true)?;
+        self.writer.write_all(b"\"?>")?;
+        self.line_break()
+    }
+
+    /// Writes an opening tag; every call must be matched by a call to `end()`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an IO Error if there is a problem writing to the underlying writer.
+    pub fn start(&mut self, name: &str, attributes: &[(&str, &str)]) -> Result<()> {
+        self.open_tag(name, attributes)?;
+        self.writer.write_all(b">")?;
+        self.line_break()?;
+        self.open.push(name.to_string());
+        Ok(())
+    }
+
+    /// Writes the closing tag of the element most recently opened with `start()`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an IO Error if there is a problem writing to the underlying writer.
+    /// Will return an IO Error of kind `InvalidInput` if there is no element left to close, or if `name` isn't the name of the element most recently opened.
+    pub fn end(&mut self, name: &str) -> Result<()> {
+        match self.open.last() {
+            Some(open) if open == name => {}
+            Some(open) => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("</{name}> does not close <{open}>"),
+                ));
+            }
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("</{name}> does not close any element"),
+                ));
+            }
+        }
+        self.open.pop();
+        self.indent()?;
+        self.writer.write_all(b"</")?;
+        self.writer.write_all(name.as_bytes())?;
+        self.writer.write_all(b">")?;
+        self.line_break()
+    }
+
+    /// Writes a self-closing element, such as `<xhtml:link ... />`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an IO Error if there is a problem writing to the underlying writer.
+    pub fn empty(&mut self, name: &str, attributes: &[(&str, &str)]) -> Result<()> {
+        self.open_tag(name, attributes)?;
+        self.writer.write_all(b" />")?;
+        self.line_break()
+    }
+
+    /// Writes an element whose only content is `text`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an IO Error if there is a problem writing to the underlying writer.
+    pub fn text(&mut self, name: &str, attributes: &[(&str, &str)], text: &str) -> Result<()> {
+        self.open_tag(name, attributes)?;
+        self.writer.write_all(b">")?;
+        write_escaped(self.writer, text, false)?;
+        self.writer.write_all(b"</")?;
+        self.writer.write_all(name.as_bytes())?;
+        self.writer.write_all(b">")?;
+        self.line_break()
+    }
+
+    /// Writes an element whose only content is `date`, formatted according to the `DateFormat` of the `WriteOptions`.
+    ///
+    /// # Errors
+    ///
+    /// Will return an IO Error if there is a problem writing to the underlying writer.
+    pub fn date(&mut self, name: &str, date: &DateTime<FixedOffset>) -> Result<()> {
+        let date: String = self.options.date_format.format(date);
+        self.text(name, &[], &date)
+    }
+
+    fn open_tag(&mut self, name: &str, attributes: &[(&str, &str)]) -> Result<()> {
+        self.indent()?;
+        self.writer.write_all(b"<")?;
+        self.writer.write_all(name.as_bytes())?;
+        for (key, value) in attributes {
+            self.writer.write_all(b" ")?;
+            self.writer.write_all(key.as_bytes())?;
+            self.writer.write_all(b"=\"")?;
+            write_escaped(self.writer, value, true)?;
+            self.writer.write_all(b"\"")?;
+        }
+        Ok(())
+    }
+
+    fn indent(&mut self) -> Result<()> {
+        match self.options.layout {
+            Layout::Compact => Ok(()),
+            Layout::Pretty(Indent::Tab) => {
+                for _ in 0..self.open.len() {
+                    self.writer.write_all(b"\t")?;
+                }
+                Ok(())
+            }
+            Layout::Pretty(Indent::Spaces(spaces)) => {
+                for _ in 0..self.open.len() * spaces {
+                    self.writer.write_all(b" ")?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn line_break(&mut self) -> Result<()> {
+        match self.options.layout {
+            Layout::Compact => Ok(()),
+            Layout::Pretty(_) => self
+                .writer
+                .write_all(self.options.line_ending.as_str().as_bytes()),
+        }
+    }
+}
+
+/// Writes `text` with XML special characters replaced by entities.
+///
+/// Quotes only need escaping within attribute values.
+fn write_escaped(writer: &mut dyn Write, text: &str, is_attribute: bool) -> Result<()> {
+    let mut start: usize = 0;
+    for (index, byte) in text.bytes().enumerate() {
+        let entity: &[u8] = match byte {
+            b'&' => b"&amp;",
+            b'<' => b"&lt;",
+            b'>' => b"&gt;",
+            b'"' if is_attribute => b"&quot;",
+            b'\'' if is_attribute => b"&apos;",
+            _ => continue,
+        };
+        writer.write_all(&text.as_bytes()[start..index])?;
+        writer.write_all(entity)?;
+        start = index + 1;
+    }
+    writer.write_all(&text.as_bytes()[start..])
+}
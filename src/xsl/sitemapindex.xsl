@@ -0,0 +1,37 @@
+<?xml version="1.0" encoding="UTF-8"?>
+<xsl:stylesheet version="1.0"
+	xmlns:xsl="http://www.w3.org/1999/XSL/Transform"
+	xmlns:sitemap="http://www.sitemaps.org/schemas/sitemap/0.9"
+	exclude-result-prefixes="sitemap">
+	<xsl:output method="html" encoding="UTF-8" indent="yes" />
+	<xsl:template match="/">
+		<html>
+			<head>
+				<meta charset="UTF-8" />
+				<title>Sitemap Index</title>
+				<style>
+					body { font-family: sans-serif; margin: 2em; }
+					table { border-collapse: collapse; width: 100%; }
+					th, td { border-bottom: 1px solid #ddd; padding: 0.4em; text-align: left; }
+					th { background: #f4f4f4; }
+				</style>
+			</head>
+			<body>
+				<h1>Sitemap Index</h1>
+				<p>This sitemap index contains <xsl:value-of select="count(sitemap:sitemapindex/sitemap:sitemap)" /> sitemaps.</p>
+				<table>
+					<tr>
+						<th>Sitemap</th>
+						<th>Last Modified</th>
+					</tr>
+					<xsl:for-each select="sitemap:sitemapindex/sitemap:sitemap">
+						<tr>
+							<td><a href="{sitemap:loc}"><xsl:value-of select="sitemap:loc" /></a></td>
+							<td><xsl:value-of select="sitemap:lastmod" /></td>
+						</tr>
+					</xsl:for-each>
+				</table>
+			</body>
+		</html>
+	</xsl:template>
+</xsl:stylesheet>
@@ -0,0 +1,53 @@
+<?xml version="1.0" encoding="UTF-8"?>
+<xsl:stylesheet version="1.0"
+	xmlns:xsl="http://www.w3.org/1999/XSL/Transform"
+	xmlns:sitemap="http://www.sitemaps.org/schemas/sitemap/0.9"
+	xmlns:xhtml="http://www.w3.org/1999/xhtml"
+	xmlns:image="http://www.google.com/schemas/sitemap-image/1.1"
+	xmlns:video="http://www.google.com/schemas/sitemap-video/1.1"
+	xmlns:news="http://www.google.com/schemas/sitemap-news/0.9"
+	exclude-result-prefixes="sitemap xhtml image video news">
+	<xsl:output method="html" encoding="UTF-8" indent="yes" />
+	<xsl:template match="/">
+		<html>
+			<head>
+				<meta charset="UTF-8" />
+				<title>Sitemap</title>
+				<style>
+					body { font-family: sans-serif; margin: 2em; }
+					table { border-collapse: collapse; width: 100%; }
+					th, td { border-bottom: 1px solid #ddd; padding: 0.4em; text-align: left; }
+					th { background: #f4f4f4; }
+				</style>
+			</head>
+			<body>
+				<h1>Sitemap</h1>
+				<p>This sitemap contains <xsl:value-of select="count(sitemap:urlset/sitemap:url)" /> URLs.</p>
+				<table>
+					<tr>
+						<th>Location</th>
+						<th>Last Modified</th>
+						<th>Change Frequency</th>
+						<th>Priority</th>
+						<th>Alternates</th>
+						<th>Images</th>
+						<th>Videos</th>
+						<th>News</th>
+					</tr>
+					<xsl:for-each select="sitemap:urlset/sitemap:url">
+						<tr>
+							<td><a href="{sitemap:loc}"><xsl:value-of select="sitemap:loc" /></a></td>
+							<td><xsl:value-of select="sitemap:lastmod" /></td>
+							<td><xsl:value-of select="sitemap:changefreq" /></td>
+							<td><xsl:value-of select="sitemap:priority" /></td>
+							<td><xsl:value-of select="count(xhtml:link)" /></td>
+							<td><xsl:value-of select="count(image:image)" /></td>
+							<td><xsl:value-of select="count(video:video)" /></td>
+							<td><xsl:value-of select="news:news/news:title" /></td>
+						</tr>
+					</xsl:for-each>
+				</table>
+			</body>
+		</html>
+	</xsl:template>
+</xsl:stylesheet>
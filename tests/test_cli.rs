@@ -0,0 +1,381 @@
+#![cfg(feature = "cli")]
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+fn output_dir(name: &str) -> PathBuf {
+    let path: PathBuf =
+        std::env::temp_dir().join(format!("sitemap-rs-{}-{name}", std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    fs::create_dir_all(&path).unwrap();
+    path
+}
+
+fn sitemap_rs(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_sitemap-rs"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_generate_from_csv() {
+    let dir: PathBuf = output_dir("csv");
+    let input: PathBuf = dir.join("urls.csv");
+    fs::write(
+        &input,
+        "loc,lastmod,changefreq,priority\n/,1998-01-15,monthly,0.8\n/about,,,\n",
+    )
+    .unwrap();
+
+    let output: Output = sitemap_rs(&[
+        "generate",
+        input.to_str().unwrap(),
+        "--base-url",
+        "https://www.toddgriffin.me",
+        "--change-frequency",
+        "yearly",
+        "--output-dir",
+        dir.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+
+    let expected: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+	<url>
+		<loc>https://www.toddgriffin.me/</loc>
+		<lastmod>1998-01-15T00:00:00+00:00</lastmod>
+		<changefreq>monthly</changefreq>
+		<priority>0.8</priority>
+	</url>
+	<url>
+		<loc>https://www.toddgriffin.me/about</loc>
+		<changefreq>yearly</changefreq>
+	</url>
+</urlset>
+"#;
+    assert_eq!(
+        expected,
+        fs::read_to_string(dir.join("sitemap.xml")).unwrap()
+    );
+}
+
+#[test]
+fn test_generate_from_json_lines() {
+    let dir: PathBuf = output_dir("jsonl");
+    let input: PathBuf = dir.join("urls.jsonl");
+    fs::write(
+        &input,
+        "{\"loc\": \"https://www.toddgriffin.me/\", \"priority\": 1.0}\n",
+    )
+    .unwrap();
+
+    let output: Output = sitemap_rs(&[
+        "generate",
+        input.to_str().unwrap(),
+        "--output-dir",
+        dir.to_str().unwrap(),
+        "--name",
+        "pages",
+    ]);
+    assert!(output.status.success());
+    assert!(
+        fs::read_to_string(dir.join("pages.xml"))
+            .unwrap()
+            .contains("<priority>1</priority>")
+    );
+}
+
+#[test]
+fn test_generate_reports_invalid_entries() {
+    let dir: PathBuf = output_dir("invalid");
+    let input: PathBuf = dir.join("urls.csv");
+    fs::write(
+        &input,
+        "loc,lastmod,changefreq,priority\nhttps://www.toddgriffin.me/,yesterday,fortnightly,4.69\n",
+    )
+    .unwrap();
+
+    let output: Output = sitemap_rs(&[
+        "generate",
+        input.to_str().unwrap(),
+        "--output-dir",
+        dir.to_str().unwrap(),
+    ]);
+    assert!(!output.status.success());
+
+    let report: String = String::from_utf8(output.stderr).unwrap();
+    assert!(report.contains("line 2: lastmod must be a W3C Datetime: yesterday"));
+    assert!(report.contains("line 2: change frequency must be one of"));
+    assert!(report.contains("line 2: priority must not be above 1.0: 4.69"));
+    assert!(!dir.join("sitemap.xml").exists());
+}
+
+#[test]
+fn test_generate_reports_every_row() {
+    let dir: PathBuf = output_dir("rows");
+    let input: PathBuf = dir.join("urls.csv");
+    // the second entry spans two lines, so the third one is on line 5
+    fs::write(
+        &input,
+        format!(
+            "loc,lastmod,changefreq,priority\nhttps://www.toddgriffin.me/,,,high\n\"https://www.toddgriffin.me/\nblog/\",,,\nhttps://www.toddgriffin.me/{},,,4.69\n",
+            "t".repeat(2048)
+        ),
+    )
+    .unwrap();
+
+    let output: Output = sitemap_rs(&[
+        "generate",
+        input.to_str().unwrap(),
+        "--output-dir",
+        dir.to_str().unwrap(),
+    ]);
+    assert!(!output.status.success());
+
+    let report: String = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        report.contains("  line 2: CSV deserialize error"),
+        "{report}"
+    );
+    assert!(
+        report.contains("  line 5: location must be less than 2,048 characters"),
+        "{report}"
+    );
+    assert!(
+        report.contains("  line 5: priority must not be above 1.0: 4.69"),
+        "{report}"
+    );
+    assert!(!report.contains("line 3"), "{report}");
+    assert!(!dir.join("sitemap.xml").exists());
+}
+
+#[test]
+fn test_generate_with_manifest() {
+    let dir: PathBuf = output_dir("manifest");
+    let input: PathBuf = dir.join("urls.txt");
+    let manifest: PathBuf = dir.join("sitemap.manifest");
+    let args: [&str; 8] = [
+        "generate",
+        input.to_str().unwrap(),
+        "--base-url",
+        "https://www.toddgriffin.me",
+        "--output-dir",
+        dir.to_str().unwrap(),
+        "--manifest",
+        manifest.to_str().unwrap(),
+    ];
+
+    fs::write(&input, "/\n/about\n").unwrap();
+    let output: Output = sitemap_rs(&args);
+    assert!(output.status.success());
+    let stdout: String = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("sitemap-1.xml\n"));
+    assert!(stdout.contains("sitemap.xml\n"));
+    let index: String = fs::read_to_string(dir.join("sitemap.xml")).unwrap();
+    assert!(index.contains("<loc>https://www.toddgriffin.me/sitemap-1.xml</loc>"));
+    assert!(manifest.exists());
+
+    // nothing changed, so only the index and manifest are written
+    let output: Output = sitemap_rs(&args);
+    assert!(output.status.success());
+    assert!(
+        !String::from_utf8(output.stdout)
+            .unwrap()
+            .contains("sitemap-1.xml")
+    );
+    assert_eq!(index, fs::read_to_string(dir.join("sitemap.xml")).unwrap());
+}
+
+#[test]
+fn test_site() {
+    let dir: PathBuf = output_dir("site");
+    fs::create_dir_all(dir.join("blog")).unwrap();
+    fs::write(dir.join("index.html"), "<html></html>").unwrap();
+    fs::write(dir.join("blog").join("index.html"), "<html></html>").unwrap();
+    fs::write(
+        dir.join("drafts.html"),
+        r#"<html><head><meta name="robots" content="noindex"></head></html>"#,
+    )
+    .unwrap();
+
+    let output: Output = sitemap_rs(&[
+        "site",
+        dir.to_str().unwrap(),
+        "--base-url",
+        "https://www.toddgriffin.me",
+        "--no-last-modified",
+    ]);
+    assert!(output.status.success());
+
+    let expected: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+	<url>
+		<loc>https://www.toddgriffin.me/blog/</loc>
+	</url>
+	<url>
+		<loc>https://www.toddgriffin.me/</loc>
+	</url>
+</urlset>
+"#;
+    assert_eq!(
+        expected,
+        fs::read_to_string(dir.join("sitemap.xml")).unwrap()
+    );
+}
+
+#[test]
+fn test_validate() {
+    let dir: PathBuf = output_dir("validate");
+    let input: PathBuf = dir.join("urls.txt");
+    fs::write(&input, "https://www.toddgriffin.me/\n").unwrap();
+    assert!(
+        sitemap_rs(&[
+            "generate",
+            input.to_str().unwrap(),
+            "--gzip",
+            "--output-dir",
+            dir.to_str().unwrap(),
+        ])
+        .status
+        .success()
+    );
+
+    let sitemap: PathBuf = dir.join("sitemap.xml.gz");
+    let output: Output = sitemap_rs(&["validate", sitemap.to_str().unwrap()]);
+    assert!(output.status.success());
+    assert_eq!(
+        format!("{}: valid <urlset> with 1 URL\n", sitemap.display()),
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
+
+#[test]
+fn test_validate_reports_problems() {
+    let dir: PathBuf = output_dir("validate-invalid");
+    let sitemap: PathBuf = dir.join("sitemap.xml");
+    fs::write(
+        &sitemap,
+        r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+	<url>
+		<loc>https://www.toddgriffin.me/</loc>
+		<priority>4.2</priority>
+	</url>
+</urlset>"#,
+    )
+    .unwrap();
+
+    let output: Output = sitemap_rs(&["validate", sitemap.to_str().unwrap()]);
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8(output.stdout)
+            .unwrap()
+            .contains("  line 4: urls[0].priority: priority must not be above 1.0: 4.2\n")
+    );
+
+    let output: Output = sitemap_rs(&["validate", "--format", "json", sitemap.to_str().unwrap()]);
+    assert!(!output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(false, report[0]["valid"]);
+    assert_eq!(4, report[0]["problems"][0]["line"]);
+    assert_eq!("urls[0].priority", report[0]["problems"][0]["path"]);
+}
+
+#[test]
+fn test_validate_nested_index() {
+    let dir: PathBuf = output_dir("validate-nested");
+    fs::write(
+        dir.join("sitemap-1.xml"),
+        r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9"></urlset>"#,
+    )
+    .unwrap();
+    fs::write(
+        dir.join("nested.xml"),
+        r#"<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9"></sitemapindex>"#,
+    )
+    .unwrap();
+    let sitemap: PathBuf = dir.join("sitemap.xml");
+    fs::write(
+        &sitemap,
+        r#"<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+	<sitemap><loc>https://www.toddgriffin.me/sitemap-1.xml</loc></sitemap>
+	<sitemap><loc>https://www.toddgriffin.me/nested.xml</loc></sitemap>
+	<sitemap><loc>https://cdn.toddgriffin.me/sitemap-2.xml</loc></sitemap>
+</sitemapindex>"#,
+    )
+    .unwrap();
+
+    // without a base URL, the referenced files aren't checked
+    let output: Output = sitemap_rs(&["validate", sitemap.to_str().unwrap()]);
+    assert!(output.status.success());
+
+    let output: Output = sitemap_rs(&[
+        "validate",
+        "--base-url",
+        "https://www.toddgriffin.me",
+        sitemap.to_str().unwrap(),
+    ]);
+    assert!(!output.status.success());
+    assert_eq!(
+        format!(
+            "{}: 2 problems in <sitemapindex> with 3 sitemaps\n  sitemaps[1].location: location must not reference another sitemap index: https://www.toddgriffin.me/nested.xml\n  sitemaps[2].location: location should be on the same host as the sitemap index (www.toddgriffin.me): https://cdn.toddgriffin.me/sitemap-2.xml\n",
+            sitemap.display()
+        ),
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
+
+#[test]
+fn test_diff() {
+    let dir: PathBuf = output_dir("diff");
+    let old: PathBuf = dir.join("old.xml");
+    let new: PathBuf = dir.join("new.xml");
+    fs::write(
+        &old,
+        r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+	<url><loc>https://www.toddgriffin.me/</loc><changefreq>monthly</changefreq></url>
+	<url><loc>https://www.toddgriffin.me/old</loc></url>
+</urlset>"#,
+    )
+    .unwrap();
+    fs::write(
+        &new,
+        r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+	<url><loc>https://www.toddgriffin.me/</loc><changefreq>weekly</changefreq></url>
+	<url><loc>https://www.toddgriffin.me/new</loc></url>
+</urlset>"#,
+    )
+    .unwrap();
+
+    let output: Output = sitemap_rs(&["diff", old.to_str().unwrap(), new.to_str().unwrap()]);
+    assert!(output.status.success());
+    assert_eq!(
+        "+ https://www.toddgriffin.me/new\n- https://www.toddgriffin.me/old\n~ https://www.toddgriffin.me/\n    changefreq: monthly -> weekly\n",
+        String::from_utf8(output.stdout).unwrap()
+    );
+
+    let output: Output = sitemap_rs(&[
+        "diff",
+        "--format",
+        "json",
+        "--exit-code",
+        old.to_str().unwrap(),
+        new.to_str().unwrap(),
+    ]);
+    assert!(!output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!("https://www.toddgriffin.me/new", report["added"][0]);
+    assert_eq!("https://www.toddgriffin.me/old", report["removed"][0]);
+    assert_eq!("changefreq", report["changed"][0]["changes"][0]["field"]);
+    assert_eq!("weekly", report["changed"][0]["changes"][0]["new"]);
+
+    let output: Output = sitemap_rs(&[
+        "diff",
+        "--exit-code",
+        old.to_str().unwrap(),
+        old.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+}
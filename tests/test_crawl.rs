@@ -0,0 +1,266 @@
+#![cfg(feature = "crawl")]
+
+use sitemap_rs::crawl::{Crawl, Crawler, LocalFetcher};
+use sitemap_rs::crawl_error::CrawlError;
+use sitemap_rs::resolve::SitemapKind;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+
+fn url_set(locations: &[&str]) -> Vec<u8> {
+    let urls: String = locations.iter().fold(String::new(), |mut urls, location| {
+        let _ = write!(urls, "<url><loc>{location}</loc></url>");
+        urls
+    });
+    format!(r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">{urls}</urlset>"#)
+        .into_bytes()
+}
+
+fn sitemap_index(locations: &[&str]) -> Vec<u8> {
+    let sitemaps: String = locations
+        .iter()
+        .fold(String::new(), |mut sitemaps, location| {
+            let _ = write!(sitemaps, "<sitemap><loc>{location}</loc></sitemap>");
+            sitemaps
+        });
+    format!(
+        r#"<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">{sitemaps}</sitemapindex>"#
+    )
+    .into_bytes()
+}
+
+fn gzip(bytes: &[u8]) -> Vec<u8> {
+    let mut encoder: flate2::write::GzEncoder<Vec<u8>> =
+        flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+    encoder.write_all(bytes).unwrap();
+    encoder.finish().unwrap()
+}
+
+/// A site with a robots.txt, a sitemap index that references itself, a gzipped sitemap, and a missing sitemap.
+fn site() -> HashMap<String, Vec<u8>> {
+    HashMap::from([
+        (
+            String::from("https://www.toddgriffin.me/robots.txt"),
+            b"User-agent: *\nDisallow: /private\n\nsitemap: https://www.toddgriffin.me/sitemap.xml\n"
+                .to_vec(),
+        ),
+        (
+            String::from("https://www.toddgriffin.me/sitemap.xml"),
+            sitemap_index(&[
+                "https://www.toddgriffin.me/sitemap-1.xml.gz",
+                "https://www.toddgriffin.me/sitemap-2.xml",
+                "https://www.toddgriffin.me/missing.xml",
+                "https://www.toddgriffin.me/sitemap.xml",
+            ]),
+        ),
+        (
+            String::from("https://www.toddgriffin.me/sitemap-1.xml.gz"),
+            gzip(&url_set(&[
+                "https://www.toddgriffin.me/",
+                "https://www.toddgriffin.me/about",
+            ])),
+        ),
+        (
+            String::from("https://www.toddgriffin.me/sitemap-2.xml"),
+            url_set(&["https://www.toddgriffin.me/blog"]),
+        ),
+    ])
+}
+
+fn fetcher(site: HashMap<String, Vec<u8>>) -> impl Fn(&str) -> io::Result<Box<dyn Read>> {
+    move |location: &str| {
+        let body: Vec<u8> = site
+            .get(location)
+            .cloned()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "404 Not Found"))?;
+        Ok(Box::new(io::Cursor::new(body)))
+    }
+}
+
+fn locations(crawl: &Crawl) -> Vec<&str> {
+    crawl.urls.iter().map(|url| url.location.as_str()).collect()
+}
+
+#[test]
+fn test_crawl_robots_txt() {
+    let crawl: Crawl = Crawler::new(fetcher(site()))
+        .crawl("https://www.toddgriffin.me/robots.txt")
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            "https://www.toddgriffin.me/",
+            "https://www.toddgriffin.me/about",
+            "https://www.toddgriffin.me/blog",
+        ],
+        locations(&crawl)
+    );
+
+    // the sitemap index is only read once, even though it references itself
+    assert_eq!(3, crawl.sitemaps.len());
+    assert_eq!(SitemapKind::SitemapIndex, crawl.sitemaps[0].kind);
+    assert_eq!(0, crawl.sitemaps[0].depth);
+    assert_eq!(4, crawl.sitemaps[0].entries);
+    assert_eq!(SitemapKind::UrlSet, crawl.sitemaps[1].kind);
+    assert_eq!(1, crawl.sitemaps[1].depth);
+
+    assert_eq!(1, crawl.errors.len());
+    assert_eq!(
+        "https://www.toddgriffin.me/missing.xml: 404 Not Found",
+        crawl.errors[0].to_string()
+    );
+}
+
+#[test]
+fn test_crawl_sitemap() {
+    let crawl: Crawl = Crawler::new(fetcher(site()))
+        .crawl("https://www.toddgriffin.me/sitemap-2.xml")
+        .unwrap();
+    assert_eq!(vec!["https://www.toddgriffin.me/blog"], locations(&crawl));
+    assert!(crawl.errors.is_empty());
+}
+
+#[test]
+fn test_crawl_unreadable_start() {
+    match Crawler::new(fetcher(site())).crawl("https://www.toddgriffin.me/missing.xml") {
+        Err(CrawlError::Fetch { location, .. }) => {
+            assert_eq!("https://www.toddgriffin.me/missing.xml", location);
+        }
+        result => panic!("Returned {result:?}!"),
+    }
+
+    let mut site: HashMap<String, Vec<u8>> = site();
+    site.insert(
+        String::from("https://www.toddgriffin.me/robots.txt"),
+        b"User-agent: *\n".to_vec(),
+    );
+    match Crawler::new(fetcher(site)).crawl("https://www.toddgriffin.me/robots.txt") {
+        Err(CrawlError::NoSitemaps(_)) => {}
+        result => panic!("Returned {result:?}!"),
+    }
+}
+
+#[test]
+fn test_crawl_max_depth() {
+    let mut site: HashMap<String, Vec<u8>> = site();
+    site.insert(
+        String::from("https://www.toddgriffin.me/sitemap-2.xml"),
+        sitemap_index(&["https://www.toddgriffin.me/sitemap-3.xml"]),
+    );
+    site.insert(
+        String::from("https://www.toddgriffin.me/sitemap-3.xml"),
+        url_set(&["https://www.toddgriffin.me/deep"]),
+    );
+
+    let crawl: Crawl = Crawler::new(fetcher(site.clone()))
+        .crawl("https://www.toddgriffin.me/sitemap.xml")
+        .unwrap();
+    assert_eq!(2, crawl.urls.len());
+    assert!(crawl.errors.iter().any(|error| matches!(
+        error,
+        CrawlError::TooDeep { location, depth: 1 } if location == "https://www.toddgriffin.me/sitemap-3.xml"
+    )));
+
+    let crawl: Crawl = Crawler::new(fetcher(site))
+        .max_depth(2)
+        .crawl("https://www.toddgriffin.me/sitemap.xml")
+        .unwrap();
+    assert!(locations(&crawl).contains(&"https://www.toddgriffin.me/deep"));
+}
+
+#[test]
+fn test_crawl_too_deep_not_visited() {
+    // sitemap-3.xml is too deep through sitemap-2.xml, but is listed directly afterwards as well
+    let mut site: HashMap<String, Vec<u8>> = site();
+    site.insert(
+        String::from("https://www.toddgriffin.me/sitemap.xml"),
+        sitemap_index(&[
+            "https://www.toddgriffin.me/sitemap-2.xml",
+            "https://www.toddgriffin.me/sitemap-3.xml",
+        ]),
+    );
+    site.insert(
+        String::from("https://www.toddgriffin.me/sitemap-2.xml"),
+        sitemap_index(&["https://www.toddgriffin.me/sitemap-3.xml"]),
+    );
+    site.insert(
+        String::from("https://www.toddgriffin.me/sitemap-3.xml"),
+        url_set(&["https://www.toddgriffin.me/deep"]),
+    );
+
+    let crawl: Crawl = Crawler::new(fetcher(site))
+        .crawl("https://www.toddgriffin.me/sitemap.xml")
+        .unwrap();
+    assert_eq!(vec!["https://www.toddgriffin.me/deep"], locations(&crawl));
+    assert!(matches!(
+        crawl.errors.as_slice(),
+        [CrawlError::TooDeep { depth: 1, .. }]
+    ));
+}
+
+#[test]
+fn test_crawl_max_urls() {
+    let crawl: Crawl = Crawler::new(fetcher(site()))
+        .max_urls(1)
+        .crawl("https://www.toddgriffin.me/sitemap.xml")
+        .unwrap();
+    assert_eq!(vec!["https://www.toddgriffin.me/"], locations(&crawl));
+    assert!(matches!(
+        crawl.errors.last(),
+        Some(CrawlError::TooManyUrls(1))
+    ));
+}
+
+#[test]
+fn test_crawl_max_bytes() {
+    let site: HashMap<String, Vec<u8>> = site();
+    let index: u64 = site["https://www.toddgriffin.me/sitemap.xml"].len() as u64;
+
+    // the gzipped sitemap counts with its uncompressed size
+    let crawl: Crawl = Crawler::new(fetcher(site))
+        .max_bytes(index + 100)
+        .crawl("https://www.toddgriffin.me/sitemap.xml")
+        .unwrap();
+    assert!(crawl.urls.is_empty());
+    assert_eq!(1, crawl.sitemaps.len());
+    assert!(matches!(
+        crawl.errors.last(),
+        Some(CrawlError::TooManyBytes(_))
+    ));
+}
+
+#[test]
+fn test_crawl_max_bytes_endless() {
+    // a body that never ends is only read up to the limit
+    let endless = |_: &str| -> io::Result<Box<dyn Read>> { Ok(Box::new(io::repeat(b' '))) };
+    match Crawler::new(endless)
+        .max_bytes(1024)
+        .crawl("https://www.toddgriffin.me/sitemap.xml")
+    {
+        Err(CrawlError::TooManyBytes(1024)) => {}
+        result => panic!("Returned {result:?}!"),
+    }
+}
+
+#[test]
+fn test_local_fetcher() {
+    let dir: PathBuf =
+        std::env::temp_dir().join(format!("sitemap-rs-crawl-{}-local", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    for (location, body) in site() {
+        let name: &str = location.rsplit('/').next().unwrap();
+        fs::write(dir.join(name), body).unwrap();
+    }
+
+    let crawl: Crawl = Crawler::new(LocalFetcher::new(
+        dir,
+        String::from("https://www.toddgriffin.me"),
+    ))
+    .crawl("https://www.toddgriffin.me/robots.txt")
+    .unwrap();
+    assert_eq!(3, crawl.urls.len());
+    assert_eq!(1, crawl.errors.len());
+}
@@ -0,0 +1,195 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone, Utc};
+#[cfg(any(feature = "time", feature = "jiff"))]
+use sitemap_rs::date::TryIntoDateTime;
+use sitemap_rs::date::{DateFormat, DatePrecision, IntoDateTime, parse_w3c_datetime};
+#[cfg(any(feature = "time", feature = "jiff"))]
+use sitemap_rs::date_error::DateError;
+use sitemap_rs::url::Url;
+use sitemap_rs::url_set::UrlSet;
+use sitemap_rs::write_options::WriteOptions;
+
+fn date() -> DateTime<FixedOffset> {
+    FixedOffset::east_opt(8 * 3600)
+        .unwrap()
+        .with_ymd_and_hms(1998, 1, 15, 4, 20, 0)
+        .unwrap()
+}
+
+#[test]
+fn test_default_format() {
+    assert_eq!(
+        "1998-01-15T04:20:00+08:00",
+        DateFormat::default().format(&date())
+    );
+}
+
+#[test]
+fn test_precision() {
+    let mut date_format: DateFormat = DateFormat::new();
+
+    date_format.precision(DatePrecision::Date);
+    assert_eq!("1998-01-15", date_format.format(&date()));
+
+    date_format.precision(DatePrecision::Minutes);
+    assert_eq!("1998-01-15T04:20+08:00", date_format.format(&date()));
+}
+
+#[test]
+fn test_normalize_to_utc() {
+    let mut date_format: DateFormat = DateFormat::new();
+    date_format.normalize_to_utc(true);
+    assert_eq!("1998-01-14T20:20:00+00:00", date_format.format(&date()));
+
+    date_format.use_z(true);
+    assert_eq!("1998-01-14T20:20:00Z", date_format.format(&date()));
+
+    date_format.precision(DatePrecision::Minutes);
+    assert_eq!("1998-01-14T20:20Z", date_format.format(&date()));
+
+    // the date can change when normalizing
+    date_format.precision(DatePrecision::Date);
+    assert_eq!("1998-01-14", date_format.format(&date()));
+}
+
+#[test]
+fn test_use_z_only_applies_to_utc() {
+    let mut date_format: DateFormat = DateFormat::new();
+    date_format.use_z(true);
+    assert_eq!("1998-01-15T04:20:00+08:00", date_format.format(&date()));
+}
+
+#[test]
+fn test_into_date_time() {
+    let expected: DateTime<FixedOffset> = FixedOffset::east_opt(0)
+        .unwrap()
+        .with_ymd_and_hms(1998, 1, 15, 0, 0, 0)
+        .unwrap();
+
+    let naive_date: NaiveDate = NaiveDate::from_ymd_opt(1998, 1, 15).unwrap();
+    assert_eq!(expected, naive_date.into_date_time());
+    assert_eq!(
+        expected,
+        naive_date.and_hms_opt(0, 0, 0).unwrap().into_date_time()
+    );
+    assert_eq!(
+        expected,
+        Utc.with_ymd_and_hms(1998, 1, 15, 0, 0, 0)
+            .unwrap()
+            .into_date_time()
+    );
+}
+
+#[test]
+fn test_write_with_date_format() {
+    let urls: Vec<Url> = vec![
+        Url::builder(String::from("https://www.toddgriffin.me/"))
+            .last_modified(NaiveDate::from_ymd_opt(1998, 1, 15).unwrap())
+            .build()
+            .unwrap(),
+    ];
+    let url_set: UrlSet = UrlSet::new(urls).unwrap();
+
+    let mut date_format: DateFormat = DateFormat::new();
+    date_format.precision(DatePrecision::Date);
+    let mut options: WriteOptions = WriteOptions::default();
+    options.date_format(date_format);
+
+    let mut buf: Vec<u8> = Vec::<u8>::new();
+    url_set.write_with_options(&mut buf, &options).unwrap();
+    let actual: String = String::from_utf8(buf).unwrap();
+
+    let expected: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+	<url>
+		<loc>https://www.toddgriffin.me/</loc>
+		<lastmod>1998-01-15</lastmod>
+	</url>
+</urlset>
+"#;
+    assert_eq!(expected, actual);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_time_try_into_date_time() {
+    let offset_date_time: time::OffsetDateTime =
+        time::Date::from_calendar_date(1998, time::Month::January, 15)
+            .unwrap()
+            .with_hms(4, 20, 0)
+            .unwrap()
+            .assume_offset(time::UtcOffset::from_hms(8, 0, 0).unwrap());
+    assert_eq!(date(), offset_date_time.try_into_date_time().unwrap());
+    assert_eq!(
+        "1998-01-15T00:00:00+00:00",
+        DateFormat::new().format(&offset_date_time.date().try_into_date_time().unwrap())
+    );
+
+    // time allows offsets up to 25:59:59, chrono only up to 23:59:59
+    let offset_date_time: time::OffsetDateTime =
+        offset_date_time.replace_offset(time::UtcOffset::from_hms(25, 0, 0).unwrap());
+    assert!(matches!(
+        offset_date_time.try_into_date_time(),
+        Err(DateError::OffsetOutOfRange(90000))
+    ));
+}
+
+#[cfg(feature = "jiff")]
+#[test]
+fn test_jiff_try_into_date_time() {
+    let zoned: jiff::Zoned = jiff::civil::date(1998, 1, 15)
+        .at(4, 20, 0, 0)
+        .to_zoned(jiff::tz::TimeZone::fixed(jiff::tz::offset(8)))
+        .unwrap();
+    assert_eq!(date(), zoned.clone().try_into_date_time().unwrap());
+    assert_eq!(date(), zoned.timestamp().try_into_date_time().unwrap());
+    assert_eq!(
+        "1998-01-15T00:00:00+00:00",
+        DateFormat::new().format(&zoned.date().try_into_date_time().unwrap())
+    );
+
+    // timestamps before the epoch keep their fractional seconds
+    let timestamp: jiff::Timestamp = jiff::Timestamp::new(-1, 500_000_000).unwrap();
+    assert_eq!(
+        "1969-12-31T23:59:59.500+00:00",
+        timestamp
+            .try_into_date_time()
+            .unwrap()
+            .to_rfc3339_opts(chrono::SecondsFormat::Millis, false)
+    );
+
+    // the earliest civil date time has no timestamp in UTC
+    assert!(matches!(
+        jiff::civil::DateTime::MIN.try_into_date_time(),
+        Err(DateError::OutOfRange(_))
+    ));
+    let zoned: jiff::Zoned = zoned.with_time_zone(jiff::tz::TimeZone::fixed(jiff::tz::offset(25)));
+    assert!(matches!(
+        zoned.try_into_date_time(),
+        Err(DateError::OffsetOutOfRange(90000))
+    ));
+}
+
+#[test]
+fn test_parse_w3c_datetime() {
+    let midnight: DateTime<FixedOffset> = NaiveDate::from_ymd_opt(1998, 1, 1)
+        .unwrap()
+        .into_date_time();
+    assert_eq!(Some(midnight), parse_w3c_datetime("1998"));
+    assert_eq!(Some(midnight), parse_w3c_datetime("1998-01"));
+    assert_eq!(Some(midnight), parse_w3c_datetime("1998-01-01"));
+
+    assert_eq!(Some(date()), parse_w3c_datetime("1998-01-15T04:20+08:00"));
+    assert_eq!(
+        Some(date()),
+        parse_w3c_datetime("1998-01-15T04:20:00+08:00")
+    );
+    assert_eq!(
+        Some(date().with_timezone(&Utc).fixed_offset()),
+        parse_w3c_datetime("1998-01-14T20:20Z")
+    );
+    assert!(parse_w3c_datetime("1998-01-15T04:20:00.5+08:00").is_some());
+
+    assert_eq!(None, parse_w3c_datetime("1998-01-15T04:20"));
+    assert_eq!(None, parse_w3c_datetime("01/15/1998"));
+    assert_eq!(None, parse_w3c_datetime("98"));
+}
@@ -0,0 +1,135 @@
+use chrono::{DateTime, FixedOffset};
+use sitemap_rs::diff::{Change, Diff, UrlChanges, diff};
+use sitemap_rs::image::Image;
+use sitemap_rs::url::{ChangeFrequency, Link, Priority, Url};
+
+#[test]
+fn test_diff_same() {
+    let urls: Vec<Url> = vec![
+        Url::builder(String::from("https://www.toddgriffin.me/"))
+            .build()
+            .unwrap(),
+        Url::builder(String::from("https://www.toddgriffin.me/about"))
+            .build()
+            .unwrap(),
+    ];
+    let diff: Diff = diff(&urls, &urls);
+    assert!(diff.is_empty());
+    assert_eq!("", diff.to_string());
+}
+
+#[test]
+fn test_diff_added_and_removed() {
+    let home: Url = Url::builder(String::from("https://www.toddgriffin.me/"))
+        .build()
+        .unwrap();
+    let old: Url = Url::builder(String::from("https://www.toddgriffin.me/old"))
+        .build()
+        .unwrap();
+    let new: Url = Url::builder(String::from("https://www.toddgriffin.me/new"))
+        .build()
+        .unwrap();
+
+    let diff: Diff = diff(&[home.clone(), old.clone()], &[new.clone(), home]);
+    assert_eq!(vec![new], diff.added);
+    assert_eq!(vec![old], diff.removed);
+    assert!(diff.changed.is_empty());
+    assert_eq!(
+        "+ https://www.toddgriffin.me/new\n- https://www.toddgriffin.me/old\n",
+        diff.to_string()
+    );
+}
+
+#[test]
+fn test_diff_changed() {
+    let mut old: Url = Url::builder(String::from("https://www.toddgriffin.me/"))
+        .last_modified(DateTime::parse_from_rfc3339("2024-03-01T00:00:00+00:00").unwrap())
+        .build()
+        .unwrap();
+    old.images = Some(vec![
+        Image::new(String::from("https://www.toddgriffin.me/a.png")),
+        Image::new(String::from("https://www.toddgriffin.me/b.png")),
+    ]);
+    old.links = vec![Link::new(
+        String::from("de"),
+        String::from("https://www.toddgriffin.me/de/"),
+    )];
+
+    let mut new: Url = old.clone();
+    new.last_modified = Some(DateTime::parse_from_rfc3339("2024-03-02T00:00:00+00:00").unwrap());
+    new.change_frequency = Some(ChangeFrequency::Weekly);
+    new.priority = Some(Priority::new(0.8).unwrap());
+    new.images = Some(vec![
+        Image::new(String::from("https://www.toddgriffin.me/b.png")),
+        Image::new(String::from("https://www.toddgriffin.me/c.png")),
+    ]);
+    new.links = vec![
+        Link::new(
+            String::from("de"),
+            String::from("https://www.toddgriffin.me/de/"),
+        ),
+        Link::new(
+            String::from("fr"),
+            String::from("https://www.toddgriffin.me/fr/"),
+        ),
+    ];
+
+    let old_last_modified: Option<DateTime<FixedOffset>> = old.last_modified;
+    let diff: Diff = diff(&[old], std::slice::from_ref(&new));
+    assert!(diff.added.is_empty());
+    assert!(diff.removed.is_empty());
+    assert_eq!(
+        vec![UrlChanges {
+            location: new.location.clone(),
+            changes: vec![
+                Change::LastModified {
+                    old: old_last_modified,
+                    new: new.last_modified,
+                },
+                Change::ChangeFrequency {
+                    old: None,
+                    new: Some(ChangeFrequency::Weekly),
+                },
+                Change::Priority {
+                    old: None,
+                    new: Some(Priority::new(0.8).unwrap()),
+                },
+                Change::Images {
+                    added: vec![Image::new(String::from("https://www.toddgriffin.me/c.png"))],
+                    removed: vec![Image::new(String::from("https://www.toddgriffin.me/a.png"))],
+                },
+                Change::Links {
+                    added: vec![Link::new(
+                        String::from("fr"),
+                        String::from("https://www.toddgriffin.me/fr/"),
+                    )],
+                    removed: vec![],
+                },
+            ],
+        }],
+        diff.changed
+    );
+
+    let expected: &str = "~ https://www.toddgriffin.me/
+    lastmod: 2024-03-01T00:00:00+00:00 -> 2024-03-02T00:00:00+00:00
+    changefreq: - -> weekly
+    priority: - -> 0.8
+    + image https://www.toddgriffin.me/c.png
+    - image https://www.toddgriffin.me/a.png
+    + link fr https://www.toddgriffin.me/fr/
+";
+    assert_eq!(expected, diff.to_string());
+}
+
+#[test]
+fn test_diff_duplicate_locations() {
+    let url: Url = Url::builder(String::from("https://www.toddgriffin.me/"))
+        .build()
+        .unwrap();
+    let mut changed: Url = url.clone();
+    changed.priority = Some(Priority::new(0.8).unwrap());
+
+    // only the first URL of a location is compared
+    let diff: Diff = diff(std::slice::from_ref(&url), &[url.clone(), changed]);
+    assert!(diff.is_empty());
+}
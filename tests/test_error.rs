@@ -0,0 +1,71 @@
+use sitemap_rs::Error;
+use sitemap_rs::sitemap::Sitemap;
+use sitemap_rs::sitemap_index::SitemapIndex;
+use sitemap_rs::url::Url;
+use sitemap_rs::url_error::UrlError;
+use sitemap_rs::url_set::UrlSet;
+use sitemap_rs::url_set_error::UrlSetError;
+use std::error::Error as _;
+use std::io;
+
+fn build_url_set() -> Result<UrlSet, Error> {
+    let url: Url = Url::builder(String::from("https://www.toddgriffin.me/"))
+        .priority(4.69)
+        .build()?;
+    Ok(UrlSet::new(vec![url])?)
+}
+
+#[test]
+fn test_from_module_error() {
+    match build_url_set() {
+        Ok(_) => panic!("Returned a UrlSet!"),
+        Err(e) => {
+            assert!(matches!(e, Error::Url(UrlError::PriorityTooHigh(_))));
+            assert!(e.source().unwrap().is::<UrlError>());
+        }
+    }
+}
+
+#[test]
+fn test_from_url_set_error() {
+    let error: Error = Error::from(UrlSetError::TooManyUrls(50_001));
+    assert_eq!(
+        "invalid URL set: must not contain more than 50,000 URLs: 50001",
+        error.to_string()
+    );
+    assert!(error.source().unwrap().is::<UrlSetError>());
+}
+
+/// A writer that always fails.
+struct BrokenWriter;
+
+impl io::Write for BrokenWriter {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(io::Error::other("disk full"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_write_io_error() {
+    let url_set: UrlSet = UrlSet::new(vec![
+        Url::builder(String::from("https://www.toddgriffin.me/"))
+            .build()
+            .unwrap(),
+    ])
+    .unwrap();
+    assert!(matches!(url_set.write(BrokenWriter), Err(Error::Io(_))));
+
+    let sitemap_index: SitemapIndex = SitemapIndex::new(vec![Sitemap::new(
+        String::from("https://www.toddgriffin.me/sitemap.xml"),
+        None,
+    )])
+    .unwrap();
+    assert!(matches!(
+        sitemap_index.write(BrokenWriter),
+        Err(Error::Io(_))
+    ));
+}
@@ -0,0 +1,195 @@
+use sitemap_rs::extension::{Extension, ExtensionElement, Namespace};
+use sitemap_rs::image::Image;
+use sitemap_rs::url::Url;
+use sitemap_rs::url_set::UrlSet;
+use sitemap_rs::url_set_error::UrlSetError;
+use sitemap_rs::write_options::WriteOptions;
+use sitemap_rs::xml_writer::XmlWriter;
+use std::io;
+
+const PAGEMAP_NAMESPACE: &str = "http://www.google.com/schemas/sitemap-pagemap/1.0";
+
+fn pagemap() -> ExtensionElement {
+    let namespace: Namespace = Namespace::new("pagemap", PAGEMAP_NAMESPACE);
+
+    ExtensionElement::new(namespace.clone(), String::from("PageMap")).child(
+        ExtensionElement::new(namespace.clone(), String::from("DataObject"))
+            .attribute(String::from("type"), String::from("document"))
+            .child(
+                ExtensionElement::new(namespace.clone(), String::from("Attribute"))
+                    .attribute(String::from("name"), String::from("title"))
+                    .text(String::from("Todd & Friends")),
+            )
+            .child(
+                ExtensionElement::new(namespace, String::from("Attribute"))
+                    .attribute(String::from("name"), String::from("draft")),
+            ),
+    )
+}
+
+#[test]
+fn test_write_custom_extension() {
+    let urls: Vec<Url> = vec![
+        Url::builder(String::from("https://www.toddgriffin.me/"))
+            .extensions(vec![pagemap()])
+            .build()
+            .unwrap(),
+        Url::builder(String::from("https://www.toddgriffin.me/about"))
+            .images(vec![Image::new(String::from(
+                "https://www.toddgriffin.me/todd.png",
+            ))])
+            .build()
+            .unwrap(),
+    ];
+
+    let url_set: UrlSet = UrlSet::new(urls).unwrap();
+    let mut buf: Vec<u8> = Vec::<u8>::new();
+    url_set.write(&mut buf).unwrap();
+    let actual: String = String::from_utf8(buf).unwrap();
+
+    // built-in namespaces are declared before custom ones, regardless of which is used first
+    let expected: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" xmlns:image="http://www.google.com/schemas/sitemap-image/1.1" xmlns:pagemap="http://www.google.com/schemas/sitemap-pagemap/1.0">
+	<url>
+		<loc>https://www.toddgriffin.me/</loc>
+		<pagemap:PageMap>
+			<pagemap:DataObject type="document">
+				<pagemap:Attribute name="title">Todd &amp; Friends</pagemap:Attribute>
+				<pagemap:Attribute name="draft" />
+			</pagemap:DataObject>
+		</pagemap:PageMap>
+	</url>
+	<url>
+		<loc>https://www.toddgriffin.me/about</loc>
+		<image:image>
+			<image:loc>https://www.toddgriffin.me/todd.png</image:loc>
+		</image:image>
+	</url>
+</urlset>
+"#;
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_constructor_collects_namespaces() {
+    let url: Url = Url::builder(String::from("https://www.toddgriffin.me/"))
+        .extensions(vec![pagemap(), pagemap()])
+        .build()
+        .unwrap();
+
+    let url_set: UrlSet = UrlSet::new(vec![url]).unwrap();
+    assert_eq!(
+        vec![Namespace::new("pagemap", PAGEMAP_NAMESPACE)],
+        url_set.namespaces
+    );
+}
+
+#[test]
+fn test_constructor_namespace_conflict() {
+    let url: Url = Url::builder(String::from("https://www.toddgriffin.me/"))
+        .images(vec![Image::new(String::from(
+            "https://www.toddgriffin.me/todd.png",
+        ))])
+        .extensions(vec![ExtensionElement::new(
+            Namespace::new("image", "https://example.com/image"),
+            String::from("caption"),
+        )])
+        .build()
+        .unwrap();
+
+    match UrlSet::new(vec![url]) {
+        Ok(_) => panic!("Returned a UrlSet!"),
+        Err(e) => match e {
+            UrlSetError::NamespaceConflict {
+                prefix,
+                uri,
+                other_uri,
+            } => {
+                assert_eq!("image", prefix);
+                assert_eq!("http://www.google.com/schemas/sitemap-image/1.1", uri);
+                assert_eq!("https://example.com/image", other_uri);
+            }
+            e => panic!("Returned {e:?}!"),
+        },
+    }
+}
+
+/// A vendor extension of its own type, rather than an `ExtensionElement`.
+#[derive(Debug)]
+struct Rating {
+    stars: u8,
+    unbalanced: bool,
+}
+
+impl Extension for Rating {
+    fn namespace(&self) -> Namespace {
+        Namespace::new("acme", "https://example.com/schemas/acme/1.0")
+    }
+
+    fn write_xml(&self, writer: &mut XmlWriter<'_>) -> io::Result<()> {
+        writer.text("acme:stars", &[], &self.stars.to_string())?;
+        if self.unbalanced {
+            writer.end("acme:rating")?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_write_own_extension_type() {
+    let url: Url = Url::builder(String::from("https://www.toddgriffin.me/"))
+        .extensions(vec![pagemap()])
+        .extension(Rating {
+            stars: 5,
+            unbalanced: false,
+        })
+        .build()
+        .unwrap();
+    assert_eq!(url, url.clone());
+
+    let url_set: UrlSet = UrlSet::new(vec![url]).unwrap();
+    assert_eq!(
+        vec![
+            Namespace::new("pagemap", PAGEMAP_NAMESPACE),
+            Namespace::new("acme", "https://example.com/schemas/acme/1.0"),
+        ],
+        url_set.namespaces
+    );
+
+    let mut buf: Vec<u8> = Vec::<u8>::new();
+    url_set
+        .write_with_options(&mut buf, &WriteOptions::compact())
+        .unwrap();
+    let actual: String = String::from_utf8(buf).unwrap();
+    assert!(
+        actual.contains("</pagemap:PageMap><acme:stars>5</acme:stars></url>"),
+        "{actual}"
+    );
+}
+
+#[test]
+fn test_write_unbalanced_extension() {
+    let url: Url = Url::builder(String::from("https://www.toddgriffin.me/"))
+        .extension(Rating {
+            stars: 5,
+            unbalanced: true,
+        })
+        .build()
+        .unwrap();
+
+    // an extension that fails to write is compared and debugged by its error
+    assert_eq!(url, url.clone());
+    let debug: String = format!("{:?}", url.extensions[0]);
+    assert!(debug.contains("does not close any element"), "{debug}");
+
+    // within a <url>, the end() closes the wrong element
+    let url_set: UrlSet = UrlSet::new(vec![url]).unwrap();
+    let mut buf: Vec<u8> = Vec::<u8>::new();
+    let error: sitemap_rs::Error = url_set.write(&mut buf).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("</acme:rating> does not close <url>"),
+        "{error}"
+    );
+}
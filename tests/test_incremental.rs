@@ -0,0 +1,223 @@
+use chrono::{DateTime, FixedOffset};
+use sitemap_rs::incremental::{Regeneration, regenerate};
+use sitemap_rs::manifest::Manifest;
+use sitemap_rs::manifest_error::ManifestError;
+use sitemap_rs::news::{News, Publication};
+use sitemap_rs::sitemap_index::SitemapIndex;
+use sitemap_rs::split::{MAX_NEWS_URLS, MAX_URLS};
+use sitemap_rs::url::Url;
+use sitemap_rs::write_options::WriteOptions;
+
+fn changed(regeneration: &Regeneration) -> Vec<usize> {
+    regeneration
+        .sitemaps
+        .iter()
+        .filter(|sitemap| sitemap.changed)
+        .map(|sitemap| sitemap.number)
+        .collect()
+}
+
+#[test]
+fn test_regenerate_first_run() {
+    let last_modified: DateTime<FixedOffset> =
+        DateTime::parse_from_rfc3339("2024-03-01T00:00:00+00:00").unwrap();
+    let urls: Vec<Url> = (0..=MAX_URLS)
+        .map(|index| {
+            Url::builder(format!("https://www.toddgriffin.me/{index}"))
+                .last_modified(last_modified)
+                .build()
+                .unwrap()
+        })
+        .collect();
+
+    let regeneration: Regeneration = regenerate(
+        urls,
+        &Manifest::default(),
+        &WriteOptions::default(),
+        DateTime::parse_from_rfc3339("2024-03-10T00:00:00+00:00").unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(vec![1, 2], changed(&regeneration));
+    assert!(regeneration.removed.is_empty());
+    assert_eq!(MAX_URLS, regeneration.sitemaps[0].url_set.urls.len());
+    assert_eq!(1, regeneration.sitemaps[1].url_set.urls.len());
+    assert_eq!(Some(last_modified), regeneration.sitemaps[0].last_modified);
+
+    let index: SitemapIndex = regeneration
+        .sitemap_index(|number| format!("https://www.toddgriffin.me/sitemap-{number}.xml"))
+        .unwrap();
+    assert_eq!(
+        "https://www.toddgriffin.me/sitemap-2.xml",
+        index.sitemaps[1].location
+    );
+}
+
+#[test]
+fn test_regenerate_only_changed_sitemaps() {
+    let last_modified: DateTime<FixedOffset> =
+        DateTime::parse_from_rfc3339("2024-03-01T00:00:00+00:00").unwrap();
+    let updated_last_modified: DateTime<FixedOffset> =
+        DateTime::parse_from_rfc3339("2024-03-05T00:00:00+00:00").unwrap();
+    let added_last_modified: DateTime<FixedOffset> =
+        DateTime::parse_from_rfc3339("2024-03-06T00:00:00+00:00").unwrap();
+    let removed_at: DateTime<FixedOffset> =
+        DateTime::parse_from_rfc3339("2024-03-13T00:00:00+00:00").unwrap();
+    let urls: Vec<Url> = (0..MAX_URLS * 2)
+        .map(|index| {
+            Url::builder(format!("https://www.toddgriffin.me/{index}"))
+                .last_modified(last_modified)
+                .build()
+                .unwrap()
+        })
+        .collect();
+
+    let first: Regeneration = regenerate(
+        urls.clone(),
+        &Manifest::default(),
+        &WriteOptions::default(),
+        DateTime::parse_from_rfc3339("2024-03-10T00:00:00+00:00").unwrap(),
+    )
+    .unwrap();
+
+    // nothing changed
+    let second: Regeneration = regenerate(
+        urls.clone(),
+        &first.manifest,
+        &WriteOptions::default(),
+        DateTime::parse_from_rfc3339("2024-03-11T00:00:00+00:00").unwrap(),
+    )
+    .unwrap();
+    assert!(changed(&second).is_empty());
+    assert_eq!(first.manifest, second.manifest);
+
+    // one page of the second sitemap was updated, and a new page was added
+    let mut updated: Vec<Url> = urls.clone();
+    updated[MAX_URLS + 5].last_modified = Some(updated_last_modified);
+    updated.insert(
+        0,
+        Url::builder(format!("https://www.toddgriffin.me/{}", MAX_URLS * 2))
+            .last_modified(added_last_modified)
+            .build()
+            .unwrap(),
+    );
+    let third: Regeneration = regenerate(
+        updated,
+        &second.manifest,
+        &WriteOptions::default(),
+        DateTime::parse_from_rfc3339("2024-03-12T00:00:00+00:00").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(vec![2, 3], changed(&third));
+    assert_eq!(Some(last_modified), third.sitemaps[0].last_modified);
+    assert_eq!(Some(updated_last_modified), third.sitemaps[1].last_modified);
+    assert_eq!(Some(added_last_modified), third.sitemaps[2].last_modified);
+    assert_eq!(
+        "https://www.toddgriffin.me/100000",
+        third.sitemaps[2].url_set.urls[0].location
+    );
+
+    // a page was removed, which doesn't make any remaining page newer, so the sitemap is dated now
+    let mut removed: Vec<Url> = urls;
+    removed[MAX_URLS + 5].last_modified = Some(updated_last_modified);
+    removed.remove(0);
+    let fourth: Regeneration = regenerate(
+        removed,
+        &third.manifest,
+        &WriteOptions::default(),
+        removed_at,
+    )
+    .unwrap();
+    assert_eq!(vec![1], changed(&fourth));
+    assert_eq!(Some(removed_at), fourth.sitemaps[0].last_modified);
+    assert_eq!(vec![3], fourth.removed);
+    assert_eq!(2, fourth.sitemaps.len());
+}
+
+#[test]
+fn test_regenerate_news_overflow() {
+    let mut urls: Vec<Url> = (0..=MAX_NEWS_URLS)
+        .map(|index| {
+            Url::builder(format!("https://www.toddgriffin.me/{index}"))
+                .last_modified(DateTime::parse_from_rfc3339("2024-03-01T00:00:00+00:00").unwrap())
+                .build()
+                .unwrap()
+        })
+        .collect();
+    let first: Regeneration = regenerate(
+        urls.clone(),
+        &Manifest::default(),
+        &WriteOptions::default(),
+        DateTime::parse_from_rfc3339("2024-03-10T00:00:00+00:00").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(1, first.sitemaps.len());
+
+    // gaining news lowers the limit of the sitemap, so its last URL moves to a new one
+    urls[0].news = Some(News::new(
+        Publication::new(String::from("News Site"), String::from("en")),
+        DateTime::parse_from_rfc3339("2024-03-02T00:00:00+00:00").unwrap(),
+        String::from("Headline"),
+    ));
+    let second: Regeneration = regenerate(
+        urls,
+        &first.manifest,
+        &WriteOptions::default(),
+        DateTime::parse_from_rfc3339("2024-03-11T00:00:00+00:00").unwrap(),
+    )
+    .unwrap();
+    assert_eq!(vec![1, 2], changed(&second));
+    assert_eq!(MAX_NEWS_URLS, second.sitemaps[0].url_set.urls.len());
+    assert_eq!(
+        "https://www.toddgriffin.me/1000",
+        second.sitemaps[1].url_set.urls[0].location
+    );
+}
+
+#[test]
+fn test_manifest_round_trip() {
+    let regeneration: Regeneration = regenerate(
+        vec![
+            Url::builder(String::from("https://www.toddgriffin.me/0"))
+                .last_modified(DateTime::parse_from_rfc3339("2024-03-01T00:00:00+00:00").unwrap())
+                .build()
+                .unwrap(),
+            Url::builder(String::from("https://www.toddgriffin.me/1"))
+                .last_modified(DateTime::parse_from_rfc3339("2024-03-02T00:00:00+00:00").unwrap())
+                .build()
+                .unwrap(),
+        ],
+        &Manifest::default(),
+        &WriteOptions::default(),
+        DateTime::parse_from_rfc3339("2024-03-10T00:00:00+00:00").unwrap(),
+    )
+    .unwrap();
+
+    let mut buf: Vec<u8> = Vec::<u8>::new();
+    regeneration.manifest.write(&mut buf).unwrap();
+    let text: String = String::from_utf8(buf.clone()).unwrap();
+    assert!(text.starts_with("sitemap-rs manifest 1\nsitemap 1 "));
+    assert!(text.ends_with(
+        " 2024-03-02T00:00:00+00:00\n\thttps://www.toddgriffin.me/0\n\thttps://www.toddgriffin.me/1\n"
+    ));
+
+    assert_eq!(
+        regeneration.manifest,
+        Manifest::read(buf.as_slice()).unwrap()
+    );
+}
+
+#[test]
+fn test_manifest_invalid() {
+    let error: ManifestError = Manifest::read("not a manifest".as_bytes()).unwrap_err();
+    assert_eq!("line 1: not a sitemap-rs manifest", error.to_string());
+
+    let error: ManifestError =
+        Manifest::read("sitemap-rs manifest 1\n\thttps://www.toddgriffin.me/".as_bytes())
+            .unwrap_err();
+    assert_eq!("line 2: URL before any sitemap", error.to_string());
+
+    let error: ManifestError =
+        Manifest::read("sitemap-rs manifest 1\nsitemap 1 xyz -".as_bytes()).unwrap_err();
+    assert_eq!("line 2: hash must be hexadecimal", error.to_string());
+}
@@ -0,0 +1,291 @@
+#![cfg(feature = "indexnow")]
+
+use sitemap_rs::diff::{Diff, diff};
+use sitemap_rs::indexnow::{
+    IndexNow, MAX_URLS_PER_SUBMISSION, Submission, diff_locations, url_set_locations,
+};
+use sitemap_rs::indexnow_error::IndexNowError;
+use sitemap_rs::url::Url;
+use sitemap_rs::url_set::UrlSet;
+use std::cell::RefCell;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+const KEY: &str = "0123456789abcdef";
+
+fn indexnow() -> IndexNow {
+    IndexNow::new(String::from(KEY)).unwrap()
+}
+
+fn url(location: &str) -> Url {
+    Url::builder(location.to_owned()).build().unwrap()
+}
+
+/// A minimal HTTP/1.1 transport for talking to `mock_server`, over plain HTTP without TLS.
+fn http_post(endpoint: &str, body: &str) -> io::Result<u16> {
+    let timeout: Duration = Duration::from_secs(5);
+    let rest: &str = endpoint
+        .strip_prefix("http://")
+        .ok_or_else(|| io::Error::new(io::ErrorKind::Unsupported, endpoint.to_string()))?;
+    let (authority, path): (&str, &str) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    let address: SocketAddr = authority
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, authority.to_string()))?;
+
+    let mut stream: TcpStream = TcpStream::connect_timeout(&address, timeout)?;
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+    write!(
+        stream,
+        "POST {path} HTTP/1.1\r\nHost: {authority}\r\nContent-Type: application/json; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+
+    // such as "HTTP/1.1 202 Accepted"
+    let mut status_line: String = String::new();
+    BufReader::new(stream.take(1024)).read_line(&mut status_line)?;
+    status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|status| status.parse::<u16>().ok())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, status_line.clone()))
+}
+
+/// Answers `statuses.len()` requests with each status in turn, and returns the request line and body of each request.
+fn mock_server(statuses: Vec<u16>) -> (String, JoinHandle<Vec<(String, String)>>) {
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let endpoint: String = format!("http://{}/indexnow", listener.local_addr().unwrap());
+
+    let handle: JoinHandle<Vec<(String, String)>> = thread::spawn(move || {
+        let mut requests: Vec<(String, String)> = vec![];
+        for status in statuses {
+            let (mut stream, _): (TcpStream, _) = listener.accept().unwrap();
+            let mut reader: BufReader<&TcpStream> = BufReader::new(&stream);
+
+            let mut request_line: String = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut length: usize = 0;
+            loop {
+                let mut header: String = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':')
+                    && name.eq_ignore_ascii_case("content-length")
+                {
+                    length = value.trim().parse().unwrap();
+                }
+            }
+            let mut body: Vec<u8> = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+
+            write!(
+                stream,
+                "HTTP/1.1 {status} Status\r\nContent-Length: 0\r\n\r\n"
+            )
+            .unwrap();
+            requests.push((
+                request_line.trim().to_string(),
+                String::from_utf8(body).unwrap(),
+            ));
+        }
+        requests
+    });
+
+    (endpoint, handle)
+}
+
+#[test]
+fn test_key() {
+    assert_eq!("0123456789abcdef.txt", indexnow().key_file_name());
+
+    for key in ["short", "has spaces in it", &"a".repeat(129)] {
+        assert!(matches!(
+            IndexNow::new(key.to_string()),
+            Err(IndexNowError::InvalidKey(_))
+        ));
+    }
+
+    let key: String = IndexNow::generate_key();
+    assert_eq!(32, key.len());
+    assert_ne!(key, IndexNow::generate_key());
+    assert!(IndexNow::new(key).is_ok());
+}
+
+#[test]
+fn test_submissions_grouped_by_host() {
+    let submissions: Vec<Submission> = indexnow()
+        .submissions(vec![
+            String::from("https://www.toddgriffin.me/"),
+            String::from("https://blog.toddgriffin.me/post"),
+            String::from("https://WWW.toddgriffin.me/about"),
+            String::from("https://www.toddgriffin.me/"),
+        ])
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            Submission {
+                host: String::from("www.toddgriffin.me"),
+                key: String::from(KEY),
+                key_location: None,
+                urls: vec![
+                    String::from("https://www.toddgriffin.me/"),
+                    String::from("https://WWW.toddgriffin.me/about"),
+                ],
+            },
+            Submission {
+                host: String::from("blog.toddgriffin.me"),
+                key: String::from(KEY),
+                key_location: None,
+                urls: vec![String::from("https://blog.toddgriffin.me/post")],
+            },
+        ],
+        submissions
+    );
+
+    assert!(matches!(
+        indexnow().submissions(vec![String::from("/relative")]),
+        Err(IndexNowError::InvalidUrl(_))
+    ));
+}
+
+#[test]
+fn test_key_location_per_host() {
+    let mut indexnow: IndexNow = indexnow();
+    indexnow
+        .key_location(String::from("https://www.toddgriffin.me/keys/old.txt"))
+        .key_location(String::from("https://WWW.toddgriffin.me/keys/indexnow.txt"));
+    let submissions: Vec<Submission> = indexnow
+        .submissions(vec![
+            String::from("https://www.toddgriffin.me/"),
+            String::from("https://blog.toddgriffin.me/post"),
+        ])
+        .unwrap();
+
+    // the key location only proves ownership of its own host
+    assert_eq!(2, submissions.len());
+    assert_eq!(
+        Some("https://WWW.toddgriffin.me/keys/indexnow.txt"),
+        submissions[0].key_location.as_deref()
+    );
+    assert_eq!(None, submissions[1].key_location);
+}
+
+#[test]
+fn test_submissions_batched() {
+    let locations: Vec<String> = (0..=MAX_URLS_PER_SUBMISSION)
+        .map(|index| format!("https://www.toddgriffin.me/{index}"))
+        .collect();
+
+    let submissions: Vec<Submission> = indexnow().submissions(locations).unwrap();
+    assert_eq!(2, submissions.len());
+    assert_eq!(MAX_URLS_PER_SUBMISSION, submissions[0].urls.len());
+    assert_eq!(1, submissions[1].urls.len());
+}
+
+#[test]
+fn test_to_json() {
+    let mut indexnow: IndexNow = indexnow();
+    indexnow.key_location(String::from("https://www.toddgriffin.me/keys/indexnow.txt"));
+    let submissions: Vec<Submission> = indexnow
+        .submissions(vec![String::from("https://www.toddgriffin.me/")])
+        .unwrap();
+
+    let json: serde_json::Value = serde_json::from_str(&submissions[0].to_json()).unwrap();
+    assert_eq!(
+        serde_json::json!({
+            "host": "www.toddgriffin.me",
+            "key": KEY,
+            "keyLocation": "https://www.toddgriffin.me/keys/indexnow.txt",
+            "urlList": ["https://www.toddgriffin.me/"],
+        }),
+        json
+    );
+}
+
+#[test]
+fn test_locations() {
+    let old: Vec<Url> = vec![
+        url("https://www.toddgriffin.me/"),
+        url("https://www.toddgriffin.me/old"),
+    ];
+    let new: Vec<Url> = vec![
+        Url::builder(String::from("https://www.toddgriffin.me/"))
+            .priority(0.8)
+            .build()
+            .unwrap(),
+        url("https://www.toddgriffin.me/new"),
+    ];
+
+    let changes: Diff = diff(&old, &new);
+    assert_eq!(
+        vec![
+            "https://www.toddgriffin.me/new",
+            "https://www.toddgriffin.me/old",
+            "https://www.toddgriffin.me/",
+        ],
+        diff_locations(&changes)
+    );
+
+    let url_set: UrlSet = UrlSet::new(new).unwrap();
+    assert_eq!(
+        vec![
+            "https://www.toddgriffin.me/",
+            "https://www.toddgriffin.me/new",
+        ],
+        url_set_locations(&url_set)
+    );
+}
+
+#[test]
+fn test_submit_stops_at_rejection() {
+    let sent: RefCell<Vec<String>> = RefCell::new(vec![]);
+    let transport = |endpoint: &str, body: &str| -> io::Result<u16> {
+        assert_eq!("https://api.indexnow.org/indexnow", endpoint);
+        sent.borrow_mut().push(body.to_string());
+        Ok(if sent.borrow().len() == 1 { 200 } else { 403 })
+    };
+
+    let result: Result<usize, IndexNowError> = indexnow().submit(
+        &transport,
+        vec![
+            String::from("https://www.toddgriffin.me/"),
+            String::from("https://blog.toddgriffin.me/"),
+            String::from("https://shop.toddgriffin.me/"),
+        ],
+    );
+    match result {
+        Err(IndexNowError::Rejected { host, status }) => {
+            assert_eq!("blog.toddgriffin.me", host);
+            assert_eq!(403, status);
+        }
+        result => panic!("Returned {result:?}!"),
+    }
+    assert_eq!(2, sent.borrow().len());
+}
+
+#[test]
+fn test_submit_over_http() {
+    let (endpoint, server): (String, JoinHandle<Vec<(String, String)>>) =
+        mock_server(vec![200, 202]);
+    let locations: Vec<String> = (0..=MAX_URLS_PER_SUBMISSION)
+        .map(|index| format!("https://www.toddgriffin.me/{index}"))
+        .collect();
+
+    let mut indexnow: IndexNow = indexnow();
+    indexnow.endpoint(endpoint);
+    assert_eq!(2, indexnow.submit(&http_post, locations).unwrap());
+
+    let requests: Vec<(String, String)> = server.join().unwrap();
+    assert_eq!("POST /indexnow HTTP/1.1", requests[0].0);
+    let json: serde_json::Value = serde_json::from_str(&requests[1].1).unwrap();
+    assert_eq!(
+        serde_json::json!(["https://www.toddgriffin.me/10000"]),
+        json["urlList"]
+    );
+}
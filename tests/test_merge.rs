@@ -0,0 +1,163 @@
+use chrono::{DateTime, FixedOffset};
+use sitemap_rs::image::Image;
+use sitemap_rs::merge::{Conflict, Merged, Merger, Resolution, normalize_location};
+use sitemap_rs::url::{ChangeFrequency, Link, Priority, Url};
+use sitemap_rs::url_set::UrlSet;
+
+fn duplicates() -> (Url, Url) {
+    let mut first: Url = Url::builder(String::from("https://www.toddgriffin.me/blog"))
+        .last_modified(DateTime::parse_from_rfc3339("2024-03-02T00:00:00+00:00").unwrap())
+        .change_frequency(ChangeFrequency::Monthly)
+        .priority(0.5)
+        .images(vec![
+            Image::new(String::from("https://www.toddgriffin.me/a.png")),
+            Image::new(String::from("https://www.toddgriffin.me/b.png")),
+        ])
+        .links(vec![Link::new(
+            String::from("de"),
+            String::from("https://www.toddgriffin.me/de/blog"),
+        )])
+        .build()
+        .unwrap();
+    first.location = String::from("HTTPS://WWW.toddgriffin.me:443/blog#top");
+
+    let second: Url = Url::builder(String::from("https://www.toddgriffin.me/blog"))
+        .last_modified(DateTime::parse_from_rfc3339("2024-03-01T00:00:00+00:00").unwrap())
+        .change_frequency(ChangeFrequency::Daily)
+        .priority(0.8)
+        .images(vec![
+            Image::new(String::from("https://www.toddgriffin.me/b.png")),
+            Image::new(String::from("https://www.toddgriffin.me/c.png")),
+        ])
+        .links(vec![Link::new(
+            String::from("fr"),
+            String::from("https://www.toddgriffin.me/fr/blog"),
+        )])
+        .build()
+        .unwrap();
+
+    (first, second)
+}
+
+#[test]
+fn test_normalize_location() {
+    assert_eq!(
+        "https://www.toddgriffin.me/",
+        normalize_location("HTTPS://WWW.ToddGriffin.me")
+    );
+    assert_eq!(
+        "http://www.toddgriffin.me/Blog?Page=2",
+        normalize_location("http://www.toddgriffin.me:80/Blog?Page=2#comments")
+    );
+    assert_eq!(
+        "https://www.toddgriffin.me:8443/",
+        normalize_location(" https://www.toddgriffin.me:8443/ ")
+    );
+    assert_eq!(
+        "https://www.toddgriffin.me/?page=2",
+        normalize_location("https://www.toddgriffin.me?page=2")
+    );
+}
+
+#[test]
+fn test_merge_combine() {
+    let (first, second) = duplicates();
+    let last_modified: Option<DateTime<FixedOffset>> = first.last_modified;
+    let other: Url = Url::builder(String::from("https://www.toddgriffin.me/about"))
+        .build()
+        .unwrap();
+
+    let merged: Merged = Merger::new().merge(vec![first, other.clone(), second]);
+    assert_eq!(2, merged.urls.len());
+    assert_eq!(other, merged.urls[1]);
+
+    let url: &Url = &merged.urls[0];
+    assert_eq!("https://www.toddgriffin.me/blog", url.location);
+    assert_eq!(last_modified, url.last_modified);
+    assert_eq!(Some(ChangeFrequency::Daily), url.change_frequency);
+    assert_eq!(Some(0.8), url.priority.map(Priority::get));
+    assert_eq!(
+        Some(vec![
+            Image::new(String::from("https://www.toddgriffin.me/a.png")),
+            Image::new(String::from("https://www.toddgriffin.me/b.png")),
+            Image::new(String::from("https://www.toddgriffin.me/c.png"))
+        ]),
+        url.images
+    );
+    assert_eq!(2, url.links.len());
+
+    assert_eq!(
+        vec![Conflict {
+            location: String::from("https://www.toddgriffin.me/blog"),
+            duplicates: 2,
+            fields: vec!["lastmod", "changefreq", "priority", "images", "links"],
+        }],
+        merged.conflicts
+    );
+    assert_eq!(
+        "https://www.toddgriffin.me/blog: 2 duplicates disagree on lastmod, changefreq, priority, images, links",
+        merged.conflicts[0].to_string()
+    );
+}
+
+#[test]
+fn test_merge_first_and_last() {
+    let (first, second) = duplicates();
+    let last_modified: Option<DateTime<FixedOffset>> = second.last_modified;
+
+    let mut resolutions: Merger = Merger::new();
+    resolutions
+        .last_modified(Resolution::Last)
+        .priority(Resolution::First)
+        .images(Resolution::Last)
+        .links(Resolution::First);
+    let merged: Merged = resolutions.merge(vec![first, second]);
+
+    let url: &Url = &merged.urls[0];
+    assert_eq!(last_modified, url.last_modified);
+    assert_eq!(Some(0.5), url.priority.map(Priority::get));
+    assert_eq!(
+        Some(vec![
+            Image::new(String::from("https://www.toddgriffin.me/b.png")),
+            Image::new(String::from("https://www.toddgriffin.me/c.png"))
+        ]),
+        url.images
+    );
+    assert_eq!("de", url.links[0].hreflang);
+    assert_eq!(1, url.links.len());
+}
+
+#[test]
+fn test_merge_url_sets() {
+    let home: Url = Url::builder(String::from("https://www.toddgriffin.me/"))
+        .build()
+        .unwrap();
+    let blog: Url = Url::builder(String::from("https://www.toddgriffin.me/blog"))
+        .build()
+        .unwrap();
+    let about: Url = Url::builder(String::from("https://www.toddgriffin.me/about"))
+        .build()
+        .unwrap();
+    let url_sets: Vec<UrlSet> = vec![
+        UrlSet::new(vec![home, blog.clone()]).unwrap(),
+        UrlSet::new(vec![blog, about]).unwrap(),
+    ];
+
+    let merged: Merged = Merger::default().merge_url_sets(url_sets);
+    let locations: Vec<&str> = merged
+        .urls
+        .iter()
+        .map(|url| url.location.as_str())
+        .collect();
+    assert_eq!(
+        vec![
+            "https://www.toddgriffin.me/",
+            "https://www.toddgriffin.me/blog",
+            "https://www.toddgriffin.me/about",
+        ],
+        locations
+    );
+
+    // identical duplicates aren't conflicts
+    assert!(merged.conflicts.is_empty());
+}
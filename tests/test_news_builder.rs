@@ -0,0 +1,44 @@
+use chrono::NaiveDate;
+#[cfg(feature = "jiff")]
+use sitemap_rs::date_error::DateError;
+use sitemap_rs::news::{News, Publication};
+
+#[test]
+fn test_builder() {
+    let publication: Publication =
+        Publication::builder(String::from("The Todd Times"), String::from("de"))
+            .language(String::from("en"))
+            .build();
+
+    let news: News = News::builder(
+        publication,
+        NaiveDate::from_ymd_opt(1998, 1, 15).unwrap(),
+        String::from("Draft"),
+    )
+    .title(String::from("Companies A, B in Merger Talks"))
+    .build()
+    .unwrap();
+
+    assert_eq!("The Todd Times", news.publication.name);
+    assert_eq!("en", news.publication.language);
+    assert_eq!(
+        "1998-01-15T00:00:00+00:00",
+        news.publication_date.to_rfc3339()
+    );
+    assert_eq!("Companies A, B in Merger Talks", news.title);
+}
+
+#[cfg(feature = "jiff")]
+#[test]
+fn test_publication_date_out_of_range() {
+    let publication: Publication =
+        Publication::builder(String::from("The Todd Times"), String::from("en")).build();
+
+    let result: Result<News, DateError> = News::builder(
+        publication,
+        jiff::civil::DateTime::MIN,
+        String::from("Companies A, B in Merger Talks"),
+    )
+    .build();
+    assert!(matches!(result, Err(DateError::OutOfRange(_))));
+}
@@ -0,0 +1,284 @@
+#![cfg(feature = "parse")]
+
+use sitemap_rs::parse::{Document, Parsed, Problem, parse};
+use sitemap_rs::parse_error::ParseError;
+
+fn problems(xml: &str) -> Vec<String> {
+    parse(xml.as_bytes())
+        .unwrap()
+        .problems
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+fn write(document: &Document) -> String {
+    let mut buf: Vec<u8> = Vec::<u8>::new();
+    match document {
+        Document::UrlSet(url_set) => url_set.write(&mut buf).unwrap(),
+        Document::SitemapIndex(sitemap_index) => sitemap_index.write(&mut buf).unwrap(),
+    }
+    String::from_utf8(buf).unwrap()
+}
+
+#[test]
+fn test_parse_all_fields() {
+    let xml: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" xmlns:xhtml="http://www.w3.org/1999/xhtml" xmlns:image="http://www.google.com/schemas/sitemap-image/1.1" xmlns:video="http://www.google.com/schemas/sitemap-video/1.1" xmlns:news="http://www.google.com/schemas/sitemap-news/0.9">
+	<url>
+		<loc>https://www.toddgriffin.me/</loc>
+		<xhtml:link rel="alternate" hreflang="de" href="https://www.toddgriffin.me/de" />
+		<lastmod>1998-01-15T04:20:00+00:00</lastmod>
+		<changefreq>monthly</changefreq>
+		<priority>0.7</priority>
+		<image:image>
+			<image:loc>https://www.toddgriffin.me/picture.webp</image:loc>
+		</image:image>
+		<video:video>
+			<video:thumbnail_loc>https://www.toddgriffin.me/thumbs/123.jpg</video:thumbnail_loc>
+			<video:title>Grilling steaks for summer</video:title>
+			<video:description>Alkis shows you how to get perfectly done steaks every time</video:description>
+			<video:content_loc>https://www.toddgriffin.me/video123.mp4</video:content_loc>
+			<video:player_loc>https://www.toddgriffin.me/videoplayer.php?video=123</video:player_loc>
+			<video:duration>600</video:duration>
+			<video:expiration_date>2021-11-05T19:20:30+08:00</video:expiration_date>
+			<video:rating>4.2</video:rating>
+			<video:view_count>8633</video:view_count>
+			<video:publication_date>1998-01-15T12:20:00+08:00</video:publication_date>
+			<video:family_friendly>yes</video:family_friendly>
+			<video:restriction relationship="allow">CA GB IE US</video:restriction>
+			<video:platform relationship="allow">tv web</video:platform>
+			<video:requires_subscription>yes</video:requires_subscription>
+			<video:uploader info="https://www.toddgriffin.me/users/grillymcgrillerson">GrillyMcGrillserson</video:uploader>
+			<video:live>no</video:live>
+			<video:tag>steak</video:tag>
+			<video:tag>meat</video:tag>
+			<video:tag>summer</video:tag>
+			<video:tag>outdoor</video:tag>
+		</video:video>
+		<news:news>
+			<news:publication>
+				<news:name>The Example Times</news:name>
+				<news:language>en</news:language>
+			</news:publication>
+			<news:publication_date>1998-01-15T04:20:00+00:00</news:publication_date>
+			<news:title>Companies A, B in Merger Talks</news:title>
+		</news:news>
+	</url>
+</urlset>
+"#;
+
+    let parsed: Parsed = parse(xml.as_bytes()).unwrap();
+    assert_eq!(Vec::<Problem>::new(), parsed.problems);
+    assert_eq!(xml, write(&parsed.document));
+}
+
+#[test]
+fn test_parse_sitemap_index() {
+    let xml: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<?xml-stylesheet type="text/xsl" href="/sitemap.xsl"?>
+<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+	<sitemap>
+		<loc>https://www.toddgriffin.me/sitemap1.xml.gz</loc>
+		<lastmod>2004-10-01T18:23:17+00:00</lastmod>
+	</sitemap>
+	<sitemap>
+		<loc>https://www.toddgriffin.me/sitemap2.xml.gz</loc>
+	</sitemap>
+</sitemapindex>
+"#;
+
+    let parsed: Parsed = parse(xml.as_bytes()).unwrap();
+    assert_eq!(Vec::<Problem>::new(), parsed.problems);
+    assert_eq!(xml, write(&parsed.document));
+}
+
+#[test]
+fn test_parse_unescapes_stylesheet() {
+    let xml: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<?xml-stylesheet type="text/xsl" href="/sitemap.xsl?a=1&amp;b=2"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+	<url>
+		<loc>https://www.toddgriffin.me/</loc>
+	</url>
+</urlset>
+"#;
+
+    let parsed: Parsed = parse(xml.as_bytes()).unwrap();
+    match &parsed.document {
+        Document::UrlSet(url_set) => {
+            assert_eq!(Some("/sitemap.xsl?a=1&b=2"), url_set.stylesheet.as_deref());
+        }
+        Document::SitemapIndex(_) => panic!("expected a url set"),
+    }
+    assert_eq!(xml, write(&parsed.document));
+}
+
+#[test]
+fn test_parse_keeps_custom_extensions() {
+    let xml: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" xmlns:pagemap="http://www.google.com/schemas/sitemap-pagemap/1.0">
+	<url>
+		<loc>https://www.toddgriffin.me/?a=1&amp;b=2</loc>
+		<pagemap:PageMap>
+			<pagemap:DataObject type="document">
+				<pagemap:Attribute name="title">Todd &amp; Friends</pagemap:Attribute>
+			</pagemap:DataObject>
+		</pagemap:PageMap>
+	</url>
+</urlset>
+"#;
+
+    let parsed: Parsed = parse(xml.as_bytes()).unwrap();
+    assert_eq!(Vec::<Problem>::new(), parsed.problems);
+    assert_eq!(xml, write(&parsed.document));
+}
+
+#[test]
+fn test_parse_reports_every_problem() {
+    let xml: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" xmlns:image="http://www.google.com/schemas/sitemap-image/1.0" xmlns:video="http://www.google.com/schemas/sitemap-video/1.1">
+	<url>
+		<loc>/about</loc>
+		<lastmod>yesterday</lastmod>
+		<changefreq>fortnightly</changefreq>
+		<priority>4.2</priority>
+		<image:image>
+			<image:loc>https://www.toddgriffin.me/picture.webp</image:loc>
+		</image:image>
+	</url>
+	<url>
+		<lastmod>1998-01</lastmod>
+		<title>About</title>
+		<video:video>
+			<video:thumbnail_loc>https://www.toddgriffin.me/thumbs/123.jpg</video:thumbnail_loc>
+			<video:description>Alkis shows you how to get perfectly done steaks every time</video:description>
+			<video:duration>0</video:duration>
+			<video:family_friendly>maybe</video:family_friendly>
+		</video:video>
+	</url>
+</urlset>
+"#;
+
+    let expected: Vec<&str> = vec![
+        "line 4: urls[0].location: location must be an absolute http or https URL: /about",
+        "line 5: urls[0].last_modified: must be a W3C Datetime: yesterday",
+        "line 6: urls[0].change_frequency: change frequency must be one of always, hourly, daily, weekly, monthly, yearly, or never: fortnightly",
+        "line 7: urls[0].priority: priority must not be above 1.0: 4.2",
+        "line 8: urls[0]: <image:image> must be in the namespace http://www.google.com/schemas/sitemap-image/1.1: http://www.google.com/schemas/sitemap-image/1.0",
+        "line 12: urls[1].location: <loc> is required",
+        "line 14: urls[1]: unexpected element <title>",
+        "line 15: urls[1].videos[0].title: <video:title> is required",
+        "line 15: urls[1].videos[0].content_location: <video:content_loc> or <video:player_loc> is required",
+        "line 18: urls[1].videos[0].duration: duration is below 1 (second): 0",
+        "line 19: urls[1].videos[0].family_friendly: must be yes or no: maybe",
+    ];
+    assert_eq!(expected, problems(xml));
+}
+
+#[test]
+fn test_parse_reports_library_rules() {
+    let location: String = format!("https://www.toddgriffin.me/{}", "a".repeat(2048));
+    let xml: String = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+	<sitemap>
+		<loc>{location}</loc>
+	</sitemap>
+</sitemapindex>
+"#
+    );
+
+    let problems: Vec<String> = problems(&xml);
+    assert_eq!(1, problems.len());
+    assert!(
+        problems[0].starts_with(
+            "line 3: sitemaps[0].location: location must be less than 2,048 characters"
+        )
+    );
+}
+
+#[test]
+fn test_parse_wrong_namespace() {
+    let xml: &str = r#"<urlset xmlns="http://www.google.com/schemas/sitemap/0.84">
+	<url>
+		<loc>https://www.toddgriffin.me/</loc>
+	</url>
+</urlset>"#;
+
+    assert_eq!(
+        vec![
+            "line 1: xmlns: root element must be in the sitemap namespace http://www.sitemaps.org/schemas/sitemap/0.9: http://www.google.com/schemas/sitemap/0.84"
+        ],
+        problems(xml)
+    );
+}
+
+#[test]
+fn test_parse_undeclared_prefix() {
+    let xml: &str = r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+	<url>
+		<loc>https://www.toddgriffin.me/</loc>
+		<image:image>
+			<image:loc>https://www.toddgriffin.me/picture.webp</image:loc>
+		</image:image>
+	</url>
+</urlset>"#;
+
+    assert_eq!(
+        vec!["line 4: urls[0]: namespace prefix 'image' must be declared"],
+        problems(xml)
+    );
+}
+
+#[test]
+fn test_parse_malformed_xml() {
+    let xml: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+	<url>
+		<loc>https://www.toddgriffin.me/</location>
+	</url>
+</urlset>"#;
+
+    match parse(xml.as_bytes()) {
+        Err(ParseError::Xml { line, .. }) => assert_eq!(4, line),
+        other => panic!("expected malformed XML: {other:?}"),
+    }
+}
+
+#[test]
+fn test_parse_second_root() {
+    let xml: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+	<url>
+		<loc>https://www.toddgriffin.me/</loc>
+	</url>
+</urlset>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" />"#;
+
+    match parse(xml.as_bytes()) {
+        Err(ParseError::Xml { line, .. }) => assert_eq!(7, line),
+        other => panic!("expected malformed XML: {other:?}"),
+    }
+}
+
+#[test]
+fn test_parse_too_large() {
+    // the reader never ends, so this only returns if reading stops at the limit
+    let reader = std::io::repeat(b' ');
+
+    match parse(reader) {
+        Err(ParseError::TooLarge) => {}
+        other => panic!("expected too large: {other:?}"),
+    }
+}
+
+#[test]
+fn test_parse_not_a_sitemap() {
+    let xml: &str = "<html><body /></html>";
+
+    match parse(xml.as_bytes()) {
+        Err(ParseError::NotASitemap(name)) => assert_eq!("html", name),
+        other => panic!("expected not a sitemap: {other:?}"),
+    }
+}
@@ -0,0 +1,82 @@
+use sitemap_rs::resolve::{LocalResolver, SitemapKind, SitemapResolver};
+use std::fs;
+use std::path::PathBuf;
+
+const BASE_URL: &str = "https://www.toddgriffin.me/sitemaps/";
+
+fn resolver_dir(name: &str) -> PathBuf {
+    let path: PathBuf =
+        std::env::temp_dir().join(format!("sitemap-rs-resolve-{}-{name}", std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    fs::create_dir_all(&path).unwrap();
+    path
+}
+
+#[test]
+fn test_local_resolver() {
+    let dir: PathBuf = resolver_dir("local");
+    fs::write(
+        dir.join("sitemap-1.xml"),
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<?xml-stylesheet type="text/xsl" href="sitemap.xsl"?>
+<!-- <sitemapindex> -->
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9"></urlset>"#,
+    )
+    .unwrap();
+    fs::write(
+        dir.join("sitemap.xml"),
+        r#"<sm:sitemapindex xmlns:sm="http://www.sitemaps.org/schemas/sitemap/0.9"/>"#,
+    )
+    .unwrap();
+    fs::write(dir.join("feed.rss"), "<rss></rss>").unwrap();
+
+    let resolver: LocalResolver = LocalResolver::new(dir, String::from(BASE_URL));
+    assert_eq!(
+        SitemapKind::UrlSet,
+        resolver.resolve("https://www.toddgriffin.me/sitemaps/sitemap-1.xml")
+    );
+    assert_eq!(
+        SitemapKind::SitemapIndex,
+        resolver.resolve("https://WWW.toddgriffin.me/sitemaps/sitemap.xml?v=2")
+    );
+    assert_eq!(
+        SitemapKind::Unknown,
+        resolver.resolve("https://www.toddgriffin.me/sitemaps/feed.rss")
+    );
+    assert_eq!(
+        SitemapKind::Unknown,
+        resolver.resolve("https://www.toddgriffin.me/sitemaps/missing.xml")
+    );
+    assert_eq!(
+        SitemapKind::Unknown,
+        resolver.resolve("https://www.toddgriffin.me/sitemaps/../sitemaps/sitemap.xml")
+    );
+    assert_eq!(
+        SitemapKind::Unknown,
+        resolver.resolve("https://www.toddgriffin.me/sitemaps-old/sitemap.xml")
+    );
+    assert_eq!(
+        SitemapKind::Unknown,
+        resolver.resolve("https://cdn.toddgriffin.me/sitemaps/sitemap.xml")
+    );
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn test_local_resolver_gzip() {
+    use std::io::Write;
+
+    let dir: PathBuf = resolver_dir("gzip");
+    let mut encoder: flate2::write::GzEncoder<Vec<u8>> =
+        flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+    encoder
+        .write_all(br#"<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9"/>"#)
+        .unwrap();
+    fs::write(dir.join("sitemap.xml.gz"), encoder.finish().unwrap()).unwrap();
+
+    let resolver: LocalResolver = LocalResolver::new(dir, String::from(BASE_URL));
+    assert_eq!(
+        SitemapKind::SitemapIndex,
+        resolver.resolve("https://www.toddgriffin.me/sitemaps/sitemap.xml.gz")
+    );
+}
@@ -0,0 +1,50 @@
+use chrono::NaiveDate;
+#[cfg(feature = "time")]
+use sitemap_rs::date_error::DateError;
+use sitemap_rs::sitemap::Sitemap;
+use sitemap_rs::sitemap_index_error::SitemapIndexError;
+
+#[test]
+fn test_builder() {
+    let sitemap: Sitemap =
+        Sitemap::builder(String::from("https://www.toddgriffin.me/sitemap1.xml"))
+            .last_modified(NaiveDate::from_ymd_opt(1998, 1, 15).unwrap())
+            .build()
+            .unwrap();
+
+    assert_eq!("https://www.toddgriffin.me/sitemap1.xml", sitemap.location);
+    assert_eq!(
+        "1998-01-15T00:00:00+00:00",
+        sitemap.last_modified.unwrap().to_rfc3339()
+    );
+}
+
+#[test]
+fn test_builder_location_too_long() {
+    match Sitemap::builder("a".repeat(2048)).build() {
+        Ok(_) => panic!("Returned a Sitemap!"),
+        Err(e) => match e {
+            SitemapIndexError::LocationTooLong { characters, .. } => assert_eq!(2048, characters),
+            e => panic!("Returned {e}!"),
+        },
+    }
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_last_modified_out_of_range() {
+    let offset_date_time: time::OffsetDateTime = time::OffsetDateTime::UNIX_EPOCH
+        .replace_offset(time::UtcOffset::from_hms(25, 0, 0).unwrap());
+
+    let result: Result<Sitemap, SitemapIndexError> =
+        Sitemap::builder(String::from("https://www.toddgriffin.me/sitemap1.xml"))
+            .last_modified(offset_date_time)
+            .build();
+    assert!(matches!(
+        result,
+        Err(SitemapIndexError::Date {
+            field: "last_modified",
+            error: DateError::OffsetOutOfRange(90000),
+        })
+    ));
+}
@@ -1,6 +1,7 @@
 extern crate core;
 
 use chrono::{DateTime, FixedOffset, NaiveDate};
+use sitemap_rs::resolve::SitemapKind;
 use sitemap_rs::sitemap::Sitemap;
 use sitemap_rs::sitemap_index::SitemapIndex;
 use sitemap_rs::sitemap_index_error::SitemapIndexError;
@@ -60,10 +61,57 @@ fn test_constructor_too_many_sitemaps() {
         Ok(_) => panic!("Returned a SitemapIndex!"),
         Err(e) => match e {
             SitemapIndexError::TooManySitemaps(count) => assert_eq!(50_001, count),
+            SitemapIndexError::LocationTooLong { .. } => panic!("Returned LocationTooLong!"),
+            e => panic!("Returned {e}!"),
         },
     }
 }
 
+#[test]
+fn test_constructor_location_too_long() {
+    let sitemaps: Vec<Sitemap> = vec![Sitemap::new("t".repeat(2048), None)];
+
+    let sitemap_index_result: Result<SitemapIndex, SitemapIndexError> = SitemapIndex::new(sitemaps);
+    match sitemap_index_result {
+        Ok(_) => panic!("Returned a SitemapIndex!"),
+        Err(e) => match e {
+            SitemapIndexError::TooManySitemaps(_) => panic!("Returned TooManySitemaps!"),
+            SitemapIndexError::LocationTooLong {
+                characters, bytes, ..
+            } => {
+                assert_eq!(2048, characters);
+                assert_eq!(2048, bytes);
+            }
+            e => panic!("Returned {e}!"),
+        },
+    }
+}
+
+#[test]
+fn test_lenient_constructor_skips_invalid_sitemaps() {
+    let sitemaps: Vec<Sitemap> = vec![
+        Sitemap::new(
+            String::from("https://www.toddgriffin.me/sitemap1.xml"),
+            None,
+        ),
+        Sitemap::new("t".repeat(2048), None),
+        Sitemap::new(
+            String::from("https://www.toddgriffin.me/sitemap2.xml"),
+            None,
+        ),
+    ];
+
+    let (sitemap_index, rejected) = SitemapIndex::new_lenient(sitemaps).unwrap();
+    assert_eq!(2, sitemap_index.sitemaps.len());
+    assert_eq!(1, rejected.len());
+    assert_eq!(1, rejected[0].index);
+    assert_eq!("t".repeat(2048), rejected[0].item.location);
+    assert!(matches!(
+        rejected[0].errors[..],
+        [SitemapIndexError::LocationTooLong { .. }]
+    ));
+}
+
 #[test]
 fn test_write() {
     let sitemaps: Vec<Sitemap> = vec![Sitemap::new(
@@ -76,3 +124,110 @@ fn test_write() {
     let mut buf = Vec::<u8>::new();
     sitemap_index.write(&mut buf).unwrap();
 }
+
+#[test]
+fn test_write_borrows_sitemap_index() {
+    let sitemaps: Vec<Sitemap> = vec![Sitemap::new(
+        String::from("https://www.toddgriffin.me/sitemap.xml"),
+        None,
+    )];
+    let sitemap_index: SitemapIndex = SitemapIndex::new(sitemaps).unwrap();
+
+    let mut first: Vec<u8> = Vec::<u8>::new();
+    sitemap_index.write(&mut first).unwrap();
+    let mut second: Vec<u8> = Vec::<u8>::new();
+    sitemap_index.write(&mut second).unwrap();
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_write_stylesheet() {
+    let mut sitemap_index: SitemapIndex = SitemapIndex::new(vec![Sitemap::new(
+        String::from("https://www.toddgriffin.me/sitemap.xml"),
+        None,
+    )])
+    .unwrap();
+    sitemap_index.stylesheet = Some(String::from("/sitemapindex.xsl"));
+
+    let mut buf: Vec<u8> = Vec::<u8>::new();
+    sitemap_index.write(&mut buf).unwrap();
+    let actual: String = String::from_utf8(buf).unwrap();
+
+    let expected: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<?xml-stylesheet type="text/xsl" href="/sitemapindex.xsl"?>
+<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+	<sitemap>
+		<loc>https://www.toddgriffin.me/sitemap.xml</loc>
+	</sitemap>
+</sitemapindex>
+"#;
+    assert_eq!(expected, actual);
+}
+
+fn resolve(location: &str) -> SitemapKind {
+    if location.ends_with("sitemap-index.xml") {
+        SitemapKind::SitemapIndex
+    } else {
+        SitemapKind::UrlSet
+    }
+}
+
+#[test]
+fn test_resolved_constructor_rejects_nested_index() {
+    let sitemaps: Vec<Sitemap> = vec![
+        Sitemap::new(
+            String::from("https://www.toddgriffin.me/sitemap-1.xml"),
+            None,
+        ),
+        Sitemap::new(
+            String::from("https://www.toddgriffin.me/sitemap-index.xml"),
+            None,
+        ),
+    ];
+
+    match SitemapIndex::new_resolved(sitemaps, &resolve) {
+        Err(SitemapIndexError::NestedIndex(location)) => {
+            assert_eq!("https://www.toddgriffin.me/sitemap-index.xml", location);
+        }
+        result => panic!("Returned {result:?}!"),
+    }
+
+    let sitemaps: Vec<Sitemap> = vec![Sitemap::new(
+        String::from("https://www.toddgriffin.me/sitemap-1.xml"),
+        None,
+    )];
+    assert!(SitemapIndex::new_resolved(sitemaps, &resolve).is_ok());
+}
+
+#[test]
+fn test_check_references() {
+    let sitemap_index: SitemapIndex = SitemapIndex::new(vec![
+        Sitemap::new(
+            String::from("https://WWW.toddgriffin.me:443/sitemap-1.xml"),
+            None,
+        ),
+        Sitemap::new(
+            String::from("https://www.toddgriffin.me/sitemap-index.xml"),
+            None,
+        ),
+        Sitemap::new(
+            String::from("https://cdn.toddgriffin.me/sitemap-2.xml"),
+            None,
+        ),
+    ])
+    .unwrap();
+
+    let errors: Vec<SitemapIndexError> =
+        sitemap_index.check_references("https://www.toddgriffin.me/sitemap.xml", &resolve);
+    assert_eq!(2, errors.len());
+    assert!(matches!(
+        &errors[0],
+        SitemapIndexError::NestedIndex(location) if location == "https://www.toddgriffin.me/sitemap-index.xml"
+    ));
+    assert_eq!(
+        "location should be on the same host as the sitemap index (www.toddgriffin.me): https://cdn.toddgriffin.me/sitemap-2.xml",
+        errors[1].to_string()
+    );
+    assert_eq!("location", errors[1].field());
+}
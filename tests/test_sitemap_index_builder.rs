@@ -0,0 +1,48 @@
+use sitemap_rs::sitemap::Sitemap;
+use sitemap_rs::sitemap_index::SitemapIndex;
+use sitemap_rs::sitemap_index_builder::SitemapIndexBuilder;
+use sitemap_rs::sitemap_index_error::SitemapIndexError;
+
+#[test]
+fn test_builder() {
+    let mut builder: SitemapIndexBuilder = SitemapIndex::builder();
+    builder
+        .stylesheet(String::from("/sitemap-index.xsl"))
+        .push(Sitemap::new(
+            String::from("https://www.toddgriffin.me/sitemap1.xml.gz"),
+            None,
+        ))
+        .unwrap();
+
+    let mut buf: Vec<u8> = Vec::<u8>::new();
+    builder.build().write(&mut buf).unwrap();
+    let actual: String = String::from_utf8(buf).unwrap();
+
+    let expected: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<?xml-stylesheet type="text/xsl" href="/sitemap-index.xsl"?>
+<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+	<sitemap>
+		<loc>https://www.toddgriffin.me/sitemap1.xml.gz</loc>
+	</sitemap>
+</sitemapindex>
+"#;
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_push_rejects_invalid_sitemap() {
+    let mut builder: SitemapIndexBuilder = SitemapIndexBuilder::new();
+
+    let rejected = builder
+        .push(Sitemap::new("a".repeat(2048), None))
+        .unwrap_err();
+    assert_eq!(0, rejected.index);
+    assert!(matches!(
+        rejected.errors[0],
+        SitemapIndexError::LocationTooLong {
+            characters: 2048,
+            ..
+        }
+    ));
+    assert!(builder.is_empty());
+}
@@ -0,0 +1,89 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use sitemap_rs::date::IntoDateTime;
+use sitemap_rs::news::{News, Publication};
+use sitemap_rs::sitemap_index::SitemapIndex;
+use sitemap_rs::split::{MAX_NEWS_URLS, MAX_URLS, sitemap_index, split_urls};
+use sitemap_rs::url::Url;
+use sitemap_rs::url_set::UrlSet;
+
+fn url() -> Url {
+    Url::builder(String::from("https://www.toddgriffin.me/"))
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_split_fits_in_one() {
+    let url_sets: Vec<UrlSet> = split_urls(vec![url(); 3]).unwrap();
+    assert_eq!(1, url_sets.len());
+    assert_eq!(3, url_sets[0].urls.len());
+}
+
+#[test]
+fn test_split_empty() {
+    let url_sets: Vec<UrlSet> = split_urls(vec![]).unwrap();
+    assert_eq!(1, url_sets.len());
+    assert!(url_sets[0].urls.is_empty());
+}
+
+#[test]
+fn test_split_too_many_urls() {
+    let url_sets: Vec<UrlSet> = split_urls(vec![url(); MAX_URLS * 2 + 1]).unwrap();
+    let lengths: Vec<usize> = url_sets.iter().map(|url_set| url_set.urls.len()).collect();
+    assert_eq!(vec![MAX_URLS, MAX_URLS, 1], lengths);
+}
+
+#[test]
+fn test_split_news() {
+    let news_url: Url = Url::builder(String::from("https://www.toddgriffin.me/news"))
+        .news(News::new(
+            Publication::new(String::from("The Todd Times"), String::from("en")),
+            Utc::now(),
+            String::from("Todd Splits Sitemaps"),
+        ))
+        .build()
+        .unwrap();
+
+    // plain URLs fill a set until news shows up, then the set is capped at 1,000
+    let mut urls: Vec<Url> = vec![url(); 1500];
+    urls.push(news_url.clone());
+    urls.extend(vec![news_url; MAX_NEWS_URLS]);
+
+    let url_sets: Vec<UrlSet> = split_urls(urls).unwrap();
+    let lengths: Vec<usize> = url_sets.iter().map(|url_set| url_set.urls.len()).collect();
+    assert_eq!(vec![1500, MAX_NEWS_URLS, 1], lengths);
+}
+
+#[test]
+fn test_sitemap_index() {
+    let latest: DateTime<FixedOffset> = NaiveDate::from_ymd_opt(2000, 1, 31)
+        .unwrap()
+        .into_date_time();
+    let urls: Vec<Url> = vec![
+        Url::builder(String::from("https://www.toddgriffin.me/"))
+            .last_modified(NaiveDate::from_ymd_opt(1998, 1, 15).unwrap())
+            .build()
+            .unwrap(),
+        Url::builder(String::from("https://www.toddgriffin.me/about"))
+            .last_modified(latest)
+            .build()
+            .unwrap(),
+    ];
+    let url_sets: Vec<UrlSet> = vec![
+        UrlSet::new(urls).unwrap(),
+        UrlSet::new(vec![url()]).unwrap(),
+    ];
+
+    let index: SitemapIndex = sitemap_index(&url_sets, |index| {
+        format!("https://www.toddgriffin.me/sitemap-{}.xml", index + 1)
+    })
+    .unwrap();
+
+    assert_eq!(2, index.sitemaps.len());
+    assert_eq!(
+        "https://www.toddgriffin.me/sitemap-1.xml",
+        index.sitemaps[0].location
+    );
+    assert_eq!(Some(latest), index.sitemaps[0].last_modified);
+    assert_eq!(None, index.sitemaps[1].last_modified);
+}
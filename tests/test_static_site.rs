@@ -0,0 +1,307 @@
+use chrono::{DateTime, TimeZone, Utc};
+use sitemap_rs::image::Image;
+use sitemap_rs::static_site::{StaticSite, StaticSiteWarning};
+use sitemap_rs::url::Url;
+use sitemap_rs::url_error::UrlError;
+use sitemap_rs::video::Video;
+#[cfg(feature = "json-ld")]
+use sitemap_rs::video::VideoDuration;
+use std::fmt::Write;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+const BASE_URL: &str = "https://www.toddgriffin.me";
+
+fn site_dir(name: &str) -> PathBuf {
+    let path: PathBuf = std::env::temp_dir().join(format!(
+        "sitemap-rs-static-site-{}-{name}",
+        std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&path);
+    fs::create_dir_all(&path).unwrap();
+    path
+}
+
+fn page(root: &Path, path: &str, head: &str, modified: DateTime<Utc>) {
+    let path: PathBuf = root.join(path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(
+        &path,
+        format!("<!DOCTYPE html>\n<html>\n<head>{head}</head>\n<body></body>\n</html>\n"),
+    )
+    .unwrap();
+    File::options()
+        .write(true)
+        .open(&path)
+        .unwrap()
+        .set_modified(SystemTime::from(modified))
+        .unwrap();
+}
+
+fn build_site(root: &Path) {
+    let modified: DateTime<Utc> = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+    page(root, "index.html", "<title>Home</title>", modified);
+    page(root, "about.html", "", modified);
+    page(root, "blog/index.html", "", modified);
+    page(root, "blog/my post.html", "", modified);
+    page(root, "404.html", "", modified);
+    page(root, ".hidden/secret.html", "", modified);
+    page(
+        root,
+        "drafts.html",
+        r#"<meta name="robots" content="noindex, nofollow">"#,
+        modified,
+    );
+    page(
+        root,
+        "blog/page/1/index.html",
+        r#"<link rel="canonical" href="../../">"#,
+        Utc.with_ymd_and_hms(2024, 4, 1, 12, 0, 0).unwrap(),
+    );
+    page(
+        root,
+        "mirror.html",
+        r#"<link rel="canonical" href="https://example.com/mirror">"#,
+        modified,
+    );
+    page(
+        root,
+        "lookalike.html",
+        r#"<link rel="canonical" href="https://www.toddgriffin.me.example.com/lookalike">"#,
+        modified,
+    );
+    fs::write(root.join("style.css"), "body {}").unwrap();
+}
+
+fn locations(urls: &[Url]) -> Vec<&str> {
+    urls.iter().map(|url| url.location.as_str()).collect()
+}
+
+#[test]
+fn test_static_site() {
+    let root: PathBuf = site_dir("default");
+    build_site(&root);
+
+    let urls: Vec<Url> = StaticSite::new(root, String::from(BASE_URL))
+        .urls()
+        .unwrap();
+    assert_eq!(
+        vec![
+            "https://www.toddgriffin.me/about.html",
+            "https://www.toddgriffin.me/blog/",
+            "https://www.toddgriffin.me/blog/my%20post.html",
+            "https://www.toddgriffin.me/",
+        ],
+        locations(&urls)
+    );
+
+    // blog/page/1/ is listed as its canonical blog/, which takes on its newer modification time
+    assert_eq!(
+        Some(
+            Utc.with_ymd_and_hms(2024, 4, 1, 12, 0, 0)
+                .unwrap()
+                .fixed_offset()
+        ),
+        urls[1].last_modified
+    );
+    assert_eq!(
+        Some(
+            Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0)
+                .unwrap()
+                .fixed_offset()
+        ),
+        urls[0].last_modified
+    );
+}
+
+#[test]
+fn test_static_site_options() {
+    let root: PathBuf = site_dir("options");
+    build_site(&root);
+
+    let mut site: StaticSite = StaticSite::new(root, format!("{BASE_URL}/"));
+    site.last_modified(false)
+        .honor_noindex(false)
+        .honor_canonical(false)
+        .exclude(PathBuf::from("blog"));
+    let urls: Vec<Url> = site.urls().unwrap();
+
+    assert_eq!(
+        vec![
+            "https://www.toddgriffin.me/about.html",
+            "https://www.toddgriffin.me/drafts.html",
+            "https://www.toddgriffin.me/",
+            "https://www.toddgriffin.me/lookalike.html",
+            "https://www.toddgriffin.me/mirror.html",
+        ],
+        locations(&urls)
+    );
+    assert!(urls.iter().all(|url| url.last_modified.is_none()));
+}
+
+#[test]
+fn test_static_site_missing_root() {
+    let root: PathBuf = site_dir("missing").join("public");
+    let error: String = StaticSite::new(root, String::from(BASE_URL))
+        .urls()
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("public"), "{error}");
+}
+
+#[test]
+fn test_static_site_images_alternates_and_videos() {
+    let root: PathBuf = site_dir("media");
+    fs::create_dir_all(root.join("blog")).unwrap();
+    fs::write(
+        root.join("blog").join("post.html"),
+        r#"<html>
+<head>
+<title>Road trip &amp; more</title>
+<meta name="description" content="A week on the road.">
+<link rel="alternate" hreflang="de" href="/de/blog/post.html">
+<link rel="alternate" hreflang="x-default" href="https://www.toddgriffin.me/blog/post.html">
+</head>
+<body>
+<img src="../images/car.jpg" alt="">
+<img src="/images/map.png">
+<img src="/images/map.png">
+<img src="data:image/gif;base64,R0lGOD">
+<video poster="trip.jpg" controls>
+  <source src="trip.webm" type="video/webm">
+  <source src="trip.mp4" type="video/mp4">
+</video>
+<video src="untitled.mp4"></video>
+</body>
+</html>"#,
+    )
+    .unwrap();
+
+    let mut site: StaticSite = StaticSite::new(root, String::from(BASE_URL));
+    site.last_modified(false);
+    let urls: Vec<Url> = site.urls().unwrap();
+    let url: &Url = &urls[0];
+
+    let links: Vec<(&str, &str)> = url
+        .links
+        .iter()
+        .map(|link| (link.hreflang.as_str(), link.href.as_str()))
+        .collect();
+    assert_eq!(
+        vec![
+            ("de", "https://www.toddgriffin.me/de/blog/post.html"),
+            ("x-default", "https://www.toddgriffin.me/blog/post.html"),
+        ],
+        links
+    );
+
+    let images: Vec<&str> = url
+        .images
+        .iter()
+        .flatten()
+        .map(|image| image.location.as_str())
+        .collect();
+    assert_eq!(
+        vec![
+            "https://www.toddgriffin.me/images/car.jpg",
+            "https://www.toddgriffin.me/images/map.png",
+        ],
+        images
+    );
+
+    // the second <video> has no poster, so it is left out
+    let videos: &Vec<Video> = url.videos.as_ref().unwrap();
+    assert_eq!(1, videos.len());
+    assert_eq!(
+        "https://www.toddgriffin.me/blog/trip.jpg",
+        videos[0].thumbnail_location
+    );
+    assert_eq!("Road trip & more", videos[0].title);
+    assert_eq!("A week on the road.", videos[0].description);
+    assert_eq!(
+        "https://www.toddgriffin.me/blog/trip.webm",
+        videos[0].content_location
+    );
+    assert_eq!("", videos[0].player_location);
+}
+
+#[cfg(feature = "json-ld")]
+#[test]
+fn test_static_site_json_ld_videos() {
+    let root: PathBuf = site_dir("json-ld");
+    fs::write(
+        root.join("index.html"),
+        r#"<html><head>
+<script type="application/ld+json">
+{
+  "@context": "https://schema.org",
+  "@graph": [
+    {"@type": "WebPage", "name": "Home"},
+    {
+      "@type": "VideoObject",
+      "name": "Grilling steaks for summer",
+      "description": "Alkis shows you how to get perfectly done steaks every time",
+      "thumbnailUrl": ["/thumbs/123.jpg"],
+      "contentUrl": "https://www.toddgriffin.me/video123.mp4",
+      "embedUrl": "https://www.toddgriffin.me/videoplayer.php?video=123",
+      "duration": "PT10M",
+      "uploadDate": "2024-03-01T08:00:00+08:00",
+      "isFamilyFriendly": true
+    },
+    {"@type": "VideoObject", "name": "No thumbnail", "description": "Left out", "contentUrl": "/a.mp4"}
+  ]
+}
+</script>
+</head><body></body></html>"#,
+    )
+    .unwrap();
+
+    let mut site: StaticSite = StaticSite::new(root, String::from(BASE_URL));
+    site.last_modified(false);
+    let urls: Vec<Url> = site.urls().unwrap();
+
+    let videos: &Vec<Video> = urls[0].videos.as_ref().unwrap();
+    assert_eq!(1, videos.len());
+    assert_eq!("Grilling steaks for summer", videos[0].title);
+    assert_eq!(
+        "https://www.toddgriffin.me/thumbs/123.jpg",
+        videos[0].thumbnail_location
+    );
+    assert_eq!(
+        "https://www.toddgriffin.me/videoplayer.php?video=123",
+        videos[0].player_location
+    );
+    assert_eq!(Some(600), videos[0].duration.map(VideoDuration::get));
+    assert_eq!(
+        Some(DateTime::parse_from_rfc3339("2024-03-01T08:00:00+08:00").unwrap()),
+        videos[0].publication_date
+    );
+    assert_eq!(Some(true), videos[0].family_friendly);
+}
+
+#[test]
+fn test_static_site_too_many_images() {
+    let root: PathBuf = site_dir("too-many-images");
+    let mut body: String = String::new();
+    for index in 0..1_001 {
+        write!(body, "<img src=\"/{index}.png\">").unwrap();
+    }
+    fs::write(root.join("index.html"), format!("<body>{body}</body>")).unwrap();
+
+    fs::write(root.join("about.html"), "<body></body>").unwrap();
+
+    // the page is listed with its first 1,000 images, and the rest of the site is still listed
+    let (urls, warnings): (Vec<Url>, Vec<StaticSiteWarning>) =
+        StaticSite::new(root.clone(), String::from(BASE_URL))
+            .urls_with_warnings()
+            .unwrap();
+    assert_eq!(2, urls.len());
+    let images: &Vec<Image> = urls[1].images.as_ref().unwrap();
+    assert_eq!(1000, images.len());
+    assert_eq!("https://www.toddgriffin.me/999.png", images[999].location);
+    assert_eq!(1, warnings.len());
+    let StaticSiteWarning::Url { path, error } = &warnings[0];
+    assert_eq!(&root.join("index.html"), path);
+    assert!(matches!(error, UrlError::TooManyImages(1001)), "{error:?}");
+}
@@ -0,0 +1,16 @@
+use sitemap_rs::NAMESPACE;
+use sitemap_rs::stylesheet::{SITEMAP_INDEX_XSL, URL_SET_XSL};
+
+#[test]
+fn test_url_set_xsl() {
+    assert!(URL_SET_XSL.starts_with("<?xml"));
+    assert!(URL_SET_XSL.contains(NAMESPACE));
+    assert!(URL_SET_XSL.contains("sitemap:urlset/sitemap:url"));
+}
+
+#[test]
+fn test_sitemap_index_xsl() {
+    assert!(SITEMAP_INDEX_XSL.starts_with("<?xml"));
+    assert!(SITEMAP_INDEX_XSL.contains(NAMESPACE));
+    assert!(SITEMAP_INDEX_XSL.contains("sitemap:sitemapindex/sitemap:sitemap"));
+}
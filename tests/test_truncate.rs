@@ -0,0 +1,29 @@
+use sitemap_rs::truncate::{truncate_string, truncate_to_characters};
+
+#[test]
+fn test_truncate_short_text_is_unchanged() {
+    assert_eq!("steak", truncate_to_characters("steak", 255));
+}
+
+#[test]
+fn test_truncate_ascii() {
+    assert_eq!("ste", truncate_to_characters("steak", 3));
+}
+
+#[test]
+fn test_truncate_multibyte_characters() {
+    assert_eq!("説明", truncate_to_characters("説明文", 2));
+}
+
+#[test]
+fn test_truncate_does_not_split_graphemes() {
+    // "e" followed by a combining acute accent is two characters but one grapheme
+    assert_eq!("e\u{301}", truncate_to_characters("e\u{301}e\u{301}", 3));
+}
+
+#[test]
+fn test_truncate_string_in_place() {
+    let mut text: String = String::from("Grilling steaks for summer");
+    truncate_string(&mut text, 8);
+    assert_eq!("Grilling", text);
+}
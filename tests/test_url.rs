@@ -2,8 +2,9 @@ extern crate core;
 
 use chrono::{DateTime, Utc};
 use sitemap_rs::image::Image;
-use sitemap_rs::url::{ChangeFrequency, DEFAULT_PRIORITY, Link, Url};
-use sitemap_rs::url_error::UrlError;
+use sitemap_rs::url::{ChangeFrequency, DEFAULT_PRIORITY, Link, Priority, Url};
+use sitemap_rs::url_error::{ParseChangeFrequencyError, UrlError};
+use std::collections::HashSet;
 
 #[test]
 fn test_constructor_only_required_fields() {
@@ -48,14 +49,36 @@ fn test_constructor_location_too_long() {
     match url_result {
         Ok(_) => panic!("Returned a URL!"),
         Err(e) => match e {
-            UrlError::LocationTooLong(location) => assert_eq!(location, "t".repeat(2048)),
+            UrlError::LocationTooLong {
+                location,
+                characters,
+                bytes,
+            } => {
+                assert_eq!(location, "t".repeat(2048));
+                assert_eq!(2048, characters);
+                assert_eq!(2048, bytes);
+            }
             UrlError::PriorityTooLow(_) => panic!("Returned PriorityTooLow!"),
             UrlError::PriorityTooHigh(_) => panic!("Returned PriorityTooHigh!"),
+            UrlError::PriorityNotANumber => panic!("Returned PriorityNotANumber!"),
+            UrlError::Date { .. } => panic!("Returned Date!"),
             UrlError::TooManyImages(_) => panic!("Returned TooManyImages!"),
         },
     }
 }
 
+#[test]
+fn test_constructor_location_counts_characters_not_bytes() {
+    // 2,047 characters, but well over 2,048 bytes
+    let location: String = format!("https://www.toddgriffin.me/{}", "ü".repeat(2020));
+    assert_eq!(2047, location.chars().count());
+    assert!(location.len() > 2048);
+
+    let url_result: Result<Url, UrlError> =
+        Url::new(location, vec![], None, None, None, None, None, None);
+    assert!(url_result.is_ok());
+}
+
 #[test]
 fn test_constructor_priority_too_low() {
     let url_result: Result<Url, UrlError> = Url::new(
@@ -71,12 +94,14 @@ fn test_constructor_priority_too_low() {
     match url_result {
         Ok(_) => panic!("Returned a URL!"),
         Err(e) => match e {
-            UrlError::LocationTooLong(_) => panic!("Returned LocationTooLong!"),
+            UrlError::LocationTooLong { .. } => panic!("Returned LocationTooLong!"),
             UrlError::PriorityTooLow(priority) => {
                 let expected_priority: f32 = -1.0;
                 assert!((priority - expected_priority).abs() < f32::EPSILON);
             }
             UrlError::PriorityTooHigh(_) => panic!("Returned PriorityTooHigh!"),
+            UrlError::PriorityNotANumber => panic!("Returned PriorityNotANumber!"),
+            UrlError::Date { .. } => panic!("Returned Date!"),
             UrlError::TooManyImages(_) => panic!("Returned TooManyImages!"),
         },
     }
@@ -97,12 +122,14 @@ fn test_constructor_priority_too_high() {
     match url_result {
         Ok(_) => panic!("Returned a URL!"),
         Err(e) => match e {
-            UrlError::LocationTooLong(_) => panic!("Returned LocationTooLong!"),
+            UrlError::LocationTooLong { .. } => panic!("Returned LocationTooLong!"),
             UrlError::PriorityTooLow(_) => panic!("Returned PriorityTooLow!"),
             UrlError::PriorityTooHigh(priority) => {
                 let expected_priority: f32 = 4.69;
                 assert!((priority - expected_priority).abs() < f32::EPSILON);
             }
+            UrlError::PriorityNotANumber => panic!("Returned PriorityNotANumber!"),
+            UrlError::Date { .. } => panic!("Returned Date!"),
             UrlError::TooManyImages(_) => panic!("Returned TooManyImages!"),
         },
     }
@@ -130,10 +157,130 @@ fn test_constructor_too_many_images() {
     match url_result {
         Ok(_) => panic!("Returned a URL!"),
         Err(e) => match e {
-            UrlError::LocationTooLong(_) => panic!("Returned LocationTooLong!"),
+            UrlError::LocationTooLong { .. } => panic!("Returned LocationTooLong!"),
             UrlError::PriorityTooLow(_) => panic!("Returned PriorityTooLow!"),
             UrlError::PriorityTooHigh(_) => panic!("Returned PriorityTooHigh!"),
+            UrlError::PriorityNotANumber => panic!("Returned PriorityNotANumber!"),
+            UrlError::Date { .. } => panic!("Returned Date!"),
             UrlError::TooManyImages(count) => assert_eq!(1001, count),
         },
     }
 }
+
+#[test]
+fn test_validate_reports_every_error() {
+    let mut url: Url = Url::builder(String::from("https://www.toddgriffin.me/"))
+        .build()
+        .unwrap();
+    url.location = "t".repeat(2048);
+    url.images = Some(vec![
+        Image::new(String::from(
+            "https://www.toddgriffin.me/image.webp"
+        ));
+        1001
+    ]);
+
+    let errors: Vec<UrlError> = url.validate();
+    let fields: Vec<&str> = errors.iter().map(UrlError::field).collect();
+    assert_eq!(vec!["location", "images"], fields);
+}
+
+#[test]
+fn test_validate_valid_url() {
+    let url: Url = Url::builder(String::from("https://www.toddgriffin.me/"))
+        .priority(DEFAULT_PRIORITY)
+        .build()
+        .unwrap();
+    assert!(url.validate().is_empty());
+}
+
+#[test]
+fn test_priority() {
+    assert_eq!(
+        Priority::new(DEFAULT_PRIORITY).unwrap(),
+        Priority::default()
+    );
+    assert_eq!("0.69", Priority::new(0.69).unwrap().to_string());
+    assert_eq!("1", Priority::try_from(1.0).unwrap().to_string());
+    assert!(matches!(
+        Priority::new(-0.1),
+        Err(UrlError::PriorityTooLow(_))
+    ));
+    assert!(matches!(
+        Priority::new(1.1),
+        Err(UrlError::PriorityTooHigh(_))
+    ));
+    assert!(matches!(
+        Priority::new(f32::NAN),
+        Err(UrlError::PriorityNotANumber)
+    ));
+}
+
+#[test]
+fn test_priority_ordering_and_hashing() {
+    let zero: Priority = Priority::new(0.0).unwrap();
+    let negative_zero: Priority = Priority::new(-0.0).unwrap();
+    assert_eq!(zero, negative_zero);
+    assert_eq!(1, HashSet::from([zero, negative_zero]).len());
+
+    let mut priorities: Vec<Priority> = [0.8, 0.1, 1.0, 0.5]
+        .into_iter()
+        .map(|priority| Priority::new(priority).unwrap())
+        .collect();
+    priorities.sort();
+    assert_eq!(
+        vec![0.1, 0.5, 0.8, 1.0],
+        priorities
+            .into_iter()
+            .map(Priority::get)
+            .collect::<Vec<f32>>()
+    );
+}
+
+#[test]
+fn test_url_equality_and_hashing() {
+    let url = |priority: f32| {
+        Url::builder(String::from("https://www.toddgriffin.me/"))
+            .priority(priority)
+            .images(vec![Image::new(String::from(
+                "https://www.toddgriffin.me/car.jpg",
+            ))])
+            .links(vec![Link::new(
+                String::from("de"),
+                String::from("https://www.toddgriffin.me/de/"),
+            )])
+            .build()
+            .unwrap()
+    };
+
+    assert_eq!(url(0.5), url(0.5));
+    assert_ne!(url(0.5), url(0.8));
+    assert_eq!(2, HashSet::from([url(0.5), url(0.5), url(0.8)]).len());
+
+    let mut change_frequencies: Vec<ChangeFrequency> = vec![
+        ChangeFrequency::Never,
+        ChangeFrequency::Daily,
+        ChangeFrequency::Always,
+    ];
+    change_frequencies.sort();
+    assert_eq!(
+        vec![
+            ChangeFrequency::Always,
+            ChangeFrequency::Daily,
+            ChangeFrequency::Never,
+        ],
+        change_frequencies
+    );
+}
+
+#[test]
+fn test_parse_change_frequency() {
+    assert!(matches!(
+        "weekly".parse::<ChangeFrequency>(),
+        Ok(ChangeFrequency::Weekly)
+    ));
+    match "fortnightly".parse::<ChangeFrequency>() {
+        Err(ParseChangeFrequencyError(text)) => assert_eq!("fortnightly", text),
+        other => panic!("Returned {other:?}!"),
+    }
+}
@@ -1,4 +1,6 @@
-use chrono::{DateTime, Utc};
+use chrono::Utc;
+#[cfg(feature = "time")]
+use sitemap_rs::date_error::DateError;
 use sitemap_rs::news::{News, Publication};
 use sitemap_rs::url::{ChangeFrequency, DEFAULT_PRIORITY, Url};
 use sitemap_rs::url_builder::UrlBuilder;
@@ -17,7 +19,7 @@ fn test_only_required_fields() {
 fn test_all_normal_fields() {
     let url_builder_result: Result<Url, UrlError> =
         UrlBuilder::new(String::from("https://www.toddgriffin.me/"))
-            .last_modified(DateTime::from(Utc::now()))
+            .last_modified(Utc::now())
             .change_frequency(ChangeFrequency::Weekly)
             .priority(DEFAULT_PRIORITY)
             .build();
@@ -32,7 +34,7 @@ fn test_all_fields() {
                 "de".to_owned(),
                 "https://www.toddgriffin.me/de".to_owned(),
             )])
-            .last_modified(DateTime::from(Utc::now()))
+            .last_modified(Utc::now())
             .change_frequency(ChangeFrequency::Weekly)
             .priority(DEFAULT_PRIORITY)
             .images(vec![Image::new(String::from("https://www.toddgriffin.me/static/image/social/profile-picture.webp"))])
@@ -45,7 +47,7 @@ fn test_all_fields() {
             ).build().unwrap()])
             .news(News::new(
                 Publication::new(String::from("The Todd Times"), String::from("en")),
-                DateTime::from(Utc::now()),
+                Utc::now(),
                 String::from(
                     "Local Software Engineer, Todd, Finally Completes Project He Has Talked About For Years",
                 ),
@@ -53,3 +55,33 @@ fn test_all_fields() {
             .build();
     assert!(url_builder_result.is_ok());
 }
+
+#[cfg(feature = "time")]
+#[test]
+fn test_last_modified_out_of_range() {
+    // time allows offsets up to 25:59:59, chrono only up to 23:59:59
+    let offset_date_time: time::OffsetDateTime = time::OffsetDateTime::UNIX_EPOCH
+        .replace_offset(time::UtcOffset::from_hms(25, 0, 0).unwrap());
+
+    let mut url_builder: UrlBuilder = UrlBuilder::new(String::from("https://www.toddgriffin.me/"));
+    url_builder.last_modified(offset_date_time);
+    assert!(matches!(
+        url_builder.build(),
+        Err(UrlError::Date {
+            field: "last_modified",
+            error: DateError::OffsetOutOfRange(90000),
+        })
+    ));
+
+    // a later date that can be converted replaces the error
+    url_builder.last_modified(time::OffsetDateTime::UNIX_EPOCH);
+    assert_eq!(
+        "1970-01-01T00:00:00+00:00",
+        url_builder
+            .build()
+            .unwrap()
+            .last_modified
+            .unwrap()
+            .to_rfc3339()
+    );
+}
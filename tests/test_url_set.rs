@@ -1,6 +1,10 @@
 use std::collections::BTreeSet;
 
 use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use sitemap_rs::url_builder::UrlBuilder;
+use sitemap_rs::url_error::UrlError;
+use sitemap_rs::validation_error::{ValidationError, ValidationErrorKind};
+use sitemap_rs::video_error::VideoError;
 use sitemap_rs::{
     image::Image,
     url::Url,
@@ -10,7 +14,10 @@ use sitemap_rs::{
     news::{News, Publication},
     url::{ChangeFrequency, Link},
 };
-use sitemap_rs::{url_set::UrlSet, video::Video};
+use sitemap_rs::{
+    url_set::{UrlOrder, UrlSet},
+    video::Video,
+};
 use sitemap_rs::{
     url_set_error::UrlSetError,
     video::{Platform, Relationship, Restriction},
@@ -31,7 +38,7 @@ fn test_write_all_fields() {
             String::from("https://www.toddgriffin.me/videoplayer.php?video=123"),
         )
         .duration(600)
-        .expiration_date(DateTime::from_naive_utc_and_offset(
+        .expiration_date(DateTime::<FixedOffset>::from_naive_utc_and_offset(
             NaiveDate::from_ymd_opt(2021, 11, 5)
                 .unwrap()
                 .and_hms_opt(11, 20, 30)
@@ -40,7 +47,7 @@ fn test_write_all_fields() {
         ))
         .rating(4.2)
         .view_count(8633)
-        .publication_date(DateTime::from_naive_utc_and_offset(
+        .publication_date(DateTime::<FixedOffset>::from_naive_utc_and_offset(
             NaiveDate::from_ymd_opt(1998, 1, 15)
                 .unwrap()
                 .and_hms_opt(4, 20, 0)
@@ -80,7 +87,7 @@ fn test_write_all_fields() {
     ];
     let news: News = News::new(
         Publication::new(String::from("The Example Times"), String::from("en")),
-        DateTime::from_naive_utc_and_offset(
+        DateTime::<FixedOffset>::from_naive_utc_and_offset(
             NaiveDate::from_ymd_opt(1998, 1, 15)
                 .unwrap()
                 .and_hms_opt(4, 20, 0)
@@ -95,7 +102,7 @@ fn test_write_all_fields() {
                 "de".to_owned(),
                 "https://www.toddgriffin.me/de".to_owned(),
             )])
-            .last_modified(DateTime::from_naive_utc_and_offset(
+            .last_modified(DateTime::<FixedOffset>::from_naive_utc_and_offset(
                 NaiveDate::from_ymd_opt(1998, 1, 15)
                     .unwrap()
                     .and_hms_opt(4, 20, 0)
@@ -191,6 +198,7 @@ fn test_constructor_too_many_urls() {
         Err(e) => match e {
             UrlSetError::TooManyUrls(count) => assert_eq!(50_001, count),
             UrlSetError::TooMuchNews(_) => panic!("Returned TooMuchNews!"),
+            UrlSetError::NamespaceConflict { .. } => panic!("Returned NamespaceConflict!"),
         },
     }
 }
@@ -199,7 +207,7 @@ fn test_constructor_too_many_urls() {
 fn test_constructor_too_much_news() {
     let news: News = News::new(
         Publication::new(String::from("The Todd Times"), String::from("en")),
-        DateTime::from(Utc::now()),
+        Utc::now(),
         String::from(
             "Local Software Engineer, Todd, Finally Completes Project He Has Talked About For Years",
         ),
@@ -220,6 +228,277 @@ fn test_constructor_too_much_news() {
         Err(e) => match e {
             UrlSetError::TooManyUrls(_) => panic!("Returned TooManyUrls!"),
             UrlSetError::TooMuchNews(count) => assert_eq!(1001, count),
+            UrlSetError::NamespaceConflict { .. } => panic!("Returned NamespaceConflict!"),
         },
     }
 }
+
+#[test]
+fn test_validate_reports_every_error_with_paths() {
+    let video: Video = Video::builder(
+        String::from("https://www.toddgriffin.me/thumbs/123.jpg"),
+        String::from("Grilling steaks for summer"),
+        String::from("Alkis shows you how to get perfectly done steaks every time"),
+        String::from("https://www.toddgriffin.me/video123.mp4"),
+        String::from("https://www.toddgriffin.me/videoplayer.php?video=123"),
+    )
+    .build()
+    .unwrap();
+    let mut invalid_video: Video = video.clone();
+    invalid_video.description = "5".repeat(2049);
+
+    let mut urls: Vec<Url> = vec![
+        Url::builder(String::from("https://www.toddgriffin.me/"))
+            .build()
+            .unwrap();
+        3
+    ];
+    urls[1].location = "t".repeat(2048);
+    urls[2].videos = Some(vec![video, invalid_video]);
+
+    let errors: Vec<ValidationError> = UrlSet::validate(&urls);
+    assert_eq!(2, errors.len());
+
+    assert_eq!(Some(1), errors[0].index);
+    assert_eq!("urls[1].location", errors[0].path);
+    assert!(matches!(
+        errors[0].kind,
+        ValidationErrorKind::Url(UrlError::LocationTooLong { .. })
+    ));
+
+    assert_eq!(Some(2), errors[1].index);
+    assert_eq!("urls[2].videos[1].description", errors[1].path);
+    assert!(matches!(
+        errors[1].kind,
+        ValidationErrorKind::Video(VideoError::DescriptionTooLong { .. })
+    ));
+
+    match UrlSet::new_validated(urls) {
+        Ok(_) => panic!("Returned a UrlSet!"),
+        Err(errors) => assert_eq!(2, errors.len()),
+    }
+}
+
+#[test]
+fn test_validate_reports_set_errors() {
+    let urls: Vec<Url> = vec![
+        Url::builder(String::from("https://www.toddgriffin.me/"))
+            .build()
+            .unwrap();
+        50_001
+    ];
+
+    let errors: Vec<ValidationError> = UrlSet::validate(&urls);
+    assert_eq!(1, errors.len());
+    assert_eq!(None, errors[0].index);
+    assert_eq!("urls", errors[0].path);
+    assert!(matches!(
+        errors[0].kind,
+        ValidationErrorKind::UrlSet(UrlSetError::TooManyUrls(50_001))
+    ));
+}
+
+#[test]
+fn test_new_validated() {
+    let urls: Vec<Url> = vec![
+        Url::builder(String::from("https://www.toddgriffin.me/"))
+            .build()
+            .unwrap(),
+    ];
+    assert!(UrlSet::new_validated(urls).is_ok());
+}
+
+#[test]
+fn test_lenient_constructor_skips_invalid_urls() {
+    let mut invalid_video: Video = Video::builder(
+        String::from("https://www.toddgriffin.me/thumbs/123.jpg"),
+        String::from("Grilling steaks for summer"),
+        String::from("Alkis shows you how to get perfectly done steaks every time"),
+        String::from("https://www.toddgriffin.me/video123.mp4"),
+        String::from("https://www.toddgriffin.me/videoplayer.php?video=123"),
+    )
+    .build()
+    .unwrap();
+    invalid_video.uploader = Some(Uploader::new("5".repeat(256), None));
+
+    let mut urls: Vec<Url> = vec![
+        Url::builder(String::from("https://www.toddgriffin.me/"))
+            .build()
+            .unwrap();
+        4
+    ];
+    urls[1].images = Some(vec![
+        Image::new(String::from(
+            "https://www.toddgriffin.me/image.webp"
+        ));
+        1001
+    ]);
+    urls[3].videos = Some(vec![invalid_video]);
+
+    let (url_set, rejected) = UrlSet::new_lenient(urls).unwrap();
+    assert_eq!(2, url_set.urls.len());
+    assert_eq!(2, rejected.len());
+
+    assert_eq!(1, rejected[0].index);
+    assert!(matches!(
+        rejected[0].errors[0].kind,
+        ValidationErrorKind::Url(UrlError::TooManyImages(1001))
+    ));
+
+    assert_eq!(3, rejected[1].index);
+    assert_eq!(
+        "urls[3].videos[0].uploader.name",
+        rejected[1].errors[0].path
+    );
+    assert!(matches!(
+        rejected[1].errors[0].kind,
+        ValidationErrorKind::Video(VideoError::UploaderNameTooLong { .. })
+    ));
+}
+
+#[test]
+fn test_write_borrows_url_set() {
+    let urls: Vec<Url> = vec![
+        Url::builder(String::from("https://www.toddgriffin.me/"))
+            .images(vec![Image::new(String::from(
+                "https://www.toddgriffin.me/picture.webp",
+            ))])
+            .build()
+            .unwrap(),
+    ];
+    let url_set: UrlSet = UrlSet::new(urls).unwrap();
+
+    let mut first: Vec<u8> = Vec::<u8>::new();
+    url_set.write(&mut first).unwrap();
+    let mut second: Vec<u8> = Vec::<u8>::new();
+    url_set.write(&mut second).unwrap();
+
+    assert_eq!(first, second);
+    assert_eq!(1, url_set.urls.len());
+}
+
+#[test]
+fn test_write_escapes_special_characters() {
+    let urls: Vec<Url> = vec![
+        Url::builder(String::from("https://www.toddgriffin.me/?a=1&b=<2>"))
+            .links(vec![Link::new(
+                "de".to_owned(),
+                "https://www.toddgriffin.me/de?q=\"it's\"".to_owned(),
+            )])
+            .build()
+            .unwrap(),
+    ];
+    let url_set: UrlSet = UrlSet::new(urls).unwrap();
+
+    let mut buf: Vec<u8> = Vec::<u8>::new();
+    url_set.write(&mut buf).unwrap();
+    let actual: String = String::from_utf8(buf).unwrap();
+
+    let expected: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" xmlns:xhtml="http://www.w3.org/1999/xhtml">
+	<url>
+		<loc>https://www.toddgriffin.me/?a=1&amp;b=&lt;2&gt;</loc>
+		<xhtml:link rel="alternate" hreflang="de" href="https://www.toddgriffin.me/de?q=&quot;it&apos;s&quot;" />
+	</url>
+</urlset>
+"#;
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_write_empty() {
+    let url_set: UrlSet = UrlSet::new(vec![]).unwrap();
+
+    let mut buf: Vec<u8> = Vec::<u8>::new();
+    url_set.write(&mut buf).unwrap();
+    let actual: String = String::from_utf8(buf).unwrap();
+
+    let expected: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" />
+"#;
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_write_stylesheet() {
+    let mut url_set: UrlSet = UrlSet::new(vec![]).unwrap();
+    url_set.stylesheet = Some(String::from("/sitemap.xsl?v=1&theme=dark"));
+
+    let mut buf: Vec<u8> = Vec::<u8>::new();
+    url_set.write(&mut buf).unwrap();
+    let actual: String = String::from_utf8(buf).unwrap();
+
+    let expected: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<?xml-stylesheet type="text/xsl" href="/sitemap.xsl?v=1&amp;theme=dark"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" />
+"#;
+    assert_eq!(expected, actual);
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn test_write_gzip() {
+    use sitemap_rs::write_options::WriteOptions;
+    use std::io::Read;
+
+    let url_set: UrlSet = UrlSet::new(vec![]).unwrap();
+
+    let mut buf: Vec<u8> = Vec::<u8>::new();
+    url_set
+        .write_gzip(&mut buf, &WriteOptions::default())
+        .unwrap();
+
+    let mut actual: String = String::new();
+    flate2::read::GzDecoder::new(buf.as_slice())
+        .read_to_string(&mut actual)
+        .unwrap();
+
+    let expected: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" />
+"#;
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_sort() {
+    let url = |path: &str, day: Option<u32>| {
+        let mut builder: UrlBuilder = Url::builder(format!("https://www.toddgriffin.me/{path}"));
+        if let Some(day) = day {
+            builder.last_modified(
+                NaiveDate::from_ymd_opt(2024, 3, day)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
+                    .and_utc(),
+            );
+        }
+        builder.build().unwrap()
+    };
+    let mut url_set: UrlSet = UrlSet::new(vec![
+        url("c", Some(1)),
+        url("d", None),
+        url("a", Some(2)),
+        url("b", Some(1)),
+    ])
+    .unwrap();
+    let paths = |url_set: &UrlSet| {
+        url_set
+            .urls
+            .iter()
+            .map(|url| {
+                url.location
+                    .trim_start_matches("https://www.toddgriffin.me/")
+                    .to_string()
+            })
+            .collect::<Vec<String>>()
+    };
+
+    url_set.sort(UrlOrder::Location);
+    assert_eq!(vec!["a", "b", "c", "d"], paths(&url_set));
+
+    url_set.sort(UrlOrder::LastModified);
+    assert_eq!(vec!["b", "c", "a", "d"], paths(&url_set));
+
+    url_set.sort(UrlOrder::LastModifiedDescending);
+    assert_eq!(vec!["a", "b", "c", "d"], paths(&url_set));
+}
@@ -0,0 +1,156 @@
+use chrono::Utc;
+use sitemap_rs::extension::{ExtensionElement, Namespace};
+use sitemap_rs::image::Image;
+use sitemap_rs::news::{News, Publication};
+use sitemap_rs::url::Url;
+use sitemap_rs::url_error::UrlError;
+use sitemap_rs::url_set::UrlSet;
+use sitemap_rs::url_set_builder::UrlSetBuilder;
+use sitemap_rs::url_set_error::UrlSetError;
+use sitemap_rs::validation_error::ValidationErrorKind;
+use sitemap_rs::xml_version::XmlVersion;
+
+#[test]
+fn test_builder_matches_constructor() {
+    let urls: Vec<Url> = vec![
+        Url::builder(String::from("https://www.toddgriffin.me/"))
+            .images(vec![Image::new(String::from(
+                "https://www.toddgriffin.me/todd.png",
+            ))])
+            .build()
+            .unwrap(),
+        Url::builder(String::from("https://www.toddgriffin.me/about"))
+            .build()
+            .unwrap(),
+    ];
+
+    let mut builder: UrlSetBuilder = UrlSet::builder();
+    for url in urls.clone() {
+        builder.push(url).unwrap();
+    }
+    assert_eq!(2, builder.len());
+
+    let mut expected: Vec<u8> = Vec::<u8>::new();
+    UrlSet::new(urls).unwrap().write(&mut expected).unwrap();
+    let mut actual: Vec<u8> = Vec::<u8>::new();
+    builder.build().write(&mut actual).unwrap();
+
+    assert_eq!(
+        String::from_utf8(expected).unwrap(),
+        String::from_utf8(actual).unwrap()
+    );
+}
+
+#[test]
+fn test_push_rejects_invalid_url() {
+    let mut builder: UrlSetBuilder = UrlSetBuilder::new();
+    builder
+        .push(
+            Url::builder(String::from("https://www.toddgriffin.me/"))
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+
+    let mut url: Url = Url::builder(String::from("https://www.toddgriffin.me/about"))
+        .build()
+        .unwrap();
+    url.location = "t".repeat(2048);
+
+    let rejected = builder.push(url).unwrap_err();
+    assert_eq!(1, rejected.index);
+    assert_eq!("t".repeat(2048), rejected.item.location);
+    assert_eq!(1, rejected.errors.len());
+    assert_eq!("urls[1].location", rejected.errors[0].path);
+    assert!(matches!(
+        rejected.errors[0].kind,
+        ValidationErrorKind::Url(UrlError::LocationTooLong { .. })
+    ));
+
+    // the rejected url is not added
+    assert_eq!(1, builder.len());
+}
+
+#[test]
+fn test_push_too_much_news() {
+    let mut builder: UrlSetBuilder = UrlSetBuilder::new();
+    for _ in 0..1000 {
+        builder
+            .push(
+                Url::builder(String::from("https://www.toddgriffin.me/"))
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+    }
+
+    let news: News = News::new(
+        Publication::new(String::from("The Todd Times"), String::from("en")),
+        Utc::now(),
+        String::from("Local Software Engineer, Todd, Finally Writes Builders"),
+    );
+    let url: Url = Url::builder(String::from("https://www.toddgriffin.me/news"))
+        .news(news)
+        .build()
+        .unwrap();
+
+    let rejected = builder.push(url).unwrap_err();
+    assert!(matches!(
+        rejected.errors[0].kind,
+        ValidationErrorKind::UrlSet(UrlSetError::TooMuchNews(1001))
+    ));
+}
+
+#[test]
+fn test_push_namespace_conflict() {
+    let extension = |uri: &'static str| {
+        Url::builder(String::from("https://www.toddgriffin.me/"))
+            .extensions(vec![ExtensionElement::new(
+                Namespace::new("vendor", uri),
+                String::from("rank"),
+            )])
+            .build()
+            .unwrap()
+    };
+
+    let mut builder: UrlSetBuilder = UrlSetBuilder::new();
+    builder.push(extension("https://example.com/a")).unwrap();
+
+    let rejected = builder
+        .push(extension("https://example.com/b"))
+        .unwrap_err();
+    assert!(matches!(
+        rejected.errors[0].kind,
+        ValidationErrorKind::UrlSet(UrlSetError::NamespaceConflict { .. })
+    ));
+}
+
+#[test]
+fn test_build_with_options() {
+    let mut builder: UrlSetBuilder = UrlSetBuilder::new();
+    builder
+        .xml_version(XmlVersion::Xml1_1)
+        .xml_encoding(String::from("ISO-8859-1"))
+        .stylesheet(String::from("/sitemap.xsl"))
+        .namespace(Namespace::new("vendor", "https://example.com/vendor"))
+        .push(
+            Url::builder(String::from("https://www.toddgriffin.me/"))
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+
+    let mut buf: Vec<u8> = Vec::<u8>::new();
+    builder.build().write(&mut buf).unwrap();
+    let actual: String = String::from_utf8(buf).unwrap();
+
+    let expected: &str = r#"<?xml version="1.1" encoding="ISO-8859-1"?>
+<?xml-stylesheet type="text/xsl" href="/sitemap.xsl"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" xmlns:vendor="https://example.com/vendor">
+	<url>
+		<loc>https://www.toddgriffin.me/</loc>
+	</url>
+</urlset>
+"#;
+    assert_eq!(expected, actual);
+}
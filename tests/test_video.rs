@@ -1,5 +1,8 @@
 use chrono::{DateTime, FixedOffset, NaiveDate};
-use sitemap_rs::video::{Platform, PlatformType, Relationship, Restriction, Uploader, Video};
+use sitemap_rs::video::{
+    Platform, PlatformType, Relationship, Restriction, TagList, Uploader, Video, VideoDuration,
+    VideoRating,
+};
 use sitemap_rs::video_error::VideoError;
 use std::collections::BTreeSet;
 
@@ -114,12 +117,17 @@ fn test_constructor_description_too_long() {
     match video_result {
         Ok(_) => panic!("Returned a Video!"),
         Err(e) => match e {
-            VideoError::DescriptionTooLong(count) => assert_eq!(2049, count),
+            VideoError::DescriptionTooLong { characters, bytes } => {
+                assert_eq!(2049, characters);
+                assert_eq!(2049, bytes);
+            }
             VideoError::DurationTooShort(_) => panic!("Returned DurationTooShort!"),
             VideoError::DurationTooLong(_) => panic!("Returned DurationTooLong!"),
             VideoError::RatingTooLow(_) => panic!("Returned RatingTooLow!"),
             VideoError::RatingTooHigh(_) => panic!("Returned RatingTooHigh!"),
-            VideoError::UploaderNameTooLong(_) => panic!("Returned UploaderNameTooLong!"),
+            VideoError::RatingNotANumber => panic!("Returned RatingNotANumber!"),
+            VideoError::Date { .. } => panic!("Returned Date!"),
+            VideoError::UploaderNameTooLong { .. } => panic!("Returned UploaderNameTooLong!"),
             VideoError::TooManyTags(_) => panic!("Returned TooManyTags!"),
         },
     }
@@ -150,12 +158,14 @@ fn test_constructor_duration_too_short() {
     match video_result {
         Ok(_) => panic!("Returned a Video!"),
         Err(e) => match e {
-            VideoError::DescriptionTooLong(_) => panic!("Returned DescriptionTooLong!"),
+            VideoError::DescriptionTooLong { .. } => panic!("Returned DescriptionTooLong!"),
             VideoError::DurationTooShort(duration) => assert_eq!(0, duration),
             VideoError::DurationTooLong(_) => panic!("Returned DurationTooLong!"),
             VideoError::RatingTooLow(_) => panic!("Returned RatingTooLow!"),
             VideoError::RatingTooHigh(_) => panic!("Returned RatingTooHigh!"),
-            VideoError::UploaderNameTooLong(_) => panic!("Returned UploaderNameTooLong!"),
+            VideoError::RatingNotANumber => panic!("Returned RatingNotANumber!"),
+            VideoError::Date { .. } => panic!("Returned Date!"),
+            VideoError::UploaderNameTooLong { .. } => panic!("Returned UploaderNameTooLong!"),
             VideoError::TooManyTags(_) => panic!("Returned TooManyTags!"),
         },
     }
@@ -186,12 +196,14 @@ fn test_constructor_duration_too_long() {
     match video_result {
         Ok(_) => panic!("Returned a Video!"),
         Err(e) => match e {
-            VideoError::DescriptionTooLong(_) => panic!("Returned DescriptionTooLong!"),
+            VideoError::DescriptionTooLong { .. } => panic!("Returned DescriptionTooLong!"),
             VideoError::DurationTooShort(_) => panic!("Returned DurationTooShort!"),
             VideoError::DurationTooLong(duration) => assert_eq!(28_801, duration),
             VideoError::RatingTooLow(_) => panic!("Returned RatingTooLow!"),
             VideoError::RatingTooHigh(_) => panic!("Returned RatingTooHigh!"),
-            VideoError::UploaderNameTooLong(_) => panic!("Returned UploaderNameTooLong!"),
+            VideoError::RatingNotANumber => panic!("Returned RatingNotANumber!"),
+            VideoError::Date { .. } => panic!("Returned Date!"),
+            VideoError::UploaderNameTooLong { .. } => panic!("Returned UploaderNameTooLong!"),
             VideoError::TooManyTags(_) => panic!("Returned TooManyTags!"),
         },
     }
@@ -222,7 +234,7 @@ fn test_constructor_rating_too_low() {
     match video_result {
         Ok(_) => panic!("Returned a Video!"),
         Err(e) => match e {
-            VideoError::DescriptionTooLong(_) => panic!("Returned DescriptionTooLong!"),
+            VideoError::DescriptionTooLong { .. } => panic!("Returned DescriptionTooLong!"),
             VideoError::DurationTooShort(_) => panic!("Returned DurationTooShort!"),
             VideoError::DurationTooLong(_) => panic!("Returned DurationTooLong!"),
             VideoError::RatingTooLow(rating) => {
@@ -230,7 +242,9 @@ fn test_constructor_rating_too_low() {
                 assert!((rating - expected_rating).abs() < f32::EPSILON);
             }
             VideoError::RatingTooHigh(_) => panic!("Returned RatingTooHigh!"),
-            VideoError::UploaderNameTooLong(_) => panic!("Returned UploaderNameTooLong!"),
+            VideoError::RatingNotANumber => panic!("Returned RatingNotANumber!"),
+            VideoError::Date { .. } => panic!("Returned Date!"),
+            VideoError::UploaderNameTooLong { .. } => panic!("Returned UploaderNameTooLong!"),
             VideoError::TooManyTags(_) => panic!("Returned TooManyTags!"),
         },
     }
@@ -261,7 +275,7 @@ fn test_constructor_rating_too_high() {
     match video_result {
         Ok(_) => panic!("Returned a Video!"),
         Err(e) => match e {
-            VideoError::DescriptionTooLong(_) => panic!("Returned DescriptionTooLong!"),
+            VideoError::DescriptionTooLong { .. } => panic!("Returned DescriptionTooLong!"),
             VideoError::DurationTooShort(_) => panic!("Returned DurationTooShort!"),
             VideoError::DurationTooLong(_) => panic!("Returned DurationTooLong!"),
             VideoError::RatingTooLow(_) => panic!("Returned RatingTooLow!"),
@@ -269,12 +283,41 @@ fn test_constructor_rating_too_high() {
                 let expected_rating: f32 = 6.69;
                 assert!((rating - expected_rating).abs() < f32::EPSILON);
             }
-            VideoError::UploaderNameTooLong(_) => panic!("Returned UploaderNameTooLong!"),
+            VideoError::RatingNotANumber => panic!("Returned RatingNotANumber!"),
+            VideoError::Date { .. } => panic!("Returned Date!"),
+            VideoError::UploaderNameTooLong { .. } => panic!("Returned UploaderNameTooLong!"),
             VideoError::TooManyTags(_) => panic!("Returned TooManyTags!"),
         },
     }
 }
 
+#[test]
+fn test_constructor_description_counts_characters_not_bytes() {
+    // 2,048 characters, but 6,144 bytes
+    let description: String = "説".repeat(2048);
+
+    let video_result: Result<Video, VideoError> = Video::new(
+        String::from("https://www.toddgriffin.me/thumbs/123.jpg"),
+        String::from("Grilling steaks for summer"),
+        description,
+        String::from("https://www.toddgriffin.me/video123.mp4"),
+        String::from("https://www.toddgriffin.me/videoplayer.php?video=123"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Uploader::new("名".repeat(255), None)),
+        None,
+        None,
+    );
+    assert!(video_result.is_ok());
+}
+
 #[test]
 fn test_constructor_uploader_name_too_long() {
     let mut uploader_name: String = String::new();
@@ -306,12 +349,17 @@ fn test_constructor_uploader_name_too_long() {
     match video_result {
         Ok(_) => panic!("Returned a Video!"),
         Err(e) => match e {
-            VideoError::DescriptionTooLong(_) => panic!("Returned DescriptionTooLong!"),
+            VideoError::DescriptionTooLong { .. } => panic!("Returned DescriptionTooLong!"),
             VideoError::DurationTooShort(_) => panic!("Returned DurationTooShort!"),
             VideoError::DurationTooLong(_) => panic!("Returned DurationTooLong!"),
             VideoError::RatingTooLow(_) => panic!("Returned RatingTooLow!"),
             VideoError::RatingTooHigh(_) => panic!("Returned RatingTooHigh!"),
-            VideoError::UploaderNameTooLong(count) => assert_eq!(256, count),
+            VideoError::RatingNotANumber => panic!("Returned RatingNotANumber!"),
+            VideoError::Date { .. } => panic!("Returned Date!"),
+            VideoError::UploaderNameTooLong { characters, bytes } => {
+                assert_eq!(256, characters);
+                assert_eq!(256, bytes);
+            }
             VideoError::TooManyTags(_) => panic!("Returned TooManyTags!"),
         },
     }
@@ -347,13 +395,73 @@ fn test_constructor_too_many_tags() {
     match video_result {
         Ok(_) => panic!("Returned a Video!"),
         Err(e) => match e {
-            VideoError::DescriptionTooLong(_) => panic!("Returned DescriptionTooLong!"),
+            VideoError::DescriptionTooLong { .. } => panic!("Returned DescriptionTooLong!"),
             VideoError::DurationTooShort(_) => panic!("Returned DurationTooShort!"),
             VideoError::DurationTooLong(_) => panic!("Returned DurationTooLong!"),
             VideoError::RatingTooLow(_) => panic!("Returned RatingTooLow!"),
             VideoError::RatingTooHigh(_) => panic!("Returned RatingTooHigh!"),
-            VideoError::UploaderNameTooLong(_) => panic!("Returned UploaderNameTooLong!"),
+            VideoError::RatingNotANumber => panic!("Returned RatingNotANumber!"),
+            VideoError::Date { .. } => panic!("Returned Date!"),
+            VideoError::UploaderNameTooLong { .. } => panic!("Returned UploaderNameTooLong!"),
             VideoError::TooManyTags(count) => assert_eq!(33, count),
         },
     }
 }
+
+#[test]
+fn test_validate_reports_every_error() {
+    let mut video: Video = Video::builder(
+        String::from("https://www.toddgriffin.me/thumbs/123.jpg"),
+        String::from("Grilling steaks for summer"),
+        String::from("Alkis shows you how to get perfectly done steaks every time"),
+        String::from("https://www.toddgriffin.me/video123.mp4"),
+        String::from("https://www.toddgriffin.me/videoplayer.php?video=123"),
+    )
+    .build()
+    .unwrap();
+    video.description = "5".repeat(2049);
+    video.uploader = Some(Uploader::new("5".repeat(256), None));
+
+    let errors: Vec<VideoError> = video.validate();
+    let fields: Vec<&str> = errors.iter().map(VideoError::field).collect();
+    assert_eq!(vec!["description", "uploader.name"], fields);
+}
+
+#[test]
+fn test_newtypes() {
+    assert_eq!(600, VideoDuration::new(600).unwrap().get());
+    assert!(matches!(
+        VideoDuration::new(0),
+        Err(VideoError::DurationTooShort(0))
+    ));
+    assert!(matches!(
+        VideoDuration::try_from(28801),
+        Err(VideoError::DurationTooLong(28801))
+    ));
+
+    assert_eq!("4.2", VideoRating::new(4.2).unwrap().to_string());
+    assert!(matches!(
+        VideoRating::new(-0.1),
+        Err(VideoError::RatingTooLow(_))
+    ));
+    assert!(matches!(
+        VideoRating::new(5.1),
+        Err(VideoError::RatingTooHigh(_))
+    ));
+    assert!(matches!(
+        VideoRating::new(f32::NAN),
+        Err(VideoError::RatingNotANumber)
+    ));
+    assert_eq!(
+        VideoRating::new(0.0).unwrap(),
+        VideoRating::new(-0.0).unwrap()
+    );
+    assert!(VideoRating::new(4.2).unwrap() > VideoRating::new(0.5).unwrap());
+
+    let tags: TagList = TagList::new(vec![String::from("steak"); 32]).unwrap();
+    assert_eq!(32, tags.as_slice().len());
+    assert!(matches!(
+        TagList::new(vec![String::from("steak"); 33]),
+        Err(VideoError::TooManyTags(33))
+    ));
+}
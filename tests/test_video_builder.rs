@@ -1,4 +1,6 @@
 use chrono::{DateTime, FixedOffset, NaiveDate};
+#[cfg(feature = "jiff")]
+use sitemap_rs::date_error::DateError;
 use sitemap_rs::video::{Platform, PlatformType, Relationship, Restriction, Uploader, Video};
 use sitemap_rs::video_builder::VideoBuilder;
 use sitemap_rs::video_error::VideoError;
@@ -27,7 +29,7 @@ fn test_all_fields() {
         String::from("https://www.toddgriffin.me/videoplayer.php?video=123"),
     )
     .duration(600)
-    .expiration_date(DateTime::from_naive_utc_and_offset(
+    .expiration_date(DateTime::<FixedOffset>::from_naive_utc_and_offset(
         NaiveDate::from_ymd_opt(2021, 11, 5)
             .unwrap()
             .and_hms_opt(11, 20, 30)
@@ -36,7 +38,7 @@ fn test_all_fields() {
     ))
     .rating(4.2)
     .view_count(12345)
-    .publication_date(DateTime::from_naive_utc_and_offset(
+    .publication_date(DateTime::<FixedOffset>::from_naive_utc_and_offset(
         NaiveDate::from_ymd_opt(1998, 1, 15)
             .unwrap()
             .and_hms_opt(4, 20, 0)
@@ -74,3 +76,58 @@ fn test_all_fields() {
     .build();
     assert!(url_builder_result.is_ok());
 }
+
+#[test]
+fn test_truncate_long_text() {
+    let video: Video = VideoBuilder::new(
+        String::from("https://www.toddgriffin.me/thumbs/123.jpg"),
+        String::from("Grilling steaks for summer"),
+        "e\u{301}".repeat(1500),
+        String::from("https://www.toddgriffin.me/video123.mp4"),
+        String::from("https://www.toddgriffin.me/videoplayer.php?video=123"),
+    )
+    .uploader(Uploader::new("5".repeat(300), None))
+    .truncate_long_text(true)
+    .build()
+    .unwrap();
+
+    // each "é" is two characters, so the last grapheme is dropped rather than split
+    assert_eq!(2048, video.description.chars().count());
+    assert_eq!(255, video.uploader.unwrap().name.chars().count());
+}
+
+#[test]
+fn test_long_text_without_truncation() {
+    let video_result: Result<Video, VideoError> = VideoBuilder::new(
+        String::from("https://www.toddgriffin.me/thumbs/123.jpg"),
+        String::from("Grilling steaks for summer"),
+        "5".repeat(2049),
+        String::from("https://www.toddgriffin.me/video123.mp4"),
+        String::from("https://www.toddgriffin.me/videoplayer.php?video=123"),
+    )
+    .build();
+    assert!(video_result.is_err());
+}
+
+#[cfg(feature = "jiff")]
+#[test]
+fn test_publication_date_out_of_range() {
+    // the earliest civil date time has no timestamp in UTC
+    let result: Result<Video, VideoError> = VideoBuilder::new(
+        String::from("https://www.toddgriffin.me/thumbs/123.jpg"),
+        String::from("Grilling steaks for summer"),
+        String::from("Alkis shows you how to get perfectly done steaks every time"),
+        String::from("https://www.toddgriffin.me/video123.mp4"),
+        String::from("https://www.toddgriffin.me/videoplayer.php?video=123"),
+    )
+    .expiration_date(jiff::Timestamp::UNIX_EPOCH)
+    .publication_date(jiff::civil::DateTime::MIN)
+    .build();
+    assert!(matches!(
+        result,
+        Err(VideoError::Date {
+            field: "publication_date",
+            error: DateError::OutOfRange(_),
+        })
+    ));
+}
@@ -0,0 +1,278 @@
+use chrono::{DateTime, FixedOffset};
+use sitemap_rs::extension::{ExtensionElement, Namespace};
+use sitemap_rs::image::Image;
+use sitemap_rs::sitemap::Sitemap;
+use sitemap_rs::sitemap_index::SitemapIndex;
+use sitemap_rs::url::{Link, Url};
+use sitemap_rs::url_set::{UrlOrder, UrlSet};
+use sitemap_rs::video::Video;
+use sitemap_rs::write_options::{Indent, LineEnding, WriteOptions};
+
+fn url_set() -> UrlSet {
+    let urls: Vec<Url> = vec![
+        Url::builder(String::from("https://www.toddgriffin.me/"))
+            .links(vec![Link::new(
+                "de".to_owned(),
+                "https://www.toddgriffin.me/de".to_owned(),
+            )])
+            .build()
+            .unwrap(),
+    ];
+    UrlSet::new(urls).unwrap()
+}
+
+fn write(url_set: &UrlSet, options: &WriteOptions) -> String {
+    let mut buf: Vec<u8> = Vec::<u8>::new();
+    url_set.write_with_options(&mut buf, options).unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+#[test]
+fn test_default_matches_write() {
+    let url_set: UrlSet = url_set();
+
+    let mut buf: Vec<u8> = Vec::<u8>::new();
+    url_set.write(&mut buf).unwrap();
+
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        write(&url_set, &WriteOptions::default())
+    );
+}
+
+#[test]
+fn test_compact() {
+    let expected: &str = concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" xmlns:xhtml="http://www.w3.org/1999/xhtml">"#,
+        r#"<url>"#,
+        r#"<loc>https://www.toddgriffin.me/</loc>"#,
+        r#"<xhtml:link rel="alternate" hreflang="de" href="https://www.toddgriffin.me/de" />"#,
+        r#"</url>"#,
+        r#"</urlset>"#,
+    );
+    assert_eq!(expected, write(&url_set(), &WriteOptions::compact()));
+}
+
+#[test]
+fn test_pretty_with_spaces() {
+    let expected: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" xmlns:xhtml="http://www.w3.org/1999/xhtml">
+  <url>
+    <loc>https://www.toddgriffin.me/</loc>
+    <xhtml:link rel="alternate" hreflang="de" href="https://www.toddgriffin.me/de" />
+  </url>
+</urlset>
+"#;
+    assert_eq!(
+        expected,
+        write(&url_set(), &WriteOptions::pretty(Indent::Spaces(2)))
+    );
+}
+
+#[test]
+fn test_crlf_line_endings() {
+    let sitemap_index: SitemapIndex = SitemapIndex::new(vec![Sitemap::new(
+        String::from("https://www.toddgriffin.me/sitemap.xml"),
+        None,
+    )])
+    .unwrap();
+
+    let mut buf: Vec<u8> = Vec::<u8>::new();
+    sitemap_index
+        .write_with_options(
+            &mut buf,
+            WriteOptions::pretty(Indent::Tab).line_ending(LineEnding::CrLf),
+        )
+        .unwrap();
+
+    let expected: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\r\n<sitemapindex xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\r\n\t<sitemap>\r\n\t\t<loc>https://www.toddgriffin.me/sitemap.xml</loc>\r\n\t</sitemap>\r\n</sitemapindex>\r\n";
+    assert_eq!(expected, String::from_utf8(buf).unwrap());
+}
+
+/// The same URLs, with everything in them (and so the first use of each namespace) given in the opposite order when `reversed`.
+fn unordered_url_set(reversed: bool) -> UrlSet {
+    let mut links: Vec<Link> = vec![
+        Link::new("de".to_owned(), "https://www.toddgriffin.me/de".to_owned()),
+        Link::new("en".to_owned(), "https://www.toddgriffin.me/en".to_owned()),
+    ];
+    let mut images: Vec<Image> = vec![
+        Image::new(String::from("https://www.toddgriffin.me/a.png")),
+        Image::new(String::from("https://www.toddgriffin.me/b.png")),
+    ];
+    let mut attributes: Vec<(String, String)> = vec![
+        (String::from("a"), String::from("1")),
+        (String::from("b"), String::from("2")),
+    ];
+    let mut extensions: Vec<ExtensionElement> = vec![
+        ExtensionElement::new(
+            Namespace::new("x", "https://example.com/x"),
+            String::from("x"),
+        ),
+        ExtensionElement::new(
+            Namespace::new("y", "https://example.com/y"),
+            String::from("y"),
+        ),
+    ];
+    if reversed {
+        links.reverse();
+        images.reverse();
+        attributes.reverse();
+    }
+
+    let mut element: ExtensionElement = extensions.remove(0);
+    for (name, value) in attributes {
+        element = element.attribute(name, value);
+    }
+    let mut urls: Vec<Url> = vec![
+        Url::builder(String::from("https://www.toddgriffin.me/a"))
+            .links(links)
+            .images(images)
+            .extensions(vec![element])
+            .build()
+            .unwrap(),
+        Url::builder(String::from("https://www.toddgriffin.me/b"))
+            .extensions(extensions)
+            .build()
+            .unwrap(),
+    ];
+    if reversed {
+        urls.reverse();
+    }
+    UrlSet::new(urls).unwrap()
+}
+
+#[test]
+fn test_order_is_stable() {
+    let mut options: WriteOptions = WriteOptions::compact();
+    options.order(UrlOrder::Location);
+
+    let written: String = write(&unordered_url_set(false), &options);
+    assert_eq!(written, write(&unordered_url_set(true), &options));
+    assert_ne!(
+        write(&unordered_url_set(false), &WriteOptions::compact()),
+        write(&unordered_url_set(true), &WriteOptions::compact())
+    );
+
+    let expected: &str = concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" xmlns:xhtml="http://www.w3.org/1999/xhtml" xmlns:image="http://www.google.com/schemas/sitemap-image/1.1" xmlns:x="https://example.com/x" xmlns:y="https://example.com/y">"#,
+        r#"<url>"#,
+        r#"<loc>https://www.toddgriffin.me/a</loc>"#,
+        r#"<xhtml:link rel="alternate" hreflang="de" href="https://www.toddgriffin.me/de" />"#,
+        r#"<xhtml:link rel="alternate" hreflang="en" href="https://www.toddgriffin.me/en" />"#,
+        r#"<image:image><image:loc>https://www.toddgriffin.me/a.png</image:loc></image:image>"#,
+        r#"<image:image><image:loc>https://www.toddgriffin.me/b.png</image:loc></image:image>"#,
+        r#"<x:x a="1" b="2" />"#,
+        r#"</url>"#,
+        r#"<url>"#,
+        r#"<loc>https://www.toddgriffin.me/b</loc>"#,
+        r#"<y:y />"#,
+        r#"</url>"#,
+        r#"</urlset>"#,
+    );
+    assert_eq!(expected, written);
+}
+
+#[test]
+fn test_order_videos_and_extensions() {
+    let url_set = |reversed: bool| -> UrlSet {
+        let namespace: Namespace = Namespace::new("x", "https://example.com/x");
+        let mut videos: Vec<Video> = ["a", "b"]
+            .iter()
+            .map(|name| {
+                Video::builder(
+                    format!("https://www.toddgriffin.me/{name}.jpg"),
+                    format!("Video {name}"),
+                    format!("Video {name}"),
+                    format!("https://www.toddgriffin.me/{name}.mp4"),
+                    format!("https://www.toddgriffin.me/player?video={name}"),
+                )
+                .build()
+                .unwrap()
+            })
+            .collect();
+        let mut children: Vec<ExtensionElement> = vec![
+            ExtensionElement::new(namespace.clone(), String::from("a")),
+            ExtensionElement::new(namespace.clone(), String::from("b")),
+        ];
+        let mut extensions: Vec<ExtensionElement> = vec![
+            ExtensionElement::new(namespace.clone(), String::from("x")).text(String::from("1")),
+            ExtensionElement::new(namespace.clone(), String::from("x")).text(String::from("2")),
+        ];
+        if reversed {
+            videos.reverse();
+            children.reverse();
+            extensions.reverse();
+        }
+
+        let mut parent: ExtensionElement = ExtensionElement::new(namespace, String::from("parent"));
+        for child in children {
+            parent = parent.child(child);
+        }
+        extensions.push(parent);
+        let url: Url = Url::builder(String::from("https://www.toddgriffin.me/"))
+            .videos(videos)
+            .extensions(extensions)
+            .build()
+            .unwrap();
+        UrlSet::new(vec![url]).unwrap()
+    };
+
+    let mut options: WriteOptions = WriteOptions::compact();
+    options.order(UrlOrder::Location);
+    let written: String = write(&url_set(false), &options);
+    assert_eq!(written, write(&url_set(true), &options));
+    assert_ne!(
+        write(&url_set(false), &WriteOptions::compact()),
+        write(&url_set(true), &WriteOptions::compact())
+    );
+
+    let positions: Vec<usize> = [
+        "<video:title>Video a",
+        "<video:title>Video b",
+        "<x:parent><x:a /><x:b /></x:parent>",
+        "<x:x>1</x:x>",
+        "<x:x>2</x:x>",
+    ]
+    .iter()
+    .map(|element| written.find(element).unwrap())
+    .collect();
+    assert!(positions.is_sorted());
+}
+
+#[test]
+fn test_order_sitemaps() {
+    let date = |date: &str| -> Option<DateTime<FixedOffset>> {
+        Some(DateTime::parse_from_rfc3339(date).unwrap())
+    };
+    let sitemap_index: SitemapIndex = SitemapIndex::new(vec![
+        Sitemap::new(
+            String::from("https://www.toddgriffin.me/sitemap-1.xml"),
+            None,
+        ),
+        Sitemap::new(
+            String::from("https://www.toddgriffin.me/sitemap-2.xml"),
+            date("2024-01-01T00:00:00+00:00"),
+        ),
+        Sitemap::new(
+            String::from("https://www.toddgriffin.me/sitemap-3.xml"),
+            date("2024-06-01T00:00:00+00:00"),
+        ),
+    ])
+    .unwrap();
+
+    let mut options: WriteOptions = WriteOptions::compact();
+    options.order(UrlOrder::LastModifiedDescending);
+    let mut buf: Vec<u8> = Vec::<u8>::new();
+    sitemap_index
+        .write_with_options(&mut buf, &options)
+        .unwrap();
+    let written: String = String::from_utf8(buf).unwrap();
+
+    let positions: Vec<usize> = ["sitemap-3", "sitemap-2", "sitemap-1"]
+        .iter()
+        .map(|name| written.find(name).unwrap())
+        .collect();
+    assert!(positions.is_sorted());
+}